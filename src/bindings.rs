@@ -3,6 +3,7 @@
 #![allow(non_snake_case)]
 #![allow(dead_code)]
 #![allow(improper_ctypes)]
+#![allow(clippy::all)]
 #![allow(missing_docs)]
 
 /*
@@ -615,7 +616,7 @@ fn bindgen_test_layout___fsid_t() {
     concat!("Alignment of ", stringify!(__fsid_t))
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<__fsid_t>())).__val as *const _ as usize },
+    ::std::mem::offset_of!(__fsid_t, __val),
     0usize,
     concat!(
       "Offset of field: ",
@@ -680,7 +681,7 @@ fn bindgen_test_layout___mbstate_t__bindgen_ty_1() {
     concat!("Alignment of ", stringify!(__mbstate_t__bindgen_ty_1))
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<__mbstate_t__bindgen_ty_1>())).__wch as *const _ as usize },
+    ::std::mem::offset_of!(__mbstate_t__bindgen_ty_1, __wch),
     0usize,
     concat!(
       "Offset of field: ",
@@ -690,7 +691,7 @@ fn bindgen_test_layout___mbstate_t__bindgen_ty_1() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<__mbstate_t__bindgen_ty_1>())).__wchb as *const _ as usize },
+    ::std::mem::offset_of!(__mbstate_t__bindgen_ty_1, __wchb),
     0usize,
     concat!(
       "Offset of field: ",
@@ -713,7 +714,7 @@ fn bindgen_test_layout___mbstate_t() {
     concat!("Alignment of ", stringify!(__mbstate_t))
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<__mbstate_t>())).__count as *const _ as usize },
+    ::std::mem::offset_of!(__mbstate_t, __count),
     0usize,
     concat!(
       "Offset of field: ",
@@ -723,7 +724,7 @@ fn bindgen_test_layout___mbstate_t() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<__mbstate_t>())).__value as *const _ as usize },
+    ::std::mem::offset_of!(__mbstate_t, __value),
     4usize,
     concat!(
       "Offset of field: ",
@@ -752,7 +753,7 @@ fn bindgen_test_layout__G_fpos_t() {
     concat!("Alignment of ", stringify!(_G_fpos_t))
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_G_fpos_t>())).__pos as *const _ as usize },
+    ::std::mem::offset_of!(_G_fpos_t, __pos),
     0usize,
     concat!(
       "Offset of field: ",
@@ -762,7 +763,7 @@ fn bindgen_test_layout__G_fpos_t() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_G_fpos_t>())).__state as *const _ as usize },
+    ::std::mem::offset_of!(_G_fpos_t, __state),
     8usize,
     concat!(
       "Offset of field: ",
@@ -791,7 +792,7 @@ fn bindgen_test_layout__G_fpos64_t() {
     concat!("Alignment of ", stringify!(_G_fpos64_t))
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_G_fpos64_t>())).__pos as *const _ as usize },
+    ::std::mem::offset_of!(_G_fpos64_t, __pos),
     0usize,
     concat!(
       "Offset of field: ",
@@ -801,7 +802,7 @@ fn bindgen_test_layout__G_fpos64_t() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_G_fpos64_t>())).__state as *const _ as usize },
+    ::std::mem::offset_of!(_G_fpos64_t, __state),
     8usize,
     concat!(
       "Offset of field: ",
@@ -837,7 +838,7 @@ fn bindgen_test_layout__IO_marker() {
     concat!("Alignment of ", stringify!(_IO_marker))
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_IO_marker>()))._next as *const _ as usize },
+    ::std::mem::offset_of!(_IO_marker, _next),
     0usize,
     concat!(
       "Offset of field: ",
@@ -847,7 +848,7 @@ fn bindgen_test_layout__IO_marker() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_IO_marker>()))._sbuf as *const _ as usize },
+    ::std::mem::offset_of!(_IO_marker, _sbuf),
     8usize,
     concat!(
       "Offset of field: ",
@@ -857,7 +858,7 @@ fn bindgen_test_layout__IO_marker() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_IO_marker>()))._pos as *const _ as usize },
+    ::std::mem::offset_of!(_IO_marker, _pos),
     16usize,
     concat!(
       "Offset of field: ",
@@ -918,7 +919,7 @@ fn bindgen_test_layout__IO_FILE() {
     concat!("Alignment of ", stringify!(_IO_FILE))
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_IO_FILE>()))._flags as *const _ as usize },
+    ::std::mem::offset_of!(_IO_FILE, _flags),
     0usize,
     concat!(
       "Offset of field: ",
@@ -928,7 +929,7 @@ fn bindgen_test_layout__IO_FILE() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_IO_FILE>()))._IO_read_ptr as *const _ as usize },
+    ::std::mem::offset_of!(_IO_FILE, _IO_read_ptr),
     8usize,
     concat!(
       "Offset of field: ",
@@ -938,7 +939,7 @@ fn bindgen_test_layout__IO_FILE() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_IO_FILE>()))._IO_read_end as *const _ as usize },
+    ::std::mem::offset_of!(_IO_FILE, _IO_read_end),
     16usize,
     concat!(
       "Offset of field: ",
@@ -948,7 +949,7 @@ fn bindgen_test_layout__IO_FILE() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_IO_FILE>()))._IO_read_base as *const _ as usize },
+    ::std::mem::offset_of!(_IO_FILE, _IO_read_base),
     24usize,
     concat!(
       "Offset of field: ",
@@ -958,7 +959,7 @@ fn bindgen_test_layout__IO_FILE() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_IO_FILE>()))._IO_write_base as *const _ as usize },
+    ::std::mem::offset_of!(_IO_FILE, _IO_write_base),
     32usize,
     concat!(
       "Offset of field: ",
@@ -968,7 +969,7 @@ fn bindgen_test_layout__IO_FILE() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_IO_FILE>()))._IO_write_ptr as *const _ as usize },
+    ::std::mem::offset_of!(_IO_FILE, _IO_write_ptr),
     40usize,
     concat!(
       "Offset of field: ",
@@ -978,7 +979,7 @@ fn bindgen_test_layout__IO_FILE() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_IO_FILE>()))._IO_write_end as *const _ as usize },
+    ::std::mem::offset_of!(_IO_FILE, _IO_write_end),
     48usize,
     concat!(
       "Offset of field: ",
@@ -988,7 +989,7 @@ fn bindgen_test_layout__IO_FILE() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_IO_FILE>()))._IO_buf_base as *const _ as usize },
+    ::std::mem::offset_of!(_IO_FILE, _IO_buf_base),
     56usize,
     concat!(
       "Offset of field: ",
@@ -998,7 +999,7 @@ fn bindgen_test_layout__IO_FILE() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_IO_FILE>()))._IO_buf_end as *const _ as usize },
+    ::std::mem::offset_of!(_IO_FILE, _IO_buf_end),
     64usize,
     concat!(
       "Offset of field: ",
@@ -1008,7 +1009,7 @@ fn bindgen_test_layout__IO_FILE() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_IO_FILE>()))._IO_save_base as *const _ as usize },
+    ::std::mem::offset_of!(_IO_FILE, _IO_save_base),
     72usize,
     concat!(
       "Offset of field: ",
@@ -1018,7 +1019,7 @@ fn bindgen_test_layout__IO_FILE() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_IO_FILE>()))._IO_backup_base as *const _ as usize },
+    ::std::mem::offset_of!(_IO_FILE, _IO_backup_base),
     80usize,
     concat!(
       "Offset of field: ",
@@ -1028,7 +1029,7 @@ fn bindgen_test_layout__IO_FILE() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_IO_FILE>()))._IO_save_end as *const _ as usize },
+    ::std::mem::offset_of!(_IO_FILE, _IO_save_end),
     88usize,
     concat!(
       "Offset of field: ",
@@ -1038,7 +1039,7 @@ fn bindgen_test_layout__IO_FILE() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_IO_FILE>()))._markers as *const _ as usize },
+    ::std::mem::offset_of!(_IO_FILE, _markers),
     96usize,
     concat!(
       "Offset of field: ",
@@ -1048,7 +1049,7 @@ fn bindgen_test_layout__IO_FILE() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_IO_FILE>()))._chain as *const _ as usize },
+    ::std::mem::offset_of!(_IO_FILE, _chain),
     104usize,
     concat!(
       "Offset of field: ",
@@ -1058,7 +1059,7 @@ fn bindgen_test_layout__IO_FILE() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_IO_FILE>()))._fileno as *const _ as usize },
+    ::std::mem::offset_of!(_IO_FILE, _fileno),
     112usize,
     concat!(
       "Offset of field: ",
@@ -1068,7 +1069,7 @@ fn bindgen_test_layout__IO_FILE() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_IO_FILE>()))._flags2 as *const _ as usize },
+    ::std::mem::offset_of!(_IO_FILE, _flags2),
     116usize,
     concat!(
       "Offset of field: ",
@@ -1078,7 +1079,7 @@ fn bindgen_test_layout__IO_FILE() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_IO_FILE>()))._old_offset as *const _ as usize },
+    ::std::mem::offset_of!(_IO_FILE, _old_offset),
     120usize,
     concat!(
       "Offset of field: ",
@@ -1088,7 +1089,7 @@ fn bindgen_test_layout__IO_FILE() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_IO_FILE>()))._cur_column as *const _ as usize },
+    ::std::mem::offset_of!(_IO_FILE, _cur_column),
     128usize,
     concat!(
       "Offset of field: ",
@@ -1098,7 +1099,7 @@ fn bindgen_test_layout__IO_FILE() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_IO_FILE>()))._vtable_offset as *const _ as usize },
+    ::std::mem::offset_of!(_IO_FILE, _vtable_offset),
     130usize,
     concat!(
       "Offset of field: ",
@@ -1108,7 +1109,7 @@ fn bindgen_test_layout__IO_FILE() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_IO_FILE>()))._shortbuf as *const _ as usize },
+    ::std::mem::offset_of!(_IO_FILE, _shortbuf),
     131usize,
     concat!(
       "Offset of field: ",
@@ -1118,7 +1119,7 @@ fn bindgen_test_layout__IO_FILE() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_IO_FILE>()))._lock as *const _ as usize },
+    ::std::mem::offset_of!(_IO_FILE, _lock),
     136usize,
     concat!(
       "Offset of field: ",
@@ -1128,7 +1129,7 @@ fn bindgen_test_layout__IO_FILE() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_IO_FILE>()))._offset as *const _ as usize },
+    ::std::mem::offset_of!(_IO_FILE, _offset),
     144usize,
     concat!(
       "Offset of field: ",
@@ -1138,7 +1139,7 @@ fn bindgen_test_layout__IO_FILE() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_IO_FILE>())).__pad1 as *const _ as usize },
+    ::std::mem::offset_of!(_IO_FILE, __pad1),
     152usize,
     concat!(
       "Offset of field: ",
@@ -1148,7 +1149,7 @@ fn bindgen_test_layout__IO_FILE() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_IO_FILE>())).__pad2 as *const _ as usize },
+    ::std::mem::offset_of!(_IO_FILE, __pad2),
     160usize,
     concat!(
       "Offset of field: ",
@@ -1158,7 +1159,7 @@ fn bindgen_test_layout__IO_FILE() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_IO_FILE>())).__pad3 as *const _ as usize },
+    ::std::mem::offset_of!(_IO_FILE, __pad3),
     168usize,
     concat!(
       "Offset of field: ",
@@ -1168,7 +1169,7 @@ fn bindgen_test_layout__IO_FILE() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_IO_FILE>())).__pad4 as *const _ as usize },
+    ::std::mem::offset_of!(_IO_FILE, __pad4),
     176usize,
     concat!(
       "Offset of field: ",
@@ -1178,7 +1179,7 @@ fn bindgen_test_layout__IO_FILE() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_IO_FILE>())).__pad5 as *const _ as usize },
+    ::std::mem::offset_of!(_IO_FILE, __pad5),
     184usize,
     concat!(
       "Offset of field: ",
@@ -1188,7 +1189,7 @@ fn bindgen_test_layout__IO_FILE() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_IO_FILE>()))._mode as *const _ as usize },
+    ::std::mem::offset_of!(_IO_FILE, _mode),
     192usize,
     concat!(
       "Offset of field: ",
@@ -1198,7 +1199,7 @@ fn bindgen_test_layout__IO_FILE() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_IO_FILE>()))._unused2 as *const _ as usize },
+    ::std::mem::offset_of!(_IO_FILE, _unused2),
     196usize,
     concat!(
       "Offset of field: ",
@@ -1960,7 +1961,7 @@ fn bindgen_test_layout__xmlBuffer() {
     concat!("Alignment of ", stringify!(_xmlBuffer))
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlBuffer>())).content as *const _ as usize },
+    ::std::mem::offset_of!(_xmlBuffer, content),
     0usize,
     concat!(
       "Offset of field: ",
@@ -1970,7 +1971,7 @@ fn bindgen_test_layout__xmlBuffer() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlBuffer>())).use_ as *const _ as usize },
+    ::std::mem::offset_of!(_xmlBuffer, use_),
     8usize,
     concat!(
       "Offset of field: ",
@@ -1980,7 +1981,7 @@ fn bindgen_test_layout__xmlBuffer() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlBuffer>())).size as *const _ as usize },
+    ::std::mem::offset_of!(_xmlBuffer, size),
     12usize,
     concat!(
       "Offset of field: ",
@@ -1990,7 +1991,7 @@ fn bindgen_test_layout__xmlBuffer() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlBuffer>())).alloc as *const _ as usize },
+    ::std::mem::offset_of!(_xmlBuffer, alloc),
     16usize,
     concat!(
       "Offset of field: ",
@@ -2000,7 +2001,7 @@ fn bindgen_test_layout__xmlBuffer() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlBuffer>())).contentIO as *const _ as usize },
+    ::std::mem::offset_of!(_xmlBuffer, contentIO),
     24usize,
     concat!(
       "Offset of field: ",
@@ -2083,7 +2084,7 @@ fn bindgen_test_layout__xmlNotation() {
     concat!("Alignment of ", stringify!(_xmlNotation))
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlNotation>())).name as *const _ as usize },
+    ::std::mem::offset_of!(_xmlNotation, name),
     0usize,
     concat!(
       "Offset of field: ",
@@ -2093,7 +2094,7 @@ fn bindgen_test_layout__xmlNotation() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlNotation>())).PublicID as *const _ as usize },
+    ::std::mem::offset_of!(_xmlNotation, PublicID),
     8usize,
     concat!(
       "Offset of field: ",
@@ -2103,7 +2104,7 @@ fn bindgen_test_layout__xmlNotation() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlNotation>())).SystemID as *const _ as usize },
+    ::std::mem::offset_of!(_xmlNotation, SystemID),
     16usize,
     concat!(
       "Offset of field: ",
@@ -2159,7 +2160,7 @@ fn bindgen_test_layout__xmlEnumeration() {
     concat!("Alignment of ", stringify!(_xmlEnumeration))
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlEnumeration>())).next as *const _ as usize },
+    ::std::mem::offset_of!(_xmlEnumeration, next),
     0usize,
     concat!(
       "Offset of field: ",
@@ -2169,7 +2170,7 @@ fn bindgen_test_layout__xmlEnumeration() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlEnumeration>())).name as *const _ as usize },
+    ::std::mem::offset_of!(_xmlEnumeration, name),
     8usize,
     concat!(
       "Offset of field: ",
@@ -2217,7 +2218,7 @@ fn bindgen_test_layout__xmlAttribute() {
     concat!("Alignment of ", stringify!(_xmlAttribute))
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlAttribute>()))._private as *const _ as usize },
+    ::std::mem::offset_of!(_xmlAttribute, _private),
     0usize,
     concat!(
       "Offset of field: ",
@@ -2227,7 +2228,7 @@ fn bindgen_test_layout__xmlAttribute() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlAttribute>())).type_ as *const _ as usize },
+    ::std::mem::offset_of!(_xmlAttribute, type_),
     8usize,
     concat!(
       "Offset of field: ",
@@ -2237,7 +2238,7 @@ fn bindgen_test_layout__xmlAttribute() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlAttribute>())).name as *const _ as usize },
+    ::std::mem::offset_of!(_xmlAttribute, name),
     16usize,
     concat!(
       "Offset of field: ",
@@ -2247,7 +2248,7 @@ fn bindgen_test_layout__xmlAttribute() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlAttribute>())).children as *const _ as usize },
+    ::std::mem::offset_of!(_xmlAttribute, children),
     24usize,
     concat!(
       "Offset of field: ",
@@ -2257,7 +2258,7 @@ fn bindgen_test_layout__xmlAttribute() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlAttribute>())).last as *const _ as usize },
+    ::std::mem::offset_of!(_xmlAttribute, last),
     32usize,
     concat!(
       "Offset of field: ",
@@ -2267,7 +2268,7 @@ fn bindgen_test_layout__xmlAttribute() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlAttribute>())).parent as *const _ as usize },
+    ::std::mem::offset_of!(_xmlAttribute, parent),
     40usize,
     concat!(
       "Offset of field: ",
@@ -2277,7 +2278,7 @@ fn bindgen_test_layout__xmlAttribute() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlAttribute>())).next as *const _ as usize },
+    ::std::mem::offset_of!(_xmlAttribute, next),
     48usize,
     concat!(
       "Offset of field: ",
@@ -2287,7 +2288,7 @@ fn bindgen_test_layout__xmlAttribute() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlAttribute>())).prev as *const _ as usize },
+    ::std::mem::offset_of!(_xmlAttribute, prev),
     56usize,
     concat!(
       "Offset of field: ",
@@ -2297,7 +2298,7 @@ fn bindgen_test_layout__xmlAttribute() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlAttribute>())).doc as *const _ as usize },
+    ::std::mem::offset_of!(_xmlAttribute, doc),
     64usize,
     concat!(
       "Offset of field: ",
@@ -2307,7 +2308,7 @@ fn bindgen_test_layout__xmlAttribute() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlAttribute>())).nexth as *const _ as usize },
+    ::std::mem::offset_of!(_xmlAttribute, nexth),
     72usize,
     concat!(
       "Offset of field: ",
@@ -2317,7 +2318,7 @@ fn bindgen_test_layout__xmlAttribute() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlAttribute>())).atype as *const _ as usize },
+    ::std::mem::offset_of!(_xmlAttribute, atype),
     80usize,
     concat!(
       "Offset of field: ",
@@ -2327,7 +2328,7 @@ fn bindgen_test_layout__xmlAttribute() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlAttribute>())).def as *const _ as usize },
+    ::std::mem::offset_of!(_xmlAttribute, def),
     84usize,
     concat!(
       "Offset of field: ",
@@ -2337,7 +2338,7 @@ fn bindgen_test_layout__xmlAttribute() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlAttribute>())).defaultValue as *const _ as usize },
+    ::std::mem::offset_of!(_xmlAttribute, defaultValue),
     88usize,
     concat!(
       "Offset of field: ",
@@ -2347,7 +2348,7 @@ fn bindgen_test_layout__xmlAttribute() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlAttribute>())).tree as *const _ as usize },
+    ::std::mem::offset_of!(_xmlAttribute, tree),
     96usize,
     concat!(
       "Offset of field: ",
@@ -2357,7 +2358,7 @@ fn bindgen_test_layout__xmlAttribute() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlAttribute>())).prefix as *const _ as usize },
+    ::std::mem::offset_of!(_xmlAttribute, prefix),
     104usize,
     concat!(
       "Offset of field: ",
@@ -2367,7 +2368,7 @@ fn bindgen_test_layout__xmlAttribute() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlAttribute>())).elem as *const _ as usize },
+    ::std::mem::offset_of!(_xmlAttribute, elem),
     112usize,
     concat!(
       "Offset of field: ",
@@ -2423,7 +2424,7 @@ fn bindgen_test_layout__xmlElementContent() {
     concat!("Alignment of ", stringify!(_xmlElementContent))
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlElementContent>())).type_ as *const _ as usize },
+    ::std::mem::offset_of!(_xmlElementContent, type_),
     0usize,
     concat!(
       "Offset of field: ",
@@ -2433,7 +2434,7 @@ fn bindgen_test_layout__xmlElementContent() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlElementContent>())).ocur as *const _ as usize },
+    ::std::mem::offset_of!(_xmlElementContent, ocur),
     4usize,
     concat!(
       "Offset of field: ",
@@ -2443,7 +2444,7 @@ fn bindgen_test_layout__xmlElementContent() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlElementContent>())).name as *const _ as usize },
+    ::std::mem::offset_of!(_xmlElementContent, name),
     8usize,
     concat!(
       "Offset of field: ",
@@ -2453,7 +2454,7 @@ fn bindgen_test_layout__xmlElementContent() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlElementContent>())).c1 as *const _ as usize },
+    ::std::mem::offset_of!(_xmlElementContent, c1),
     16usize,
     concat!(
       "Offset of field: ",
@@ -2463,7 +2464,7 @@ fn bindgen_test_layout__xmlElementContent() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlElementContent>())).c2 as *const _ as usize },
+    ::std::mem::offset_of!(_xmlElementContent, c2),
     24usize,
     concat!(
       "Offset of field: ",
@@ -2473,7 +2474,7 @@ fn bindgen_test_layout__xmlElementContent() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlElementContent>())).parent as *const _ as usize },
+    ::std::mem::offset_of!(_xmlElementContent, parent),
     32usize,
     concat!(
       "Offset of field: ",
@@ -2483,7 +2484,7 @@ fn bindgen_test_layout__xmlElementContent() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlElementContent>())).prefix as *const _ as usize },
+    ::std::mem::offset_of!(_xmlElementContent, prefix),
     40usize,
     concat!(
       "Offset of field: ",
@@ -2817,7 +2818,7 @@ fn bindgen_test_layout__xmlElement() {
     concat!("Alignment of ", stringify!(_xmlElement))
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlElement>()))._private as *const _ as usize },
+    ::std::mem::offset_of!(_xmlElement, _private),
     0usize,
     concat!(
       "Offset of field: ",
@@ -2827,7 +2828,7 @@ fn bindgen_test_layout__xmlElement() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlElement>())).type_ as *const _ as usize },
+    ::std::mem::offset_of!(_xmlElement, type_),
     8usize,
     concat!(
       "Offset of field: ",
@@ -2837,7 +2838,7 @@ fn bindgen_test_layout__xmlElement() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlElement>())).name as *const _ as usize },
+    ::std::mem::offset_of!(_xmlElement, name),
     16usize,
     concat!(
       "Offset of field: ",
@@ -2847,7 +2848,7 @@ fn bindgen_test_layout__xmlElement() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlElement>())).children as *const _ as usize },
+    ::std::mem::offset_of!(_xmlElement, children),
     24usize,
     concat!(
       "Offset of field: ",
@@ -2857,7 +2858,7 @@ fn bindgen_test_layout__xmlElement() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlElement>())).last as *const _ as usize },
+    ::std::mem::offset_of!(_xmlElement, last),
     32usize,
     concat!(
       "Offset of field: ",
@@ -2867,7 +2868,7 @@ fn bindgen_test_layout__xmlElement() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlElement>())).parent as *const _ as usize },
+    ::std::mem::offset_of!(_xmlElement, parent),
     40usize,
     concat!(
       "Offset of field: ",
@@ -2877,7 +2878,7 @@ fn bindgen_test_layout__xmlElement() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlElement>())).next as *const _ as usize },
+    ::std::mem::offset_of!(_xmlElement, next),
     48usize,
     concat!(
       "Offset of field: ",
@@ -2887,7 +2888,7 @@ fn bindgen_test_layout__xmlElement() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlElement>())).prev as *const _ as usize },
+    ::std::mem::offset_of!(_xmlElement, prev),
     56usize,
     concat!(
       "Offset of field: ",
@@ -2897,7 +2898,7 @@ fn bindgen_test_layout__xmlElement() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlElement>())).doc as *const _ as usize },
+    ::std::mem::offset_of!(_xmlElement, doc),
     64usize,
     concat!(
       "Offset of field: ",
@@ -2907,7 +2908,7 @@ fn bindgen_test_layout__xmlElement() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlElement>())).etype as *const _ as usize },
+    ::std::mem::offset_of!(_xmlElement, etype),
     72usize,
     concat!(
       "Offset of field: ",
@@ -2917,7 +2918,7 @@ fn bindgen_test_layout__xmlElement() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlElement>())).content as *const _ as usize },
+    ::std::mem::offset_of!(_xmlElement, content),
     80usize,
     concat!(
       "Offset of field: ",
@@ -2927,7 +2928,7 @@ fn bindgen_test_layout__xmlElement() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlElement>())).attributes as *const _ as usize },
+    ::std::mem::offset_of!(_xmlElement, attributes),
     88usize,
     concat!(
       "Offset of field: ",
@@ -2937,7 +2938,7 @@ fn bindgen_test_layout__xmlElement() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlElement>())).prefix as *const _ as usize },
+    ::std::mem::offset_of!(_xmlElement, prefix),
     96usize,
     concat!(
       "Offset of field: ",
@@ -2947,7 +2948,7 @@ fn bindgen_test_layout__xmlElement() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlElement>())).contModel as *const _ as usize },
+    ::std::mem::offset_of!(_xmlElement, contModel),
     104usize,
     concat!(
       "Offset of field: ",
@@ -2990,7 +2991,7 @@ fn bindgen_test_layout__xmlNs() {
     concat!("Alignment of ", stringify!(_xmlNs))
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlNs>())).next as *const _ as usize },
+    ::std::mem::offset_of!(_xmlNs, next),
     0usize,
     concat!(
       "Offset of field: ",
@@ -3000,7 +3001,7 @@ fn bindgen_test_layout__xmlNs() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlNs>())).type_ as *const _ as usize },
+    ::std::mem::offset_of!(_xmlNs, type_),
     8usize,
     concat!(
       "Offset of field: ",
@@ -3010,7 +3011,7 @@ fn bindgen_test_layout__xmlNs() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlNs>())).href as *const _ as usize },
+    ::std::mem::offset_of!(_xmlNs, href),
     16usize,
     concat!(
       "Offset of field: ",
@@ -3020,7 +3021,7 @@ fn bindgen_test_layout__xmlNs() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlNs>())).prefix as *const _ as usize },
+    ::std::mem::offset_of!(_xmlNs, prefix),
     24usize,
     concat!(
       "Offset of field: ",
@@ -3030,7 +3031,7 @@ fn bindgen_test_layout__xmlNs() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlNs>()))._private as *const _ as usize },
+    ::std::mem::offset_of!(_xmlNs, _private),
     32usize,
     concat!(
       "Offset of field: ",
@@ -3040,7 +3041,7 @@ fn bindgen_test_layout__xmlNs() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlNs>())).context as *const _ as usize },
+    ::std::mem::offset_of!(_xmlNs, context),
     40usize,
     concat!(
       "Offset of field: ",
@@ -3089,7 +3090,7 @@ fn bindgen_test_layout__xmlDtd() {
     concat!("Alignment of ", stringify!(_xmlDtd))
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlDtd>()))._private as *const _ as usize },
+    ::std::mem::offset_of!(_xmlDtd, _private),
     0usize,
     concat!(
       "Offset of field: ",
@@ -3099,7 +3100,7 @@ fn bindgen_test_layout__xmlDtd() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlDtd>())).type_ as *const _ as usize },
+    ::std::mem::offset_of!(_xmlDtd, type_),
     8usize,
     concat!(
       "Offset of field: ",
@@ -3109,7 +3110,7 @@ fn bindgen_test_layout__xmlDtd() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlDtd>())).name as *const _ as usize },
+    ::std::mem::offset_of!(_xmlDtd, name),
     16usize,
     concat!(
       "Offset of field: ",
@@ -3119,7 +3120,7 @@ fn bindgen_test_layout__xmlDtd() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlDtd>())).children as *const _ as usize },
+    ::std::mem::offset_of!(_xmlDtd, children),
     24usize,
     concat!(
       "Offset of field: ",
@@ -3129,7 +3130,7 @@ fn bindgen_test_layout__xmlDtd() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlDtd>())).last as *const _ as usize },
+    ::std::mem::offset_of!(_xmlDtd, last),
     32usize,
     concat!(
       "Offset of field: ",
@@ -3139,7 +3140,7 @@ fn bindgen_test_layout__xmlDtd() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlDtd>())).parent as *const _ as usize },
+    ::std::mem::offset_of!(_xmlDtd, parent),
     40usize,
     concat!(
       "Offset of field: ",
@@ -3149,7 +3150,7 @@ fn bindgen_test_layout__xmlDtd() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlDtd>())).next as *const _ as usize },
+    ::std::mem::offset_of!(_xmlDtd, next),
     48usize,
     concat!(
       "Offset of field: ",
@@ -3159,7 +3160,7 @@ fn bindgen_test_layout__xmlDtd() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlDtd>())).prev as *const _ as usize },
+    ::std::mem::offset_of!(_xmlDtd, prev),
     56usize,
     concat!(
       "Offset of field: ",
@@ -3169,7 +3170,7 @@ fn bindgen_test_layout__xmlDtd() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlDtd>())).doc as *const _ as usize },
+    ::std::mem::offset_of!(_xmlDtd, doc),
     64usize,
     concat!(
       "Offset of field: ",
@@ -3179,7 +3180,7 @@ fn bindgen_test_layout__xmlDtd() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlDtd>())).notations as *const _ as usize },
+    ::std::mem::offset_of!(_xmlDtd, notations),
     72usize,
     concat!(
       "Offset of field: ",
@@ -3189,7 +3190,7 @@ fn bindgen_test_layout__xmlDtd() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlDtd>())).elements as *const _ as usize },
+    ::std::mem::offset_of!(_xmlDtd, elements),
     80usize,
     concat!(
       "Offset of field: ",
@@ -3199,7 +3200,7 @@ fn bindgen_test_layout__xmlDtd() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlDtd>())).attributes as *const _ as usize },
+    ::std::mem::offset_of!(_xmlDtd, attributes),
     88usize,
     concat!(
       "Offset of field: ",
@@ -3209,7 +3210,7 @@ fn bindgen_test_layout__xmlDtd() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlDtd>())).entities as *const _ as usize },
+    ::std::mem::offset_of!(_xmlDtd, entities),
     96usize,
     concat!(
       "Offset of field: ",
@@ -3219,7 +3220,7 @@ fn bindgen_test_layout__xmlDtd() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlDtd>())).ExternalID as *const _ as usize },
+    ::std::mem::offset_of!(_xmlDtd, ExternalID),
     104usize,
     concat!(
       "Offset of field: ",
@@ -3229,7 +3230,7 @@ fn bindgen_test_layout__xmlDtd() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlDtd>())).SystemID as *const _ as usize },
+    ::std::mem::offset_of!(_xmlDtd, SystemID),
     112usize,
     concat!(
       "Offset of field: ",
@@ -3239,7 +3240,7 @@ fn bindgen_test_layout__xmlDtd() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlDtd>())).pentities as *const _ as usize },
+    ::std::mem::offset_of!(_xmlDtd, pentities),
     120usize,
     concat!(
       "Offset of field: ",
@@ -3283,7 +3284,7 @@ fn bindgen_test_layout__xmlAttr() {
     concat!("Alignment of ", stringify!(_xmlAttr))
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlAttr>()))._private as *const _ as usize },
+    ::std::mem::offset_of!(_xmlAttr, _private),
     0usize,
     concat!(
       "Offset of field: ",
@@ -3293,7 +3294,7 @@ fn bindgen_test_layout__xmlAttr() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlAttr>())).type_ as *const _ as usize },
+    ::std::mem::offset_of!(_xmlAttr, type_),
     8usize,
     concat!(
       "Offset of field: ",
@@ -3303,7 +3304,7 @@ fn bindgen_test_layout__xmlAttr() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlAttr>())).name as *const _ as usize },
+    ::std::mem::offset_of!(_xmlAttr, name),
     16usize,
     concat!(
       "Offset of field: ",
@@ -3313,7 +3314,7 @@ fn bindgen_test_layout__xmlAttr() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlAttr>())).children as *const _ as usize },
+    ::std::mem::offset_of!(_xmlAttr, children),
     24usize,
     concat!(
       "Offset of field: ",
@@ -3323,7 +3324,7 @@ fn bindgen_test_layout__xmlAttr() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlAttr>())).last as *const _ as usize },
+    ::std::mem::offset_of!(_xmlAttr, last),
     32usize,
     concat!(
       "Offset of field: ",
@@ -3333,7 +3334,7 @@ fn bindgen_test_layout__xmlAttr() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlAttr>())).parent as *const _ as usize },
+    ::std::mem::offset_of!(_xmlAttr, parent),
     40usize,
     concat!(
       "Offset of field: ",
@@ -3343,7 +3344,7 @@ fn bindgen_test_layout__xmlAttr() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlAttr>())).next as *const _ as usize },
+    ::std::mem::offset_of!(_xmlAttr, next),
     48usize,
     concat!(
       "Offset of field: ",
@@ -3353,7 +3354,7 @@ fn bindgen_test_layout__xmlAttr() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlAttr>())).prev as *const _ as usize },
+    ::std::mem::offset_of!(_xmlAttr, prev),
     56usize,
     concat!(
       "Offset of field: ",
@@ -3363,7 +3364,7 @@ fn bindgen_test_layout__xmlAttr() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlAttr>())).doc as *const _ as usize },
+    ::std::mem::offset_of!(_xmlAttr, doc),
     64usize,
     concat!(
       "Offset of field: ",
@@ -3373,7 +3374,7 @@ fn bindgen_test_layout__xmlAttr() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlAttr>())).ns as *const _ as usize },
+    ::std::mem::offset_of!(_xmlAttr, ns),
     72usize,
     concat!(
       "Offset of field: ",
@@ -3383,7 +3384,7 @@ fn bindgen_test_layout__xmlAttr() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlAttr>())).atype as *const _ as usize },
+    ::std::mem::offset_of!(_xmlAttr, atype),
     80usize,
     concat!(
       "Offset of field: ",
@@ -3393,7 +3394,7 @@ fn bindgen_test_layout__xmlAttr() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlAttr>())).psvi as *const _ as usize },
+    ::std::mem::offset_of!(_xmlAttr, psvi),
     88usize,
     concat!(
       "Offset of field: ",
@@ -3431,7 +3432,7 @@ fn bindgen_test_layout__xmlID() {
     concat!("Alignment of ", stringify!(_xmlID))
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlID>())).next as *const _ as usize },
+    ::std::mem::offset_of!(_xmlID, next),
     0usize,
     concat!(
       "Offset of field: ",
@@ -3441,7 +3442,7 @@ fn bindgen_test_layout__xmlID() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlID>())).value as *const _ as usize },
+    ::std::mem::offset_of!(_xmlID, value),
     8usize,
     concat!(
       "Offset of field: ",
@@ -3451,7 +3452,7 @@ fn bindgen_test_layout__xmlID() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlID>())).attr as *const _ as usize },
+    ::std::mem::offset_of!(_xmlID, attr),
     16usize,
     concat!(
       "Offset of field: ",
@@ -3461,7 +3462,7 @@ fn bindgen_test_layout__xmlID() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlID>())).name as *const _ as usize },
+    ::std::mem::offset_of!(_xmlID, name),
     24usize,
     concat!(
       "Offset of field: ",
@@ -3471,7 +3472,7 @@ fn bindgen_test_layout__xmlID() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlID>())).lineno as *const _ as usize },
+    ::std::mem::offset_of!(_xmlID, lineno),
     32usize,
     concat!(
       "Offset of field: ",
@@ -3481,7 +3482,7 @@ fn bindgen_test_layout__xmlID() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlID>())).doc as *const _ as usize },
+    ::std::mem::offset_of!(_xmlID, doc),
     40usize,
     concat!(
       "Offset of field: ",
@@ -3518,7 +3519,7 @@ fn bindgen_test_layout__xmlRef() {
     concat!("Alignment of ", stringify!(_xmlRef))
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlRef>())).next as *const _ as usize },
+    ::std::mem::offset_of!(_xmlRef, next),
     0usize,
     concat!(
       "Offset of field: ",
@@ -3528,7 +3529,7 @@ fn bindgen_test_layout__xmlRef() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlRef>())).value as *const _ as usize },
+    ::std::mem::offset_of!(_xmlRef, value),
     8usize,
     concat!(
       "Offset of field: ",
@@ -3538,7 +3539,7 @@ fn bindgen_test_layout__xmlRef() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlRef>())).attr as *const _ as usize },
+    ::std::mem::offset_of!(_xmlRef, attr),
     16usize,
     concat!(
       "Offset of field: ",
@@ -3548,7 +3549,7 @@ fn bindgen_test_layout__xmlRef() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlRef>())).name as *const _ as usize },
+    ::std::mem::offset_of!(_xmlRef, name),
     24usize,
     concat!(
       "Offset of field: ",
@@ -3558,7 +3559,7 @@ fn bindgen_test_layout__xmlRef() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlRef>())).lineno as *const _ as usize },
+    ::std::mem::offset_of!(_xmlRef, lineno),
     32usize,
     concat!(
       "Offset of field: ",
@@ -3606,7 +3607,7 @@ fn bindgen_test_layout__xmlNode() {
     concat!("Alignment of ", stringify!(_xmlNode))
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlNode>()))._private as *const _ as usize },
+    ::std::mem::offset_of!(_xmlNode, _private),
     0usize,
     concat!(
       "Offset of field: ",
@@ -3616,7 +3617,7 @@ fn bindgen_test_layout__xmlNode() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlNode>())).type_ as *const _ as usize },
+    ::std::mem::offset_of!(_xmlNode, type_),
     8usize,
     concat!(
       "Offset of field: ",
@@ -3626,7 +3627,7 @@ fn bindgen_test_layout__xmlNode() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlNode>())).name as *const _ as usize },
+    ::std::mem::offset_of!(_xmlNode, name),
     16usize,
     concat!(
       "Offset of field: ",
@@ -3636,7 +3637,7 @@ fn bindgen_test_layout__xmlNode() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlNode>())).children as *const _ as usize },
+    ::std::mem::offset_of!(_xmlNode, children),
     24usize,
     concat!(
       "Offset of field: ",
@@ -3646,7 +3647,7 @@ fn bindgen_test_layout__xmlNode() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlNode>())).last as *const _ as usize },
+    ::std::mem::offset_of!(_xmlNode, last),
     32usize,
     concat!(
       "Offset of field: ",
@@ -3656,7 +3657,7 @@ fn bindgen_test_layout__xmlNode() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlNode>())).parent as *const _ as usize },
+    ::std::mem::offset_of!(_xmlNode, parent),
     40usize,
     concat!(
       "Offset of field: ",
@@ -3666,7 +3667,7 @@ fn bindgen_test_layout__xmlNode() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlNode>())).next as *const _ as usize },
+    ::std::mem::offset_of!(_xmlNode, next),
     48usize,
     concat!(
       "Offset of field: ",
@@ -3676,7 +3677,7 @@ fn bindgen_test_layout__xmlNode() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlNode>())).prev as *const _ as usize },
+    ::std::mem::offset_of!(_xmlNode, prev),
     56usize,
     concat!(
       "Offset of field: ",
@@ -3686,7 +3687,7 @@ fn bindgen_test_layout__xmlNode() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlNode>())).doc as *const _ as usize },
+    ::std::mem::offset_of!(_xmlNode, doc),
     64usize,
     concat!(
       "Offset of field: ",
@@ -3696,7 +3697,7 @@ fn bindgen_test_layout__xmlNode() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlNode>())).ns as *const _ as usize },
+    ::std::mem::offset_of!(_xmlNode, ns),
     72usize,
     concat!(
       "Offset of field: ",
@@ -3706,7 +3707,7 @@ fn bindgen_test_layout__xmlNode() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlNode>())).content as *const _ as usize },
+    ::std::mem::offset_of!(_xmlNode, content),
     80usize,
     concat!(
       "Offset of field: ",
@@ -3716,7 +3717,7 @@ fn bindgen_test_layout__xmlNode() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlNode>())).properties as *const _ as usize },
+    ::std::mem::offset_of!(_xmlNode, properties),
     88usize,
     concat!(
       "Offset of field: ",
@@ -3726,7 +3727,7 @@ fn bindgen_test_layout__xmlNode() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlNode>())).nsDef as *const _ as usize },
+    ::std::mem::offset_of!(_xmlNode, nsDef),
     96usize,
     concat!(
       "Offset of field: ",
@@ -3736,7 +3737,7 @@ fn bindgen_test_layout__xmlNode() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlNode>())).psvi as *const _ as usize },
+    ::std::mem::offset_of!(_xmlNode, psvi),
     104usize,
     concat!(
       "Offset of field: ",
@@ -3746,7 +3747,7 @@ fn bindgen_test_layout__xmlNode() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlNode>())).line as *const _ as usize },
+    ::std::mem::offset_of!(_xmlNode, line),
     112usize,
     concat!(
       "Offset of field: ",
@@ -3756,7 +3757,7 @@ fn bindgen_test_layout__xmlNode() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlNode>())).extra as *const _ as usize },
+    ::std::mem::offset_of!(_xmlNode, extra),
     114usize,
     concat!(
       "Offset of field: ",
@@ -3825,7 +3826,7 @@ fn bindgen_test_layout__xmlDoc() {
     concat!("Alignment of ", stringify!(_xmlDoc))
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlDoc>()))._private as *const _ as usize },
+    ::std::mem::offset_of!(_xmlDoc, _private),
     0usize,
     concat!(
       "Offset of field: ",
@@ -3835,7 +3836,7 @@ fn bindgen_test_layout__xmlDoc() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlDoc>())).type_ as *const _ as usize },
+    ::std::mem::offset_of!(_xmlDoc, type_),
     8usize,
     concat!(
       "Offset of field: ",
@@ -3845,7 +3846,7 @@ fn bindgen_test_layout__xmlDoc() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlDoc>())).name as *const _ as usize },
+    ::std::mem::offset_of!(_xmlDoc, name),
     16usize,
     concat!(
       "Offset of field: ",
@@ -3855,7 +3856,7 @@ fn bindgen_test_layout__xmlDoc() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlDoc>())).children as *const _ as usize },
+    ::std::mem::offset_of!(_xmlDoc, children),
     24usize,
     concat!(
       "Offset of field: ",
@@ -3865,7 +3866,7 @@ fn bindgen_test_layout__xmlDoc() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlDoc>())).last as *const _ as usize },
+    ::std::mem::offset_of!(_xmlDoc, last),
     32usize,
     concat!(
       "Offset of field: ",
@@ -3875,7 +3876,7 @@ fn bindgen_test_layout__xmlDoc() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlDoc>())).parent as *const _ as usize },
+    ::std::mem::offset_of!(_xmlDoc, parent),
     40usize,
     concat!(
       "Offset of field: ",
@@ -3885,7 +3886,7 @@ fn bindgen_test_layout__xmlDoc() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlDoc>())).next as *const _ as usize },
+    ::std::mem::offset_of!(_xmlDoc, next),
     48usize,
     concat!(
       "Offset of field: ",
@@ -3895,7 +3896,7 @@ fn bindgen_test_layout__xmlDoc() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlDoc>())).prev as *const _ as usize },
+    ::std::mem::offset_of!(_xmlDoc, prev),
     56usize,
     concat!(
       "Offset of field: ",
@@ -3905,7 +3906,7 @@ fn bindgen_test_layout__xmlDoc() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlDoc>())).doc as *const _ as usize },
+    ::std::mem::offset_of!(_xmlDoc, doc),
     64usize,
     concat!(
       "Offset of field: ",
@@ -3915,7 +3916,7 @@ fn bindgen_test_layout__xmlDoc() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlDoc>())).compression as *const _ as usize },
+    ::std::mem::offset_of!(_xmlDoc, compression),
     72usize,
     concat!(
       "Offset of field: ",
@@ -3925,7 +3926,7 @@ fn bindgen_test_layout__xmlDoc() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlDoc>())).standalone as *const _ as usize },
+    ::std::mem::offset_of!(_xmlDoc, standalone),
     76usize,
     concat!(
       "Offset of field: ",
@@ -3935,7 +3936,7 @@ fn bindgen_test_layout__xmlDoc() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlDoc>())).intSubset as *const _ as usize },
+    ::std::mem::offset_of!(_xmlDoc, intSubset),
     80usize,
     concat!(
       "Offset of field: ",
@@ -3945,7 +3946,7 @@ fn bindgen_test_layout__xmlDoc() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlDoc>())).extSubset as *const _ as usize },
+    ::std::mem::offset_of!(_xmlDoc, extSubset),
     88usize,
     concat!(
       "Offset of field: ",
@@ -3955,7 +3956,7 @@ fn bindgen_test_layout__xmlDoc() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlDoc>())).oldNs as *const _ as usize },
+    ::std::mem::offset_of!(_xmlDoc, oldNs),
     96usize,
     concat!(
       "Offset of field: ",
@@ -3965,7 +3966,7 @@ fn bindgen_test_layout__xmlDoc() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlDoc>())).version as *const _ as usize },
+    ::std::mem::offset_of!(_xmlDoc, version),
     104usize,
     concat!(
       "Offset of field: ",
@@ -3975,7 +3976,7 @@ fn bindgen_test_layout__xmlDoc() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlDoc>())).encoding as *const _ as usize },
+    ::std::mem::offset_of!(_xmlDoc, encoding),
     112usize,
     concat!(
       "Offset of field: ",
@@ -3985,7 +3986,7 @@ fn bindgen_test_layout__xmlDoc() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlDoc>())).ids as *const _ as usize },
+    ::std::mem::offset_of!(_xmlDoc, ids),
     120usize,
     concat!(
       "Offset of field: ",
@@ -3995,7 +3996,7 @@ fn bindgen_test_layout__xmlDoc() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlDoc>())).refs as *const _ as usize },
+    ::std::mem::offset_of!(_xmlDoc, refs),
     128usize,
     concat!(
       "Offset of field: ",
@@ -4005,7 +4006,7 @@ fn bindgen_test_layout__xmlDoc() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlDoc>())).URL as *const _ as usize },
+    ::std::mem::offset_of!(_xmlDoc, URL),
     136usize,
     concat!(
       "Offset of field: ",
@@ -4015,7 +4016,7 @@ fn bindgen_test_layout__xmlDoc() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlDoc>())).charset as *const _ as usize },
+    ::std::mem::offset_of!(_xmlDoc, charset),
     144usize,
     concat!(
       "Offset of field: ",
@@ -4025,7 +4026,7 @@ fn bindgen_test_layout__xmlDoc() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlDoc>())).dict as *const _ as usize },
+    ::std::mem::offset_of!(_xmlDoc, dict),
     152usize,
     concat!(
       "Offset of field: ",
@@ -4035,7 +4036,7 @@ fn bindgen_test_layout__xmlDoc() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlDoc>())).psvi as *const _ as usize },
+    ::std::mem::offset_of!(_xmlDoc, psvi),
     160usize,
     concat!(
       "Offset of field: ",
@@ -4045,7 +4046,7 @@ fn bindgen_test_layout__xmlDoc() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlDoc>())).parseFlags as *const _ as usize },
+    ::std::mem::offset_of!(_xmlDoc, parseFlags),
     168usize,
     concat!(
       "Offset of field: ",
@@ -4055,7 +4056,7 @@ fn bindgen_test_layout__xmlDoc() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlDoc>())).properties as *const _ as usize },
+    ::std::mem::offset_of!(_xmlDoc, properties),
     172usize,
     concat!(
       "Offset of field: ",
@@ -4108,7 +4109,7 @@ fn bindgen_test_layout__xmlDOMWrapCtxt() {
     concat!("Alignment of ", stringify!(_xmlDOMWrapCtxt))
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlDOMWrapCtxt>()))._private as *const _ as usize },
+    ::std::mem::offset_of!(_xmlDOMWrapCtxt, _private),
     0usize,
     concat!(
       "Offset of field: ",
@@ -4118,7 +4119,7 @@ fn bindgen_test_layout__xmlDOMWrapCtxt() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlDOMWrapCtxt>())).type_ as *const _ as usize },
+    ::std::mem::offset_of!(_xmlDOMWrapCtxt, type_),
     8usize,
     concat!(
       "Offset of field: ",
@@ -4128,7 +4129,7 @@ fn bindgen_test_layout__xmlDOMWrapCtxt() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlDOMWrapCtxt>())).namespaceMap as *const _ as usize },
+    ::std::mem::offset_of!(_xmlDOMWrapCtxt, namespaceMap),
     16usize,
     concat!(
       "Offset of field: ",
@@ -4138,7 +4139,7 @@ fn bindgen_test_layout__xmlDOMWrapCtxt() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlDOMWrapCtxt>())).getNsForNodeFunc as *const _ as usize },
+    ::std::mem::offset_of!(_xmlDOMWrapCtxt, getNsForNodeFunc),
     24usize,
     concat!(
       "Offset of field: ",
@@ -5142,7 +5143,7 @@ fn bindgen_test_layout__xmlError() {
     concat!("Alignment of ", stringify!(_xmlError))
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlError>())).domain as *const _ as usize },
+    ::std::mem::offset_of!(_xmlError, domain),
     0usize,
     concat!(
       "Offset of field: ",
@@ -5152,7 +5153,7 @@ fn bindgen_test_layout__xmlError() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlError>())).code as *const _ as usize },
+    ::std::mem::offset_of!(_xmlError, code),
     4usize,
     concat!(
       "Offset of field: ",
@@ -5162,7 +5163,7 @@ fn bindgen_test_layout__xmlError() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlError>())).message as *const _ as usize },
+    ::std::mem::offset_of!(_xmlError, message),
     8usize,
     concat!(
       "Offset of field: ",
@@ -5172,7 +5173,7 @@ fn bindgen_test_layout__xmlError() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlError>())).level as *const _ as usize },
+    ::std::mem::offset_of!(_xmlError, level),
     16usize,
     concat!(
       "Offset of field: ",
@@ -5182,7 +5183,7 @@ fn bindgen_test_layout__xmlError() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlError>())).file as *const _ as usize },
+    ::std::mem::offset_of!(_xmlError, file),
     24usize,
     concat!(
       "Offset of field: ",
@@ -5192,7 +5193,7 @@ fn bindgen_test_layout__xmlError() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlError>())).line as *const _ as usize },
+    ::std::mem::offset_of!(_xmlError, line),
     32usize,
     concat!(
       "Offset of field: ",
@@ -5202,7 +5203,7 @@ fn bindgen_test_layout__xmlError() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlError>())).str1 as *const _ as usize },
+    ::std::mem::offset_of!(_xmlError, str1),
     40usize,
     concat!(
       "Offset of field: ",
@@ -5212,7 +5213,7 @@ fn bindgen_test_layout__xmlError() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlError>())).str2 as *const _ as usize },
+    ::std::mem::offset_of!(_xmlError, str2),
     48usize,
     concat!(
       "Offset of field: ",
@@ -5222,7 +5223,7 @@ fn bindgen_test_layout__xmlError() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlError>())).str3 as *const _ as usize },
+    ::std::mem::offset_of!(_xmlError, str3),
     56usize,
     concat!(
       "Offset of field: ",
@@ -5232,7 +5233,7 @@ fn bindgen_test_layout__xmlError() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlError>())).int1 as *const _ as usize },
+    ::std::mem::offset_of!(_xmlError, int1),
     64usize,
     concat!(
       "Offset of field: ",
@@ -5242,7 +5243,7 @@ fn bindgen_test_layout__xmlError() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlError>())).int2 as *const _ as usize },
+    ::std::mem::offset_of!(_xmlError, int2),
     68usize,
     concat!(
       "Offset of field: ",
@@ -5252,7 +5253,7 @@ fn bindgen_test_layout__xmlError() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlError>())).ctxt as *const _ as usize },
+    ::std::mem::offset_of!(_xmlError, ctxt),
     72usize,
     concat!(
       "Offset of field: ",
@@ -5262,7 +5263,7 @@ fn bindgen_test_layout__xmlError() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlError>())).node as *const _ as usize },
+    ::std::mem::offset_of!(_xmlError, node),
     80usize,
     concat!(
       "Offset of field: ",
@@ -6457,7 +6458,7 @@ fn bindgen_test_layout__xmlValidCtxt() {
     concat!("Alignment of ", stringify!(_xmlValidCtxt))
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlValidCtxt>())).userData as *const _ as usize },
+    ::std::mem::offset_of!(_xmlValidCtxt, userData),
     0usize,
     concat!(
       "Offset of field: ",
@@ -6467,7 +6468,7 @@ fn bindgen_test_layout__xmlValidCtxt() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlValidCtxt>())).error as *const _ as usize },
+    ::std::mem::offset_of!(_xmlValidCtxt, error),
     8usize,
     concat!(
       "Offset of field: ",
@@ -6477,7 +6478,7 @@ fn bindgen_test_layout__xmlValidCtxt() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlValidCtxt>())).warning as *const _ as usize },
+    ::std::mem::offset_of!(_xmlValidCtxt, warning),
     16usize,
     concat!(
       "Offset of field: ",
@@ -6487,7 +6488,7 @@ fn bindgen_test_layout__xmlValidCtxt() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlValidCtxt>())).node as *const _ as usize },
+    ::std::mem::offset_of!(_xmlValidCtxt, node),
     24usize,
     concat!(
       "Offset of field: ",
@@ -6497,7 +6498,7 @@ fn bindgen_test_layout__xmlValidCtxt() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlValidCtxt>())).nodeNr as *const _ as usize },
+    ::std::mem::offset_of!(_xmlValidCtxt, nodeNr),
     32usize,
     concat!(
       "Offset of field: ",
@@ -6507,7 +6508,7 @@ fn bindgen_test_layout__xmlValidCtxt() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlValidCtxt>())).nodeMax as *const _ as usize },
+    ::std::mem::offset_of!(_xmlValidCtxt, nodeMax),
     36usize,
     concat!(
       "Offset of field: ",
@@ -6517,7 +6518,7 @@ fn bindgen_test_layout__xmlValidCtxt() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlValidCtxt>())).nodeTab as *const _ as usize },
+    ::std::mem::offset_of!(_xmlValidCtxt, nodeTab),
     40usize,
     concat!(
       "Offset of field: ",
@@ -6527,7 +6528,7 @@ fn bindgen_test_layout__xmlValidCtxt() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlValidCtxt>())).finishDtd as *const _ as usize },
+    ::std::mem::offset_of!(_xmlValidCtxt, finishDtd),
     48usize,
     concat!(
       "Offset of field: ",
@@ -6537,7 +6538,7 @@ fn bindgen_test_layout__xmlValidCtxt() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlValidCtxt>())).doc as *const _ as usize },
+    ::std::mem::offset_of!(_xmlValidCtxt, doc),
     56usize,
     concat!(
       "Offset of field: ",
@@ -6547,7 +6548,7 @@ fn bindgen_test_layout__xmlValidCtxt() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlValidCtxt>())).valid as *const _ as usize },
+    ::std::mem::offset_of!(_xmlValidCtxt, valid),
     64usize,
     concat!(
       "Offset of field: ",
@@ -6557,7 +6558,7 @@ fn bindgen_test_layout__xmlValidCtxt() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlValidCtxt>())).vstate as *const _ as usize },
+    ::std::mem::offset_of!(_xmlValidCtxt, vstate),
     72usize,
     concat!(
       "Offset of field: ",
@@ -6567,7 +6568,7 @@ fn bindgen_test_layout__xmlValidCtxt() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlValidCtxt>())).vstateNr as *const _ as usize },
+    ::std::mem::offset_of!(_xmlValidCtxt, vstateNr),
     80usize,
     concat!(
       "Offset of field: ",
@@ -6577,7 +6578,7 @@ fn bindgen_test_layout__xmlValidCtxt() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlValidCtxt>())).vstateMax as *const _ as usize },
+    ::std::mem::offset_of!(_xmlValidCtxt, vstateMax),
     84usize,
     concat!(
       "Offset of field: ",
@@ -6587,7 +6588,7 @@ fn bindgen_test_layout__xmlValidCtxt() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlValidCtxt>())).vstateTab as *const _ as usize },
+    ::std::mem::offset_of!(_xmlValidCtxt, vstateTab),
     88usize,
     concat!(
       "Offset of field: ",
@@ -6597,7 +6598,7 @@ fn bindgen_test_layout__xmlValidCtxt() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlValidCtxt>())).am as *const _ as usize },
+    ::std::mem::offset_of!(_xmlValidCtxt, am),
     96usize,
     concat!(
       "Offset of field: ",
@@ -6607,7 +6608,7 @@ fn bindgen_test_layout__xmlValidCtxt() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlValidCtxt>())).state as *const _ as usize },
+    ::std::mem::offset_of!(_xmlValidCtxt, state),
     104usize,
     concat!(
       "Offset of field: ",
@@ -7026,7 +7027,7 @@ fn bindgen_test_layout__xmlEntity() {
     concat!("Alignment of ", stringify!(_xmlEntity))
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlEntity>()))._private as *const _ as usize },
+    ::std::mem::offset_of!(_xmlEntity, _private),
     0usize,
     concat!(
       "Offset of field: ",
@@ -7036,7 +7037,7 @@ fn bindgen_test_layout__xmlEntity() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlEntity>())).type_ as *const _ as usize },
+    ::std::mem::offset_of!(_xmlEntity, type_),
     8usize,
     concat!(
       "Offset of field: ",
@@ -7046,7 +7047,7 @@ fn bindgen_test_layout__xmlEntity() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlEntity>())).name as *const _ as usize },
+    ::std::mem::offset_of!(_xmlEntity, name),
     16usize,
     concat!(
       "Offset of field: ",
@@ -7056,7 +7057,7 @@ fn bindgen_test_layout__xmlEntity() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlEntity>())).children as *const _ as usize },
+    ::std::mem::offset_of!(_xmlEntity, children),
     24usize,
     concat!(
       "Offset of field: ",
@@ -7066,7 +7067,7 @@ fn bindgen_test_layout__xmlEntity() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlEntity>())).last as *const _ as usize },
+    ::std::mem::offset_of!(_xmlEntity, last),
     32usize,
     concat!(
       "Offset of field: ",
@@ -7076,7 +7077,7 @@ fn bindgen_test_layout__xmlEntity() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlEntity>())).parent as *const _ as usize },
+    ::std::mem::offset_of!(_xmlEntity, parent),
     40usize,
     concat!(
       "Offset of field: ",
@@ -7086,7 +7087,7 @@ fn bindgen_test_layout__xmlEntity() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlEntity>())).next as *const _ as usize },
+    ::std::mem::offset_of!(_xmlEntity, next),
     48usize,
     concat!(
       "Offset of field: ",
@@ -7096,7 +7097,7 @@ fn bindgen_test_layout__xmlEntity() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlEntity>())).prev as *const _ as usize },
+    ::std::mem::offset_of!(_xmlEntity, prev),
     56usize,
     concat!(
       "Offset of field: ",
@@ -7106,7 +7107,7 @@ fn bindgen_test_layout__xmlEntity() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlEntity>())).doc as *const _ as usize },
+    ::std::mem::offset_of!(_xmlEntity, doc),
     64usize,
     concat!(
       "Offset of field: ",
@@ -7116,7 +7117,7 @@ fn bindgen_test_layout__xmlEntity() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlEntity>())).orig as *const _ as usize },
+    ::std::mem::offset_of!(_xmlEntity, orig),
     72usize,
     concat!(
       "Offset of field: ",
@@ -7126,7 +7127,7 @@ fn bindgen_test_layout__xmlEntity() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlEntity>())).content as *const _ as usize },
+    ::std::mem::offset_of!(_xmlEntity, content),
     80usize,
     concat!(
       "Offset of field: ",
@@ -7136,7 +7137,7 @@ fn bindgen_test_layout__xmlEntity() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlEntity>())).length as *const _ as usize },
+    ::std::mem::offset_of!(_xmlEntity, length),
     88usize,
     concat!(
       "Offset of field: ",
@@ -7146,7 +7147,7 @@ fn bindgen_test_layout__xmlEntity() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlEntity>())).etype as *const _ as usize },
+    ::std::mem::offset_of!(_xmlEntity, etype),
     92usize,
     concat!(
       "Offset of field: ",
@@ -7156,7 +7157,7 @@ fn bindgen_test_layout__xmlEntity() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlEntity>())).ExternalID as *const _ as usize },
+    ::std::mem::offset_of!(_xmlEntity, ExternalID),
     96usize,
     concat!(
       "Offset of field: ",
@@ -7166,7 +7167,7 @@ fn bindgen_test_layout__xmlEntity() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlEntity>())).SystemID as *const _ as usize },
+    ::std::mem::offset_of!(_xmlEntity, SystemID),
     104usize,
     concat!(
       "Offset of field: ",
@@ -7176,7 +7177,7 @@ fn bindgen_test_layout__xmlEntity() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlEntity>())).nexte as *const _ as usize },
+    ::std::mem::offset_of!(_xmlEntity, nexte),
     112usize,
     concat!(
       "Offset of field: ",
@@ -7186,7 +7187,7 @@ fn bindgen_test_layout__xmlEntity() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlEntity>())).URI as *const _ as usize },
+    ::std::mem::offset_of!(_xmlEntity, URI),
     120usize,
     concat!(
       "Offset of field: ",
@@ -7196,7 +7197,7 @@ fn bindgen_test_layout__xmlEntity() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlEntity>())).owner as *const _ as usize },
+    ::std::mem::offset_of!(_xmlEntity, owner),
     128usize,
     concat!(
       "Offset of field: ",
@@ -7206,7 +7207,7 @@ fn bindgen_test_layout__xmlEntity() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlEntity>())).checked as *const _ as usize },
+    ::std::mem::offset_of!(_xmlEntity, checked),
     132usize,
     concat!(
       "Offset of field: ",
@@ -7327,7 +7328,7 @@ fn bindgen_test_layout__xmlParserInput() {
     concat!("Alignment of ", stringify!(_xmlParserInput))
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserInput>())).buf as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserInput, buf),
     0usize,
     concat!(
       "Offset of field: ",
@@ -7337,7 +7338,7 @@ fn bindgen_test_layout__xmlParserInput() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserInput>())).filename as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserInput, filename),
     8usize,
     concat!(
       "Offset of field: ",
@@ -7347,7 +7348,7 @@ fn bindgen_test_layout__xmlParserInput() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserInput>())).directory as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserInput, directory),
     16usize,
     concat!(
       "Offset of field: ",
@@ -7357,7 +7358,7 @@ fn bindgen_test_layout__xmlParserInput() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserInput>())).base as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserInput, base),
     24usize,
     concat!(
       "Offset of field: ",
@@ -7367,7 +7368,7 @@ fn bindgen_test_layout__xmlParserInput() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserInput>())).cur as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserInput, cur),
     32usize,
     concat!(
       "Offset of field: ",
@@ -7377,7 +7378,7 @@ fn bindgen_test_layout__xmlParserInput() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserInput>())).end as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserInput, end),
     40usize,
     concat!(
       "Offset of field: ",
@@ -7387,7 +7388,7 @@ fn bindgen_test_layout__xmlParserInput() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserInput>())).length as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserInput, length),
     48usize,
     concat!(
       "Offset of field: ",
@@ -7397,7 +7398,7 @@ fn bindgen_test_layout__xmlParserInput() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserInput>())).line as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserInput, line),
     52usize,
     concat!(
       "Offset of field: ",
@@ -7407,7 +7408,7 @@ fn bindgen_test_layout__xmlParserInput() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserInput>())).col as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserInput, col),
     56usize,
     concat!(
       "Offset of field: ",
@@ -7417,7 +7418,7 @@ fn bindgen_test_layout__xmlParserInput() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserInput>())).consumed as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserInput, consumed),
     64usize,
     concat!(
       "Offset of field: ",
@@ -7427,7 +7428,7 @@ fn bindgen_test_layout__xmlParserInput() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserInput>())).free as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserInput, free),
     72usize,
     concat!(
       "Offset of field: ",
@@ -7437,7 +7438,7 @@ fn bindgen_test_layout__xmlParserInput() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserInput>())).encoding as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserInput, encoding),
     80usize,
     concat!(
       "Offset of field: ",
@@ -7447,7 +7448,7 @@ fn bindgen_test_layout__xmlParserInput() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserInput>())).version as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserInput, version),
     88usize,
     concat!(
       "Offset of field: ",
@@ -7457,7 +7458,7 @@ fn bindgen_test_layout__xmlParserInput() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserInput>())).standalone as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserInput, standalone),
     96usize,
     concat!(
       "Offset of field: ",
@@ -7467,7 +7468,7 @@ fn bindgen_test_layout__xmlParserInput() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserInput>())).id as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserInput, id),
     100usize,
     concat!(
       "Offset of field: ",
@@ -7506,7 +7507,7 @@ fn bindgen_test_layout__xmlParserNodeInfo() {
     concat!("Alignment of ", stringify!(_xmlParserNodeInfo))
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserNodeInfo>())).node as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserNodeInfo, node),
     0usize,
     concat!(
       "Offset of field: ",
@@ -7516,7 +7517,7 @@ fn bindgen_test_layout__xmlParserNodeInfo() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserNodeInfo>())).begin_pos as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserNodeInfo, begin_pos),
     8usize,
     concat!(
       "Offset of field: ",
@@ -7526,7 +7527,7 @@ fn bindgen_test_layout__xmlParserNodeInfo() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserNodeInfo>())).begin_line as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserNodeInfo, begin_line),
     16usize,
     concat!(
       "Offset of field: ",
@@ -7536,7 +7537,7 @@ fn bindgen_test_layout__xmlParserNodeInfo() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserNodeInfo>())).end_pos as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserNodeInfo, end_pos),
     24usize,
     concat!(
       "Offset of field: ",
@@ -7546,7 +7547,7 @@ fn bindgen_test_layout__xmlParserNodeInfo() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserNodeInfo>())).end_line as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserNodeInfo, end_line),
     32usize,
     concat!(
       "Offset of field: ",
@@ -7578,7 +7579,7 @@ fn bindgen_test_layout__xmlParserNodeInfoSeq() {
     concat!("Alignment of ", stringify!(_xmlParserNodeInfoSeq))
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserNodeInfoSeq>())).maximum as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserNodeInfoSeq, maximum),
     0usize,
     concat!(
       "Offset of field: ",
@@ -7588,7 +7589,7 @@ fn bindgen_test_layout__xmlParserNodeInfoSeq() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserNodeInfoSeq>())).length as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserNodeInfoSeq, length),
     8usize,
     concat!(
       "Offset of field: ",
@@ -7598,7 +7599,7 @@ fn bindgen_test_layout__xmlParserNodeInfoSeq() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserNodeInfoSeq>())).buffer as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserNodeInfoSeq, buffer),
     16usize,
     concat!(
       "Offset of field: ",
@@ -7757,7 +7758,7 @@ fn bindgen_test_layout__xmlParserCtxt() {
     concat!("Alignment of ", stringify!(_xmlParserCtxt))
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserCtxt>())).sax as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserCtxt, sax),
     0usize,
     concat!(
       "Offset of field: ",
@@ -7767,7 +7768,7 @@ fn bindgen_test_layout__xmlParserCtxt() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserCtxt>())).userData as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserCtxt, userData),
     8usize,
     concat!(
       "Offset of field: ",
@@ -7777,7 +7778,7 @@ fn bindgen_test_layout__xmlParserCtxt() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserCtxt>())).myDoc as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserCtxt, myDoc),
     16usize,
     concat!(
       "Offset of field: ",
@@ -7787,7 +7788,7 @@ fn bindgen_test_layout__xmlParserCtxt() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserCtxt>())).wellFormed as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserCtxt, wellFormed),
     24usize,
     concat!(
       "Offset of field: ",
@@ -7797,7 +7798,7 @@ fn bindgen_test_layout__xmlParserCtxt() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserCtxt>())).replaceEntities as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserCtxt, replaceEntities),
     28usize,
     concat!(
       "Offset of field: ",
@@ -7807,7 +7808,7 @@ fn bindgen_test_layout__xmlParserCtxt() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserCtxt>())).version as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserCtxt, version),
     32usize,
     concat!(
       "Offset of field: ",
@@ -7817,7 +7818,7 @@ fn bindgen_test_layout__xmlParserCtxt() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserCtxt>())).encoding as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserCtxt, encoding),
     40usize,
     concat!(
       "Offset of field: ",
@@ -7827,7 +7828,7 @@ fn bindgen_test_layout__xmlParserCtxt() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserCtxt>())).standalone as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserCtxt, standalone),
     48usize,
     concat!(
       "Offset of field: ",
@@ -7837,7 +7838,7 @@ fn bindgen_test_layout__xmlParserCtxt() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserCtxt>())).html as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserCtxt, html),
     52usize,
     concat!(
       "Offset of field: ",
@@ -7847,7 +7848,7 @@ fn bindgen_test_layout__xmlParserCtxt() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserCtxt>())).input as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserCtxt, input),
     56usize,
     concat!(
       "Offset of field: ",
@@ -7857,7 +7858,7 @@ fn bindgen_test_layout__xmlParserCtxt() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserCtxt>())).inputNr as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserCtxt, inputNr),
     64usize,
     concat!(
       "Offset of field: ",
@@ -7867,7 +7868,7 @@ fn bindgen_test_layout__xmlParserCtxt() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserCtxt>())).inputMax as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserCtxt, inputMax),
     68usize,
     concat!(
       "Offset of field: ",
@@ -7877,7 +7878,7 @@ fn bindgen_test_layout__xmlParserCtxt() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserCtxt>())).inputTab as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserCtxt, inputTab),
     72usize,
     concat!(
       "Offset of field: ",
@@ -7887,7 +7888,7 @@ fn bindgen_test_layout__xmlParserCtxt() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserCtxt>())).node as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserCtxt, node),
     80usize,
     concat!(
       "Offset of field: ",
@@ -7897,7 +7898,7 @@ fn bindgen_test_layout__xmlParserCtxt() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserCtxt>())).nodeNr as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserCtxt, nodeNr),
     88usize,
     concat!(
       "Offset of field: ",
@@ -7907,7 +7908,7 @@ fn bindgen_test_layout__xmlParserCtxt() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserCtxt>())).nodeMax as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserCtxt, nodeMax),
     92usize,
     concat!(
       "Offset of field: ",
@@ -7917,7 +7918,7 @@ fn bindgen_test_layout__xmlParserCtxt() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserCtxt>())).nodeTab as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserCtxt, nodeTab),
     96usize,
     concat!(
       "Offset of field: ",
@@ -7927,7 +7928,7 @@ fn bindgen_test_layout__xmlParserCtxt() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserCtxt>())).record_info as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserCtxt, record_info),
     104usize,
     concat!(
       "Offset of field: ",
@@ -7937,7 +7938,7 @@ fn bindgen_test_layout__xmlParserCtxt() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserCtxt>())).node_seq as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserCtxt, node_seq),
     112usize,
     concat!(
       "Offset of field: ",
@@ -7947,7 +7948,7 @@ fn bindgen_test_layout__xmlParserCtxt() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserCtxt>())).errNo as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserCtxt, errNo),
     136usize,
     concat!(
       "Offset of field: ",
@@ -7957,7 +7958,7 @@ fn bindgen_test_layout__xmlParserCtxt() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserCtxt>())).hasExternalSubset as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserCtxt, hasExternalSubset),
     140usize,
     concat!(
       "Offset of field: ",
@@ -7967,7 +7968,7 @@ fn bindgen_test_layout__xmlParserCtxt() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserCtxt>())).hasPErefs as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserCtxt, hasPErefs),
     144usize,
     concat!(
       "Offset of field: ",
@@ -7977,7 +7978,7 @@ fn bindgen_test_layout__xmlParserCtxt() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserCtxt>())).external as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserCtxt, external),
     148usize,
     concat!(
       "Offset of field: ",
@@ -7987,7 +7988,7 @@ fn bindgen_test_layout__xmlParserCtxt() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserCtxt>())).valid as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserCtxt, valid),
     152usize,
     concat!(
       "Offset of field: ",
@@ -7997,7 +7998,7 @@ fn bindgen_test_layout__xmlParserCtxt() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserCtxt>())).validate as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserCtxt, validate),
     156usize,
     concat!(
       "Offset of field: ",
@@ -8007,7 +8008,7 @@ fn bindgen_test_layout__xmlParserCtxt() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserCtxt>())).vctxt as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserCtxt, vctxt),
     160usize,
     concat!(
       "Offset of field: ",
@@ -8017,7 +8018,7 @@ fn bindgen_test_layout__xmlParserCtxt() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserCtxt>())).instate as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserCtxt, instate),
     272usize,
     concat!(
       "Offset of field: ",
@@ -8027,7 +8028,7 @@ fn bindgen_test_layout__xmlParserCtxt() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserCtxt>())).token as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserCtxt, token),
     276usize,
     concat!(
       "Offset of field: ",
@@ -8037,7 +8038,7 @@ fn bindgen_test_layout__xmlParserCtxt() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserCtxt>())).directory as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserCtxt, directory),
     280usize,
     concat!(
       "Offset of field: ",
@@ -8047,7 +8048,7 @@ fn bindgen_test_layout__xmlParserCtxt() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserCtxt>())).name as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserCtxt, name),
     288usize,
     concat!(
       "Offset of field: ",
@@ -8057,7 +8058,7 @@ fn bindgen_test_layout__xmlParserCtxt() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserCtxt>())).nameNr as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserCtxt, nameNr),
     296usize,
     concat!(
       "Offset of field: ",
@@ -8067,7 +8068,7 @@ fn bindgen_test_layout__xmlParserCtxt() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserCtxt>())).nameMax as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserCtxt, nameMax),
     300usize,
     concat!(
       "Offset of field: ",
@@ -8077,7 +8078,7 @@ fn bindgen_test_layout__xmlParserCtxt() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserCtxt>())).nameTab as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserCtxt, nameTab),
     304usize,
     concat!(
       "Offset of field: ",
@@ -8087,7 +8088,7 @@ fn bindgen_test_layout__xmlParserCtxt() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserCtxt>())).nbChars as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserCtxt, nbChars),
     312usize,
     concat!(
       "Offset of field: ",
@@ -8097,7 +8098,7 @@ fn bindgen_test_layout__xmlParserCtxt() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserCtxt>())).checkIndex as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserCtxt, checkIndex),
     320usize,
     concat!(
       "Offset of field: ",
@@ -8107,7 +8108,7 @@ fn bindgen_test_layout__xmlParserCtxt() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserCtxt>())).keepBlanks as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserCtxt, keepBlanks),
     328usize,
     concat!(
       "Offset of field: ",
@@ -8117,7 +8118,7 @@ fn bindgen_test_layout__xmlParserCtxt() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserCtxt>())).disableSAX as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserCtxt, disableSAX),
     332usize,
     concat!(
       "Offset of field: ",
@@ -8127,7 +8128,7 @@ fn bindgen_test_layout__xmlParserCtxt() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserCtxt>())).inSubset as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserCtxt, inSubset),
     336usize,
     concat!(
       "Offset of field: ",
@@ -8137,7 +8138,7 @@ fn bindgen_test_layout__xmlParserCtxt() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserCtxt>())).intSubName as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserCtxt, intSubName),
     344usize,
     concat!(
       "Offset of field: ",
@@ -8147,7 +8148,7 @@ fn bindgen_test_layout__xmlParserCtxt() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserCtxt>())).extSubURI as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserCtxt, extSubURI),
     352usize,
     concat!(
       "Offset of field: ",
@@ -8157,7 +8158,7 @@ fn bindgen_test_layout__xmlParserCtxt() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserCtxt>())).extSubSystem as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserCtxt, extSubSystem),
     360usize,
     concat!(
       "Offset of field: ",
@@ -8167,7 +8168,7 @@ fn bindgen_test_layout__xmlParserCtxt() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserCtxt>())).space as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserCtxt, space),
     368usize,
     concat!(
       "Offset of field: ",
@@ -8177,7 +8178,7 @@ fn bindgen_test_layout__xmlParserCtxt() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserCtxt>())).spaceNr as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserCtxt, spaceNr),
     376usize,
     concat!(
       "Offset of field: ",
@@ -8187,7 +8188,7 @@ fn bindgen_test_layout__xmlParserCtxt() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserCtxt>())).spaceMax as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserCtxt, spaceMax),
     380usize,
     concat!(
       "Offset of field: ",
@@ -8197,7 +8198,7 @@ fn bindgen_test_layout__xmlParserCtxt() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserCtxt>())).spaceTab as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserCtxt, spaceTab),
     384usize,
     concat!(
       "Offset of field: ",
@@ -8207,7 +8208,7 @@ fn bindgen_test_layout__xmlParserCtxt() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserCtxt>())).depth as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserCtxt, depth),
     392usize,
     concat!(
       "Offset of field: ",
@@ -8217,7 +8218,7 @@ fn bindgen_test_layout__xmlParserCtxt() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserCtxt>())).entity as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserCtxt, entity),
     400usize,
     concat!(
       "Offset of field: ",
@@ -8227,7 +8228,7 @@ fn bindgen_test_layout__xmlParserCtxt() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserCtxt>())).charset as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserCtxt, charset),
     408usize,
     concat!(
       "Offset of field: ",
@@ -8237,7 +8238,7 @@ fn bindgen_test_layout__xmlParserCtxt() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserCtxt>())).nodelen as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserCtxt, nodelen),
     412usize,
     concat!(
       "Offset of field: ",
@@ -8247,7 +8248,7 @@ fn bindgen_test_layout__xmlParserCtxt() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserCtxt>())).nodemem as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserCtxt, nodemem),
     416usize,
     concat!(
       "Offset of field: ",
@@ -8257,7 +8258,7 @@ fn bindgen_test_layout__xmlParserCtxt() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserCtxt>())).pedantic as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserCtxt, pedantic),
     420usize,
     concat!(
       "Offset of field: ",
@@ -8267,7 +8268,7 @@ fn bindgen_test_layout__xmlParserCtxt() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserCtxt>()))._private as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserCtxt, _private),
     424usize,
     concat!(
       "Offset of field: ",
@@ -8277,7 +8278,7 @@ fn bindgen_test_layout__xmlParserCtxt() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserCtxt>())).loadsubset as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserCtxt, loadsubset),
     432usize,
     concat!(
       "Offset of field: ",
@@ -8287,7 +8288,7 @@ fn bindgen_test_layout__xmlParserCtxt() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserCtxt>())).linenumbers as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserCtxt, linenumbers),
     436usize,
     concat!(
       "Offset of field: ",
@@ -8297,7 +8298,7 @@ fn bindgen_test_layout__xmlParserCtxt() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserCtxt>())).catalogs as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserCtxt, catalogs),
     440usize,
     concat!(
       "Offset of field: ",
@@ -8307,7 +8308,7 @@ fn bindgen_test_layout__xmlParserCtxt() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserCtxt>())).recovery as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserCtxt, recovery),
     448usize,
     concat!(
       "Offset of field: ",
@@ -8317,7 +8318,7 @@ fn bindgen_test_layout__xmlParserCtxt() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserCtxt>())).progressive as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserCtxt, progressive),
     452usize,
     concat!(
       "Offset of field: ",
@@ -8327,7 +8328,7 @@ fn bindgen_test_layout__xmlParserCtxt() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserCtxt>())).dict as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserCtxt, dict),
     456usize,
     concat!(
       "Offset of field: ",
@@ -8337,7 +8338,7 @@ fn bindgen_test_layout__xmlParserCtxt() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserCtxt>())).atts as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserCtxt, atts),
     464usize,
     concat!(
       "Offset of field: ",
@@ -8347,7 +8348,7 @@ fn bindgen_test_layout__xmlParserCtxt() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserCtxt>())).maxatts as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserCtxt, maxatts),
     472usize,
     concat!(
       "Offset of field: ",
@@ -8357,7 +8358,7 @@ fn bindgen_test_layout__xmlParserCtxt() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserCtxt>())).docdict as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserCtxt, docdict),
     476usize,
     concat!(
       "Offset of field: ",
@@ -8367,7 +8368,7 @@ fn bindgen_test_layout__xmlParserCtxt() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserCtxt>())).str_xml as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserCtxt, str_xml),
     480usize,
     concat!(
       "Offset of field: ",
@@ -8377,7 +8378,7 @@ fn bindgen_test_layout__xmlParserCtxt() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserCtxt>())).str_xmlns as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserCtxt, str_xmlns),
     488usize,
     concat!(
       "Offset of field: ",
@@ -8387,7 +8388,7 @@ fn bindgen_test_layout__xmlParserCtxt() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserCtxt>())).str_xml_ns as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserCtxt, str_xml_ns),
     496usize,
     concat!(
       "Offset of field: ",
@@ -8397,7 +8398,7 @@ fn bindgen_test_layout__xmlParserCtxt() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserCtxt>())).sax2 as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserCtxt, sax2),
     504usize,
     concat!(
       "Offset of field: ",
@@ -8407,7 +8408,7 @@ fn bindgen_test_layout__xmlParserCtxt() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserCtxt>())).nsNr as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserCtxt, nsNr),
     508usize,
     concat!(
       "Offset of field: ",
@@ -8417,7 +8418,7 @@ fn bindgen_test_layout__xmlParserCtxt() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserCtxt>())).nsMax as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserCtxt, nsMax),
     512usize,
     concat!(
       "Offset of field: ",
@@ -8427,7 +8428,7 @@ fn bindgen_test_layout__xmlParserCtxt() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserCtxt>())).nsTab as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserCtxt, nsTab),
     520usize,
     concat!(
       "Offset of field: ",
@@ -8437,7 +8438,7 @@ fn bindgen_test_layout__xmlParserCtxt() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserCtxt>())).attallocs as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserCtxt, attallocs),
     528usize,
     concat!(
       "Offset of field: ",
@@ -8447,7 +8448,7 @@ fn bindgen_test_layout__xmlParserCtxt() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserCtxt>())).pushTab as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserCtxt, pushTab),
     536usize,
     concat!(
       "Offset of field: ",
@@ -8457,7 +8458,7 @@ fn bindgen_test_layout__xmlParserCtxt() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserCtxt>())).attsDefault as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserCtxt, attsDefault),
     544usize,
     concat!(
       "Offset of field: ",
@@ -8467,7 +8468,7 @@ fn bindgen_test_layout__xmlParserCtxt() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserCtxt>())).attsSpecial as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserCtxt, attsSpecial),
     552usize,
     concat!(
       "Offset of field: ",
@@ -8477,7 +8478,7 @@ fn bindgen_test_layout__xmlParserCtxt() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserCtxt>())).nsWellFormed as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserCtxt, nsWellFormed),
     560usize,
     concat!(
       "Offset of field: ",
@@ -8487,7 +8488,7 @@ fn bindgen_test_layout__xmlParserCtxt() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserCtxt>())).options as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserCtxt, options),
     564usize,
     concat!(
       "Offset of field: ",
@@ -8497,7 +8498,7 @@ fn bindgen_test_layout__xmlParserCtxt() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserCtxt>())).dictNames as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserCtxt, dictNames),
     568usize,
     concat!(
       "Offset of field: ",
@@ -8507,7 +8508,7 @@ fn bindgen_test_layout__xmlParserCtxt() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserCtxt>())).freeElemsNr as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserCtxt, freeElemsNr),
     572usize,
     concat!(
       "Offset of field: ",
@@ -8517,7 +8518,7 @@ fn bindgen_test_layout__xmlParserCtxt() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserCtxt>())).freeElems as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserCtxt, freeElems),
     576usize,
     concat!(
       "Offset of field: ",
@@ -8527,7 +8528,7 @@ fn bindgen_test_layout__xmlParserCtxt() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserCtxt>())).freeAttrsNr as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserCtxt, freeAttrsNr),
     584usize,
     concat!(
       "Offset of field: ",
@@ -8537,7 +8538,7 @@ fn bindgen_test_layout__xmlParserCtxt() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserCtxt>())).freeAttrs as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserCtxt, freeAttrs),
     592usize,
     concat!(
       "Offset of field: ",
@@ -8547,7 +8548,7 @@ fn bindgen_test_layout__xmlParserCtxt() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserCtxt>())).lastError as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserCtxt, lastError),
     600usize,
     concat!(
       "Offset of field: ",
@@ -8557,7 +8558,7 @@ fn bindgen_test_layout__xmlParserCtxt() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserCtxt>())).parseMode as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserCtxt, parseMode),
     688usize,
     concat!(
       "Offset of field: ",
@@ -8567,7 +8568,7 @@ fn bindgen_test_layout__xmlParserCtxt() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserCtxt>())).nbentities as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserCtxt, nbentities),
     696usize,
     concat!(
       "Offset of field: ",
@@ -8577,7 +8578,7 @@ fn bindgen_test_layout__xmlParserCtxt() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserCtxt>())).sizeentities as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserCtxt, sizeentities),
     704usize,
     concat!(
       "Offset of field: ",
@@ -8587,7 +8588,7 @@ fn bindgen_test_layout__xmlParserCtxt() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserCtxt>())).nodeInfo as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserCtxt, nodeInfo),
     712usize,
     concat!(
       "Offset of field: ",
@@ -8597,7 +8598,7 @@ fn bindgen_test_layout__xmlParserCtxt() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserCtxt>())).nodeInfoNr as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserCtxt, nodeInfoNr),
     720usize,
     concat!(
       "Offset of field: ",
@@ -8607,7 +8608,7 @@ fn bindgen_test_layout__xmlParserCtxt() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserCtxt>())).nodeInfoMax as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserCtxt, nodeInfoMax),
     724usize,
     concat!(
       "Offset of field: ",
@@ -8617,7 +8618,7 @@ fn bindgen_test_layout__xmlParserCtxt() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserCtxt>())).nodeInfoTab as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserCtxt, nodeInfoTab),
     728usize,
     concat!(
       "Offset of field: ",
@@ -8627,7 +8628,7 @@ fn bindgen_test_layout__xmlParserCtxt() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserCtxt>())).input_id as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserCtxt, input_id),
     736usize,
     concat!(
       "Offset of field: ",
@@ -8637,7 +8638,7 @@ fn bindgen_test_layout__xmlParserCtxt() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserCtxt>())).sizeentcopy as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserCtxt, sizeentcopy),
     744usize,
     concat!(
       "Offset of field: ",
@@ -8677,7 +8678,7 @@ fn bindgen_test_layout__xmlSAXLocator() {
     concat!("Alignment of ", stringify!(_xmlSAXLocator))
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSAXLocator>())).getPublicId as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSAXLocator, getPublicId),
     0usize,
     concat!(
       "Offset of field: ",
@@ -8687,7 +8688,7 @@ fn bindgen_test_layout__xmlSAXLocator() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSAXLocator>())).getSystemId as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSAXLocator, getSystemId),
     8usize,
     concat!(
       "Offset of field: ",
@@ -8697,7 +8698,7 @@ fn bindgen_test_layout__xmlSAXLocator() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSAXLocator>())).getLineNumber as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSAXLocator, getLineNumber),
     16usize,
     concat!(
       "Offset of field: ",
@@ -8707,7 +8708,7 @@ fn bindgen_test_layout__xmlSAXLocator() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSAXLocator>())).getColumnNumber as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSAXLocator, getColumnNumber),
     24usize,
     concat!(
       "Offset of field: ",
@@ -9151,7 +9152,7 @@ fn bindgen_test_layout__xmlSAXHandler() {
     concat!("Alignment of ", stringify!(_xmlSAXHandler))
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSAXHandler>())).internalSubset as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSAXHandler, internalSubset),
     0usize,
     concat!(
       "Offset of field: ",
@@ -9161,7 +9162,7 @@ fn bindgen_test_layout__xmlSAXHandler() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSAXHandler>())).isStandalone as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSAXHandler, isStandalone),
     8usize,
     concat!(
       "Offset of field: ",
@@ -9171,7 +9172,7 @@ fn bindgen_test_layout__xmlSAXHandler() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSAXHandler>())).hasInternalSubset as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSAXHandler, hasInternalSubset),
     16usize,
     concat!(
       "Offset of field: ",
@@ -9181,7 +9182,7 @@ fn bindgen_test_layout__xmlSAXHandler() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSAXHandler>())).hasExternalSubset as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSAXHandler, hasExternalSubset),
     24usize,
     concat!(
       "Offset of field: ",
@@ -9191,7 +9192,7 @@ fn bindgen_test_layout__xmlSAXHandler() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSAXHandler>())).resolveEntity as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSAXHandler, resolveEntity),
     32usize,
     concat!(
       "Offset of field: ",
@@ -9201,7 +9202,7 @@ fn bindgen_test_layout__xmlSAXHandler() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSAXHandler>())).getEntity as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSAXHandler, getEntity),
     40usize,
     concat!(
       "Offset of field: ",
@@ -9211,7 +9212,7 @@ fn bindgen_test_layout__xmlSAXHandler() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSAXHandler>())).entityDecl as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSAXHandler, entityDecl),
     48usize,
     concat!(
       "Offset of field: ",
@@ -9221,7 +9222,7 @@ fn bindgen_test_layout__xmlSAXHandler() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSAXHandler>())).notationDecl as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSAXHandler, notationDecl),
     56usize,
     concat!(
       "Offset of field: ",
@@ -9231,7 +9232,7 @@ fn bindgen_test_layout__xmlSAXHandler() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSAXHandler>())).attributeDecl as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSAXHandler, attributeDecl),
     64usize,
     concat!(
       "Offset of field: ",
@@ -9241,7 +9242,7 @@ fn bindgen_test_layout__xmlSAXHandler() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSAXHandler>())).elementDecl as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSAXHandler, elementDecl),
     72usize,
     concat!(
       "Offset of field: ",
@@ -9251,7 +9252,7 @@ fn bindgen_test_layout__xmlSAXHandler() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSAXHandler>())).unparsedEntityDecl as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSAXHandler, unparsedEntityDecl),
     80usize,
     concat!(
       "Offset of field: ",
@@ -9261,7 +9262,7 @@ fn bindgen_test_layout__xmlSAXHandler() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSAXHandler>())).setDocumentLocator as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSAXHandler, setDocumentLocator),
     88usize,
     concat!(
       "Offset of field: ",
@@ -9271,7 +9272,7 @@ fn bindgen_test_layout__xmlSAXHandler() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSAXHandler>())).startDocument as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSAXHandler, startDocument),
     96usize,
     concat!(
       "Offset of field: ",
@@ -9281,7 +9282,7 @@ fn bindgen_test_layout__xmlSAXHandler() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSAXHandler>())).endDocument as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSAXHandler, endDocument),
     104usize,
     concat!(
       "Offset of field: ",
@@ -9291,7 +9292,7 @@ fn bindgen_test_layout__xmlSAXHandler() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSAXHandler>())).startElement as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSAXHandler, startElement),
     112usize,
     concat!(
       "Offset of field: ",
@@ -9301,7 +9302,7 @@ fn bindgen_test_layout__xmlSAXHandler() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSAXHandler>())).endElement as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSAXHandler, endElement),
     120usize,
     concat!(
       "Offset of field: ",
@@ -9311,7 +9312,7 @@ fn bindgen_test_layout__xmlSAXHandler() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSAXHandler>())).reference as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSAXHandler, reference),
     128usize,
     concat!(
       "Offset of field: ",
@@ -9321,7 +9322,7 @@ fn bindgen_test_layout__xmlSAXHandler() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSAXHandler>())).characters as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSAXHandler, characters),
     136usize,
     concat!(
       "Offset of field: ",
@@ -9331,7 +9332,7 @@ fn bindgen_test_layout__xmlSAXHandler() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSAXHandler>())).ignorableWhitespace as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSAXHandler, ignorableWhitespace),
     144usize,
     concat!(
       "Offset of field: ",
@@ -9341,9 +9342,7 @@ fn bindgen_test_layout__xmlSAXHandler() {
     )
   );
   assert_eq!(
-    unsafe {
-      &(*(::std::ptr::null::<_xmlSAXHandler>())).processingInstruction as *const _ as usize
-    },
+    ::std::mem::offset_of!(_xmlSAXHandler, processingInstruction),
     152usize,
     concat!(
       "Offset of field: ",
@@ -9353,7 +9352,7 @@ fn bindgen_test_layout__xmlSAXHandler() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSAXHandler>())).comment as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSAXHandler, comment),
     160usize,
     concat!(
       "Offset of field: ",
@@ -9363,7 +9362,7 @@ fn bindgen_test_layout__xmlSAXHandler() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSAXHandler>())).warning as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSAXHandler, warning),
     168usize,
     concat!(
       "Offset of field: ",
@@ -9373,7 +9372,7 @@ fn bindgen_test_layout__xmlSAXHandler() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSAXHandler>())).error as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSAXHandler, error),
     176usize,
     concat!(
       "Offset of field: ",
@@ -9383,7 +9382,7 @@ fn bindgen_test_layout__xmlSAXHandler() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSAXHandler>())).fatalError as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSAXHandler, fatalError),
     184usize,
     concat!(
       "Offset of field: ",
@@ -9393,7 +9392,7 @@ fn bindgen_test_layout__xmlSAXHandler() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSAXHandler>())).getParameterEntity as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSAXHandler, getParameterEntity),
     192usize,
     concat!(
       "Offset of field: ",
@@ -9403,7 +9402,7 @@ fn bindgen_test_layout__xmlSAXHandler() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSAXHandler>())).cdataBlock as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSAXHandler, cdataBlock),
     200usize,
     concat!(
       "Offset of field: ",
@@ -9413,7 +9412,7 @@ fn bindgen_test_layout__xmlSAXHandler() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSAXHandler>())).externalSubset as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSAXHandler, externalSubset),
     208usize,
     concat!(
       "Offset of field: ",
@@ -9423,7 +9422,7 @@ fn bindgen_test_layout__xmlSAXHandler() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSAXHandler>())).initialized as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSAXHandler, initialized),
     216usize,
     concat!(
       "Offset of field: ",
@@ -9433,7 +9432,7 @@ fn bindgen_test_layout__xmlSAXHandler() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSAXHandler>()))._private as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSAXHandler, _private),
     224usize,
     concat!(
       "Offset of field: ",
@@ -9443,7 +9442,7 @@ fn bindgen_test_layout__xmlSAXHandler() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSAXHandler>())).startElementNs as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSAXHandler, startElementNs),
     232usize,
     concat!(
       "Offset of field: ",
@@ -9453,7 +9452,7 @@ fn bindgen_test_layout__xmlSAXHandler() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSAXHandler>())).endElementNs as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSAXHandler, endElementNs),
     240usize,
     concat!(
       "Offset of field: ",
@@ -9463,7 +9462,7 @@ fn bindgen_test_layout__xmlSAXHandler() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSAXHandler>())).serror as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSAXHandler, serror),
     248usize,
     concat!(
       "Offset of field: ",
@@ -9520,7 +9519,7 @@ fn bindgen_test_layout__xmlSAXHandlerV1() {
     concat!("Alignment of ", stringify!(_xmlSAXHandlerV1))
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSAXHandlerV1>())).internalSubset as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSAXHandlerV1, internalSubset),
     0usize,
     concat!(
       "Offset of field: ",
@@ -9530,7 +9529,7 @@ fn bindgen_test_layout__xmlSAXHandlerV1() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSAXHandlerV1>())).isStandalone as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSAXHandlerV1, isStandalone),
     8usize,
     concat!(
       "Offset of field: ",
@@ -9540,7 +9539,7 @@ fn bindgen_test_layout__xmlSAXHandlerV1() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSAXHandlerV1>())).hasInternalSubset as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSAXHandlerV1, hasInternalSubset),
     16usize,
     concat!(
       "Offset of field: ",
@@ -9550,7 +9549,7 @@ fn bindgen_test_layout__xmlSAXHandlerV1() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSAXHandlerV1>())).hasExternalSubset as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSAXHandlerV1, hasExternalSubset),
     24usize,
     concat!(
       "Offset of field: ",
@@ -9560,7 +9559,7 @@ fn bindgen_test_layout__xmlSAXHandlerV1() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSAXHandlerV1>())).resolveEntity as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSAXHandlerV1, resolveEntity),
     32usize,
     concat!(
       "Offset of field: ",
@@ -9570,7 +9569,7 @@ fn bindgen_test_layout__xmlSAXHandlerV1() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSAXHandlerV1>())).getEntity as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSAXHandlerV1, getEntity),
     40usize,
     concat!(
       "Offset of field: ",
@@ -9580,7 +9579,7 @@ fn bindgen_test_layout__xmlSAXHandlerV1() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSAXHandlerV1>())).entityDecl as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSAXHandlerV1, entityDecl),
     48usize,
     concat!(
       "Offset of field: ",
@@ -9590,7 +9589,7 @@ fn bindgen_test_layout__xmlSAXHandlerV1() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSAXHandlerV1>())).notationDecl as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSAXHandlerV1, notationDecl),
     56usize,
     concat!(
       "Offset of field: ",
@@ -9600,7 +9599,7 @@ fn bindgen_test_layout__xmlSAXHandlerV1() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSAXHandlerV1>())).attributeDecl as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSAXHandlerV1, attributeDecl),
     64usize,
     concat!(
       "Offset of field: ",
@@ -9610,7 +9609,7 @@ fn bindgen_test_layout__xmlSAXHandlerV1() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSAXHandlerV1>())).elementDecl as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSAXHandlerV1, elementDecl),
     72usize,
     concat!(
       "Offset of field: ",
@@ -9620,7 +9619,7 @@ fn bindgen_test_layout__xmlSAXHandlerV1() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSAXHandlerV1>())).unparsedEntityDecl as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSAXHandlerV1, unparsedEntityDecl),
     80usize,
     concat!(
       "Offset of field: ",
@@ -9630,7 +9629,7 @@ fn bindgen_test_layout__xmlSAXHandlerV1() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSAXHandlerV1>())).setDocumentLocator as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSAXHandlerV1, setDocumentLocator),
     88usize,
     concat!(
       "Offset of field: ",
@@ -9640,7 +9639,7 @@ fn bindgen_test_layout__xmlSAXHandlerV1() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSAXHandlerV1>())).startDocument as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSAXHandlerV1, startDocument),
     96usize,
     concat!(
       "Offset of field: ",
@@ -9650,7 +9649,7 @@ fn bindgen_test_layout__xmlSAXHandlerV1() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSAXHandlerV1>())).endDocument as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSAXHandlerV1, endDocument),
     104usize,
     concat!(
       "Offset of field: ",
@@ -9660,7 +9659,7 @@ fn bindgen_test_layout__xmlSAXHandlerV1() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSAXHandlerV1>())).startElement as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSAXHandlerV1, startElement),
     112usize,
     concat!(
       "Offset of field: ",
@@ -9670,7 +9669,7 @@ fn bindgen_test_layout__xmlSAXHandlerV1() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSAXHandlerV1>())).endElement as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSAXHandlerV1, endElement),
     120usize,
     concat!(
       "Offset of field: ",
@@ -9680,7 +9679,7 @@ fn bindgen_test_layout__xmlSAXHandlerV1() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSAXHandlerV1>())).reference as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSAXHandlerV1, reference),
     128usize,
     concat!(
       "Offset of field: ",
@@ -9690,7 +9689,7 @@ fn bindgen_test_layout__xmlSAXHandlerV1() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSAXHandlerV1>())).characters as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSAXHandlerV1, characters),
     136usize,
     concat!(
       "Offset of field: ",
@@ -9700,9 +9699,7 @@ fn bindgen_test_layout__xmlSAXHandlerV1() {
     )
   );
   assert_eq!(
-    unsafe {
-      &(*(::std::ptr::null::<_xmlSAXHandlerV1>())).ignorableWhitespace as *const _ as usize
-    },
+    ::std::mem::offset_of!(_xmlSAXHandlerV1, ignorableWhitespace),
     144usize,
     concat!(
       "Offset of field: ",
@@ -9712,9 +9709,7 @@ fn bindgen_test_layout__xmlSAXHandlerV1() {
     )
   );
   assert_eq!(
-    unsafe {
-      &(*(::std::ptr::null::<_xmlSAXHandlerV1>())).processingInstruction as *const _ as usize
-    },
+    ::std::mem::offset_of!(_xmlSAXHandlerV1, processingInstruction),
     152usize,
     concat!(
       "Offset of field: ",
@@ -9724,7 +9719,7 @@ fn bindgen_test_layout__xmlSAXHandlerV1() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSAXHandlerV1>())).comment as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSAXHandlerV1, comment),
     160usize,
     concat!(
       "Offset of field: ",
@@ -9734,7 +9729,7 @@ fn bindgen_test_layout__xmlSAXHandlerV1() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSAXHandlerV1>())).warning as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSAXHandlerV1, warning),
     168usize,
     concat!(
       "Offset of field: ",
@@ -9744,7 +9739,7 @@ fn bindgen_test_layout__xmlSAXHandlerV1() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSAXHandlerV1>())).error as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSAXHandlerV1, error),
     176usize,
     concat!(
       "Offset of field: ",
@@ -9754,7 +9749,7 @@ fn bindgen_test_layout__xmlSAXHandlerV1() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSAXHandlerV1>())).fatalError as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSAXHandlerV1, fatalError),
     184usize,
     concat!(
       "Offset of field: ",
@@ -9764,7 +9759,7 @@ fn bindgen_test_layout__xmlSAXHandlerV1() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSAXHandlerV1>())).getParameterEntity as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSAXHandlerV1, getParameterEntity),
     192usize,
     concat!(
       "Offset of field: ",
@@ -9774,7 +9769,7 @@ fn bindgen_test_layout__xmlSAXHandlerV1() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSAXHandlerV1>())).cdataBlock as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSAXHandlerV1, cdataBlock),
     200usize,
     concat!(
       "Offset of field: ",
@@ -9784,7 +9779,7 @@ fn bindgen_test_layout__xmlSAXHandlerV1() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSAXHandlerV1>())).externalSubset as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSAXHandlerV1, externalSubset),
     208usize,
     concat!(
       "Offset of field: ",
@@ -9794,7 +9789,7 @@ fn bindgen_test_layout__xmlSAXHandlerV1() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSAXHandlerV1>())).initialized as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSAXHandlerV1, initialized),
     216usize,
     concat!(
       "Offset of field: ",
@@ -9854,9 +9849,7 @@ fn bindgen_test_layout_max_align_t() {
   //   concat!("Size of: ", stringify!(max_align_t))
   // );
   assert_eq!(
-    unsafe {
-      &(*(::std::ptr::null::<max_align_t>())).__clang_max_align_nonce1 as *const _ as usize
-    },
+    ::std::mem::offset_of!(max_align_t, __clang_max_align_nonce1),
     0usize,
     concat!(
       "Offset of field: ",
@@ -9866,9 +9859,7 @@ fn bindgen_test_layout_max_align_t() {
     )
   );
   assert_eq!(
-    unsafe {
-      &(*(::std::ptr::null::<max_align_t>())).__clang_max_align_nonce2 as *const _ as usize
-    },
+    ::std::mem::offset_of!(max_align_t, __clang_max_align_nonce2),
     16usize,
     concat!(
       "Offset of field: ",
@@ -10152,7 +10143,7 @@ fn bindgen_test_layout_UConverterFromUnicodeArgs() {
     concat!("Alignment of ", stringify!(UConverterFromUnicodeArgs))
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<UConverterFromUnicodeArgs>())).size as *const _ as usize },
+    ::std::mem::offset_of!(UConverterFromUnicodeArgs, size),
     0usize,
     concat!(
       "Offset of field: ",
@@ -10162,7 +10153,7 @@ fn bindgen_test_layout_UConverterFromUnicodeArgs() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<UConverterFromUnicodeArgs>())).flush as *const _ as usize },
+    ::std::mem::offset_of!(UConverterFromUnicodeArgs, flush),
     2usize,
     concat!(
       "Offset of field: ",
@@ -10172,7 +10163,7 @@ fn bindgen_test_layout_UConverterFromUnicodeArgs() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<UConverterFromUnicodeArgs>())).converter as *const _ as usize },
+    ::std::mem::offset_of!(UConverterFromUnicodeArgs, converter),
     8usize,
     concat!(
       "Offset of field: ",
@@ -10182,7 +10173,7 @@ fn bindgen_test_layout_UConverterFromUnicodeArgs() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<UConverterFromUnicodeArgs>())).source as *const _ as usize },
+    ::std::mem::offset_of!(UConverterFromUnicodeArgs, source),
     16usize,
     concat!(
       "Offset of field: ",
@@ -10192,9 +10183,7 @@ fn bindgen_test_layout_UConverterFromUnicodeArgs() {
     )
   );
   assert_eq!(
-    unsafe {
-      &(*(::std::ptr::null::<UConverterFromUnicodeArgs>())).sourceLimit as *const _ as usize
-    },
+    ::std::mem::offset_of!(UConverterFromUnicodeArgs, sourceLimit),
     24usize,
     concat!(
       "Offset of field: ",
@@ -10204,7 +10193,7 @@ fn bindgen_test_layout_UConverterFromUnicodeArgs() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<UConverterFromUnicodeArgs>())).target as *const _ as usize },
+    ::std::mem::offset_of!(UConverterFromUnicodeArgs, target),
     32usize,
     concat!(
       "Offset of field: ",
@@ -10214,9 +10203,7 @@ fn bindgen_test_layout_UConverterFromUnicodeArgs() {
     )
   );
   assert_eq!(
-    unsafe {
-      &(*(::std::ptr::null::<UConverterFromUnicodeArgs>())).targetLimit as *const _ as usize
-    },
+    ::std::mem::offset_of!(UConverterFromUnicodeArgs, targetLimit),
     40usize,
     concat!(
       "Offset of field: ",
@@ -10226,7 +10213,7 @@ fn bindgen_test_layout_UConverterFromUnicodeArgs() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<UConverterFromUnicodeArgs>())).offsets as *const _ as usize },
+    ::std::mem::offset_of!(UConverterFromUnicodeArgs, offsets),
     48usize,
     concat!(
       "Offset of field: ",
@@ -10261,7 +10248,7 @@ fn bindgen_test_layout_UConverterToUnicodeArgs() {
     concat!("Alignment of ", stringify!(UConverterToUnicodeArgs))
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<UConverterToUnicodeArgs>())).size as *const _ as usize },
+    ::std::mem::offset_of!(UConverterToUnicodeArgs, size),
     0usize,
     concat!(
       "Offset of field: ",
@@ -10271,7 +10258,7 @@ fn bindgen_test_layout_UConverterToUnicodeArgs() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<UConverterToUnicodeArgs>())).flush as *const _ as usize },
+    ::std::mem::offset_of!(UConverterToUnicodeArgs, flush),
     2usize,
     concat!(
       "Offset of field: ",
@@ -10281,7 +10268,7 @@ fn bindgen_test_layout_UConverterToUnicodeArgs() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<UConverterToUnicodeArgs>())).converter as *const _ as usize },
+    ::std::mem::offset_of!(UConverterToUnicodeArgs, converter),
     8usize,
     concat!(
       "Offset of field: ",
@@ -10291,7 +10278,7 @@ fn bindgen_test_layout_UConverterToUnicodeArgs() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<UConverterToUnicodeArgs>())).source as *const _ as usize },
+    ::std::mem::offset_of!(UConverterToUnicodeArgs, source),
     16usize,
     concat!(
       "Offset of field: ",
@@ -10301,7 +10288,7 @@ fn bindgen_test_layout_UConverterToUnicodeArgs() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<UConverterToUnicodeArgs>())).sourceLimit as *const _ as usize },
+    ::std::mem::offset_of!(UConverterToUnicodeArgs, sourceLimit),
     24usize,
     concat!(
       "Offset of field: ",
@@ -10311,7 +10298,7 @@ fn bindgen_test_layout_UConverterToUnicodeArgs() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<UConverterToUnicodeArgs>())).target as *const _ as usize },
+    ::std::mem::offset_of!(UConverterToUnicodeArgs, target),
     32usize,
     concat!(
       "Offset of field: ",
@@ -10321,7 +10308,7 @@ fn bindgen_test_layout_UConverterToUnicodeArgs() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<UConverterToUnicodeArgs>())).targetLimit as *const _ as usize },
+    ::std::mem::offset_of!(UConverterToUnicodeArgs, targetLimit),
     40usize,
     concat!(
       "Offset of field: ",
@@ -10331,7 +10318,7 @@ fn bindgen_test_layout_UConverterToUnicodeArgs() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<UConverterToUnicodeArgs>())).offsets as *const _ as usize },
+    ::std::mem::offset_of!(UConverterToUnicodeArgs, offsets),
     48usize,
     concat!(
       "Offset of field: ",
@@ -10995,7 +10982,7 @@ fn bindgen_test_layout__uconv_t() {
     concat!("Alignment of ", stringify!(_uconv_t))
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_uconv_t>())).uconv as *const _ as usize },
+    ::std::mem::offset_of!(_uconv_t, uconv),
     0usize,
     concat!(
       "Offset of field: ",
@@ -11005,7 +10992,7 @@ fn bindgen_test_layout__uconv_t() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_uconv_t>())).utf8 as *const _ as usize },
+    ::std::mem::offset_of!(_uconv_t, utf8),
     8usize,
     concat!(
       "Offset of field: ",
@@ -11042,7 +11029,7 @@ fn bindgen_test_layout__xmlCharEncodingHandler() {
     concat!("Alignment of ", stringify!(_xmlCharEncodingHandler))
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlCharEncodingHandler>())).name as *const _ as usize },
+    ::std::mem::offset_of!(_xmlCharEncodingHandler, name),
     0usize,
     concat!(
       "Offset of field: ",
@@ -11052,7 +11039,7 @@ fn bindgen_test_layout__xmlCharEncodingHandler() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlCharEncodingHandler>())).input as *const _ as usize },
+    ::std::mem::offset_of!(_xmlCharEncodingHandler, input),
     8usize,
     concat!(
       "Offset of field: ",
@@ -11062,7 +11049,7 @@ fn bindgen_test_layout__xmlCharEncodingHandler() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlCharEncodingHandler>())).output as *const _ as usize },
+    ::std::mem::offset_of!(_xmlCharEncodingHandler, output),
     16usize,
     concat!(
       "Offset of field: ",
@@ -11072,7 +11059,7 @@ fn bindgen_test_layout__xmlCharEncodingHandler() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlCharEncodingHandler>())).iconv_in as *const _ as usize },
+    ::std::mem::offset_of!(_xmlCharEncodingHandler, iconv_in),
     24usize,
     concat!(
       "Offset of field: ",
@@ -11082,7 +11069,7 @@ fn bindgen_test_layout__xmlCharEncodingHandler() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlCharEncodingHandler>())).iconv_out as *const _ as usize },
+    ::std::mem::offset_of!(_xmlCharEncodingHandler, iconv_out),
     32usize,
     concat!(
       "Offset of field: ",
@@ -11092,7 +11079,7 @@ fn bindgen_test_layout__xmlCharEncodingHandler() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlCharEncodingHandler>())).uconv_in as *const _ as usize },
+    ::std::mem::offset_of!(_xmlCharEncodingHandler, uconv_in),
     40usize,
     concat!(
       "Offset of field: ",
@@ -11102,7 +11089,7 @@ fn bindgen_test_layout__xmlCharEncodingHandler() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlCharEncodingHandler>())).uconv_out as *const _ as usize },
+    ::std::mem::offset_of!(_xmlCharEncodingHandler, uconv_out),
     48usize,
     concat!(
       "Offset of field: ",
@@ -11317,7 +11304,7 @@ fn bindgen_test_layout_div_t() {
     concat!("Alignment of ", stringify!(div_t))
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<div_t>())).quot as *const _ as usize },
+    ::std::mem::offset_of!(div_t, quot),
     0usize,
     concat!(
       "Offset of field: ",
@@ -11327,7 +11314,7 @@ fn bindgen_test_layout_div_t() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<div_t>())).rem as *const _ as usize },
+    ::std::mem::offset_of!(div_t, rem),
     4usize,
     concat!(
       "Offset of field: ",
@@ -11356,7 +11343,7 @@ fn bindgen_test_layout_ldiv_t() {
     concat!("Alignment of ", stringify!(ldiv_t))
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<ldiv_t>())).quot as *const _ as usize },
+    ::std::mem::offset_of!(ldiv_t, quot),
     0usize,
     concat!(
       "Offset of field: ",
@@ -11366,7 +11353,7 @@ fn bindgen_test_layout_ldiv_t() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<ldiv_t>())).rem as *const _ as usize },
+    ::std::mem::offset_of!(ldiv_t, rem),
     8usize,
     concat!(
       "Offset of field: ",
@@ -11395,7 +11382,7 @@ fn bindgen_test_layout_lldiv_t() {
     concat!("Alignment of ", stringify!(lldiv_t))
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<lldiv_t>())).quot as *const _ as usize },
+    ::std::mem::offset_of!(lldiv_t, quot),
     0usize,
     concat!(
       "Offset of field: ",
@@ -11405,7 +11392,7 @@ fn bindgen_test_layout_lldiv_t() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<lldiv_t>())).rem as *const _ as usize },
+    ::std::mem::offset_of!(lldiv_t, rem),
     8usize,
     concat!(
       "Offset of field: ",
@@ -11545,7 +11532,7 @@ fn bindgen_test_layout___sigset_t() {
     concat!("Alignment of ", stringify!(__sigset_t))
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<__sigset_t>())).__val as *const _ as usize },
+    ::std::mem::offset_of!(__sigset_t, __val),
     0usize,
     concat!(
       "Offset of field: ",
@@ -11575,7 +11562,7 @@ fn bindgen_test_layout_timeval() {
     concat!("Alignment of ", stringify!(timeval))
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<timeval>())).tv_sec as *const _ as usize },
+    ::std::mem::offset_of!(timeval, tv_sec),
     0usize,
     concat!(
       "Offset of field: ",
@@ -11585,7 +11572,7 @@ fn bindgen_test_layout_timeval() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<timeval>())).tv_usec as *const _ as usize },
+    ::std::mem::offset_of!(timeval, tv_usec),
     8usize,
     concat!(
       "Offset of field: ",
@@ -11614,7 +11601,7 @@ fn bindgen_test_layout_timespec() {
     concat!("Alignment of ", stringify!(timespec))
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<timespec>())).tv_sec as *const _ as usize },
+    ::std::mem::offset_of!(timespec, tv_sec),
     0usize,
     concat!(
       "Offset of field: ",
@@ -11624,7 +11611,7 @@ fn bindgen_test_layout_timespec() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<timespec>())).tv_nsec as *const _ as usize },
+    ::std::mem::offset_of!(timespec, tv_nsec),
     8usize,
     concat!(
       "Offset of field: ",
@@ -11654,7 +11641,7 @@ fn bindgen_test_layout_fd_set() {
     concat!("Alignment of ", stringify!(fd_set))
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<fd_set>())).__fds_bits as *const _ as usize },
+    ::std::mem::offset_of!(fd_set, __fds_bits),
     0usize,
     concat!(
       "Offset of field: ",
@@ -11729,7 +11716,7 @@ fn bindgen_test_layout___pthread_rwlock_arch_t() {
     concat!("Alignment of ", stringify!(__pthread_rwlock_arch_t))
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<__pthread_rwlock_arch_t>())).__readers as *const _ as usize },
+    ::std::mem::offset_of!(__pthread_rwlock_arch_t, __readers),
     0usize,
     concat!(
       "Offset of field: ",
@@ -11739,7 +11726,7 @@ fn bindgen_test_layout___pthread_rwlock_arch_t() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<__pthread_rwlock_arch_t>())).__writers as *const _ as usize },
+    ::std::mem::offset_of!(__pthread_rwlock_arch_t, __writers),
     4usize,
     concat!(
       "Offset of field: ",
@@ -11749,9 +11736,7 @@ fn bindgen_test_layout___pthread_rwlock_arch_t() {
     )
   );
   assert_eq!(
-    unsafe {
-      &(*(::std::ptr::null::<__pthread_rwlock_arch_t>())).__wrphase_futex as *const _ as usize
-    },
+    ::std::mem::offset_of!(__pthread_rwlock_arch_t, __wrphase_futex),
     8usize,
     concat!(
       "Offset of field: ",
@@ -11761,9 +11746,7 @@ fn bindgen_test_layout___pthread_rwlock_arch_t() {
     )
   );
   assert_eq!(
-    unsafe {
-      &(*(::std::ptr::null::<__pthread_rwlock_arch_t>())).__writers_futex as *const _ as usize
-    },
+    ::std::mem::offset_of!(__pthread_rwlock_arch_t, __writers_futex),
     12usize,
     concat!(
       "Offset of field: ",
@@ -11773,7 +11756,7 @@ fn bindgen_test_layout___pthread_rwlock_arch_t() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<__pthread_rwlock_arch_t>())).__pad3 as *const _ as usize },
+    ::std::mem::offset_of!(__pthread_rwlock_arch_t, __pad3),
     16usize,
     concat!(
       "Offset of field: ",
@@ -11783,7 +11766,7 @@ fn bindgen_test_layout___pthread_rwlock_arch_t() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<__pthread_rwlock_arch_t>())).__pad4 as *const _ as usize },
+    ::std::mem::offset_of!(__pthread_rwlock_arch_t, __pad4),
     20usize,
     concat!(
       "Offset of field: ",
@@ -11793,9 +11776,7 @@ fn bindgen_test_layout___pthread_rwlock_arch_t() {
     )
   );
   assert_eq!(
-    unsafe {
-      &(*(::std::ptr::null::<__pthread_rwlock_arch_t>())).__cur_writer as *const _ as usize
-    },
+    ::std::mem::offset_of!(__pthread_rwlock_arch_t, __cur_writer),
     24usize,
     concat!(
       "Offset of field: ",
@@ -11805,7 +11786,7 @@ fn bindgen_test_layout___pthread_rwlock_arch_t() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<__pthread_rwlock_arch_t>())).__shared as *const _ as usize },
+    ::std::mem::offset_of!(__pthread_rwlock_arch_t, __shared),
     28usize,
     concat!(
       "Offset of field: ",
@@ -11815,7 +11796,7 @@ fn bindgen_test_layout___pthread_rwlock_arch_t() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<__pthread_rwlock_arch_t>())).__rwelision as *const _ as usize },
+    ::std::mem::offset_of!(__pthread_rwlock_arch_t, __rwelision),
     32usize,
     concat!(
       "Offset of field: ",
@@ -11825,7 +11806,7 @@ fn bindgen_test_layout___pthread_rwlock_arch_t() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<__pthread_rwlock_arch_t>())).__pad1 as *const _ as usize },
+    ::std::mem::offset_of!(__pthread_rwlock_arch_t, __pad1),
     33usize,
     concat!(
       "Offset of field: ",
@@ -11835,7 +11816,7 @@ fn bindgen_test_layout___pthread_rwlock_arch_t() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<__pthread_rwlock_arch_t>())).__pad2 as *const _ as usize },
+    ::std::mem::offset_of!(__pthread_rwlock_arch_t, __pad2),
     40usize,
     concat!(
       "Offset of field: ",
@@ -11845,7 +11826,7 @@ fn bindgen_test_layout___pthread_rwlock_arch_t() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<__pthread_rwlock_arch_t>())).__flags as *const _ as usize },
+    ::std::mem::offset_of!(__pthread_rwlock_arch_t, __flags),
     48usize,
     concat!(
       "Offset of field: ",
@@ -11874,7 +11855,7 @@ fn bindgen_test_layout___pthread_internal_list() {
     concat!("Alignment of ", stringify!(__pthread_internal_list))
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<__pthread_internal_list>())).__prev as *const _ as usize },
+    ::std::mem::offset_of!(__pthread_internal_list, __prev),
     0usize,
     concat!(
       "Offset of field: ",
@@ -11884,7 +11865,7 @@ fn bindgen_test_layout___pthread_internal_list() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<__pthread_internal_list>())).__next as *const _ as usize },
+    ::std::mem::offset_of!(__pthread_internal_list, __next),
     8usize,
     concat!(
       "Offset of field: ",
@@ -11920,7 +11901,7 @@ fn bindgen_test_layout___pthread_mutex_s() {
     concat!("Alignment of ", stringify!(__pthread_mutex_s))
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<__pthread_mutex_s>())).__lock as *const _ as usize },
+    ::std::mem::offset_of!(__pthread_mutex_s, __lock),
     0usize,
     concat!(
       "Offset of field: ",
@@ -11930,7 +11911,7 @@ fn bindgen_test_layout___pthread_mutex_s() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<__pthread_mutex_s>())).__count as *const _ as usize },
+    ::std::mem::offset_of!(__pthread_mutex_s, __count),
     4usize,
     concat!(
       "Offset of field: ",
@@ -11940,7 +11921,7 @@ fn bindgen_test_layout___pthread_mutex_s() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<__pthread_mutex_s>())).__owner as *const _ as usize },
+    ::std::mem::offset_of!(__pthread_mutex_s, __owner),
     8usize,
     concat!(
       "Offset of field: ",
@@ -11950,7 +11931,7 @@ fn bindgen_test_layout___pthread_mutex_s() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<__pthread_mutex_s>())).__nusers as *const _ as usize },
+    ::std::mem::offset_of!(__pthread_mutex_s, __nusers),
     12usize,
     concat!(
       "Offset of field: ",
@@ -11960,7 +11941,7 @@ fn bindgen_test_layout___pthread_mutex_s() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<__pthread_mutex_s>())).__kind as *const _ as usize },
+    ::std::mem::offset_of!(__pthread_mutex_s, __kind),
     16usize,
     concat!(
       "Offset of field: ",
@@ -11970,7 +11951,7 @@ fn bindgen_test_layout___pthread_mutex_s() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<__pthread_mutex_s>())).__spins as *const _ as usize },
+    ::std::mem::offset_of!(__pthread_mutex_s, __spins),
     20usize,
     concat!(
       "Offset of field: ",
@@ -11980,7 +11961,7 @@ fn bindgen_test_layout___pthread_mutex_s() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<__pthread_mutex_s>())).__elision as *const _ as usize },
+    ::std::mem::offset_of!(__pthread_mutex_s, __elision),
     22usize,
     concat!(
       "Offset of field: ",
@@ -11990,7 +11971,7 @@ fn bindgen_test_layout___pthread_mutex_s() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<__pthread_mutex_s>())).__list as *const _ as usize },
+    ::std::mem::offset_of!(__pthread_mutex_s, __list),
     24usize,
     concat!(
       "Offset of field: ",
@@ -12043,10 +12024,7 @@ fn bindgen_test_layout___pthread_cond_s__bindgen_ty_1__bindgen_ty_1() {
     )
   );
   assert_eq!(
-    unsafe {
-      &(*(::std::ptr::null::<__pthread_cond_s__bindgen_ty_1__bindgen_ty_1>())).__low as *const _
-        as usize
-    },
+    ::std::mem::offset_of!(__pthread_cond_s__bindgen_ty_1__bindgen_ty_1, __low),
     0usize,
     concat!(
       "Offset of field: ",
@@ -12056,10 +12034,7 @@ fn bindgen_test_layout___pthread_cond_s__bindgen_ty_1__bindgen_ty_1() {
     )
   );
   assert_eq!(
-    unsafe {
-      &(*(::std::ptr::null::<__pthread_cond_s__bindgen_ty_1__bindgen_ty_1>())).__high as *const _
-        as usize
-    },
+    ::std::mem::offset_of!(__pthread_cond_s__bindgen_ty_1__bindgen_ty_1, __high),
     4usize,
     concat!(
       "Offset of field: ",
@@ -12082,9 +12057,7 @@ fn bindgen_test_layout___pthread_cond_s__bindgen_ty_1() {
     concat!("Alignment of ", stringify!(__pthread_cond_s__bindgen_ty_1))
   );
   assert_eq!(
-    unsafe {
-      &(*(::std::ptr::null::<__pthread_cond_s__bindgen_ty_1>())).__wseq as *const _ as usize
-    },
+    ::std::mem::offset_of!(__pthread_cond_s__bindgen_ty_1, __wseq),
     0usize,
     concat!(
       "Offset of field: ",
@@ -12094,9 +12067,7 @@ fn bindgen_test_layout___pthread_cond_s__bindgen_ty_1() {
     )
   );
   assert_eq!(
-    unsafe {
-      &(*(::std::ptr::null::<__pthread_cond_s__bindgen_ty_1>())).__wseq32 as *const _ as usize
-    },
+    ::std::mem::offset_of!(__pthread_cond_s__bindgen_ty_1, __wseq32),
     0usize,
     concat!(
       "Offset of field: ",
@@ -12138,10 +12109,7 @@ fn bindgen_test_layout___pthread_cond_s__bindgen_ty_2__bindgen_ty_1() {
     )
   );
   assert_eq!(
-    unsafe {
-      &(*(::std::ptr::null::<__pthread_cond_s__bindgen_ty_2__bindgen_ty_1>())).__low as *const _
-        as usize
-    },
+    ::std::mem::offset_of!(__pthread_cond_s__bindgen_ty_2__bindgen_ty_1, __low),
     0usize,
     concat!(
       "Offset of field: ",
@@ -12151,10 +12119,7 @@ fn bindgen_test_layout___pthread_cond_s__bindgen_ty_2__bindgen_ty_1() {
     )
   );
   assert_eq!(
-    unsafe {
-      &(*(::std::ptr::null::<__pthread_cond_s__bindgen_ty_2__bindgen_ty_1>())).__high as *const _
-        as usize
-    },
+    ::std::mem::offset_of!(__pthread_cond_s__bindgen_ty_2__bindgen_ty_1, __high),
     4usize,
     concat!(
       "Offset of field: ",
@@ -12177,9 +12142,7 @@ fn bindgen_test_layout___pthread_cond_s__bindgen_ty_2() {
     concat!("Alignment of ", stringify!(__pthread_cond_s__bindgen_ty_2))
   );
   assert_eq!(
-    unsafe {
-      &(*(::std::ptr::null::<__pthread_cond_s__bindgen_ty_2>())).__g1_start as *const _ as usize
-    },
+    ::std::mem::offset_of!(__pthread_cond_s__bindgen_ty_2, __g1_start),
     0usize,
     concat!(
       "Offset of field: ",
@@ -12189,9 +12152,7 @@ fn bindgen_test_layout___pthread_cond_s__bindgen_ty_2() {
     )
   );
   assert_eq!(
-    unsafe {
-      &(*(::std::ptr::null::<__pthread_cond_s__bindgen_ty_2>())).__g1_start32 as *const _ as usize
-    },
+    ::std::mem::offset_of!(__pthread_cond_s__bindgen_ty_2, __g1_start32),
     0usize,
     concat!(
       "Offset of field: ",
@@ -12214,7 +12175,7 @@ fn bindgen_test_layout___pthread_cond_s() {
     concat!("Alignment of ", stringify!(__pthread_cond_s))
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<__pthread_cond_s>())).__g_refs as *const _ as usize },
+    ::std::mem::offset_of!(__pthread_cond_s, __g_refs),
     16usize,
     concat!(
       "Offset of field: ",
@@ -12224,7 +12185,7 @@ fn bindgen_test_layout___pthread_cond_s() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<__pthread_cond_s>())).__g_size as *const _ as usize },
+    ::std::mem::offset_of!(__pthread_cond_s, __g_size),
     24usize,
     concat!(
       "Offset of field: ",
@@ -12234,7 +12195,7 @@ fn bindgen_test_layout___pthread_cond_s() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<__pthread_cond_s>())).__g1_orig_size as *const _ as usize },
+    ::std::mem::offset_of!(__pthread_cond_s, __g1_orig_size),
     32usize,
     concat!(
       "Offset of field: ",
@@ -12244,7 +12205,7 @@ fn bindgen_test_layout___pthread_cond_s() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<__pthread_cond_s>())).__wrefs as *const _ as usize },
+    ::std::mem::offset_of!(__pthread_cond_s, __wrefs),
     36usize,
     concat!(
       "Offset of field: ",
@@ -12254,7 +12215,7 @@ fn bindgen_test_layout___pthread_cond_s() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<__pthread_cond_s>())).__g_signals as *const _ as usize },
+    ::std::mem::offset_of!(__pthread_cond_s, __g_signals),
     40usize,
     concat!(
       "Offset of field: ",
@@ -12285,7 +12246,7 @@ fn bindgen_test_layout_pthread_mutexattr_t() {
     concat!("Alignment of ", stringify!(pthread_mutexattr_t))
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<pthread_mutexattr_t>())).__size as *const _ as usize },
+    ::std::mem::offset_of!(pthread_mutexattr_t, __size),
     0usize,
     concat!(
       "Offset of field: ",
@@ -12295,7 +12256,7 @@ fn bindgen_test_layout_pthread_mutexattr_t() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<pthread_mutexattr_t>())).__align as *const _ as usize },
+    ::std::mem::offset_of!(pthread_mutexattr_t, __align),
     0usize,
     concat!(
       "Offset of field: ",
@@ -12325,7 +12286,7 @@ fn bindgen_test_layout_pthread_condattr_t() {
     concat!("Alignment of ", stringify!(pthread_condattr_t))
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<pthread_condattr_t>())).__size as *const _ as usize },
+    ::std::mem::offset_of!(pthread_condattr_t, __size),
     0usize,
     concat!(
       "Offset of field: ",
@@ -12335,7 +12296,7 @@ fn bindgen_test_layout_pthread_condattr_t() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<pthread_condattr_t>())).__align as *const _ as usize },
+    ::std::mem::offset_of!(pthread_condattr_t, __align),
     0usize,
     concat!(
       "Offset of field: ",
@@ -12367,7 +12328,7 @@ fn bindgen_test_layout_pthread_attr_t() {
     concat!("Alignment of ", stringify!(pthread_attr_t))
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<pthread_attr_t>())).__size as *const _ as usize },
+    ::std::mem::offset_of!(pthread_attr_t, __size),
     0usize,
     concat!(
       "Offset of field: ",
@@ -12377,7 +12338,7 @@ fn bindgen_test_layout_pthread_attr_t() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<pthread_attr_t>())).__align as *const _ as usize },
+    ::std::mem::offset_of!(pthread_attr_t, __align),
     0usize,
     concat!(
       "Offset of field: ",
@@ -12408,7 +12369,7 @@ fn bindgen_test_layout_pthread_mutex_t() {
     concat!("Alignment of ", stringify!(pthread_mutex_t))
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<pthread_mutex_t>())).__data as *const _ as usize },
+    ::std::mem::offset_of!(pthread_mutex_t, __data),
     0usize,
     concat!(
       "Offset of field: ",
@@ -12418,7 +12379,7 @@ fn bindgen_test_layout_pthread_mutex_t() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<pthread_mutex_t>())).__size as *const _ as usize },
+    ::std::mem::offset_of!(pthread_mutex_t, __size),
     0usize,
     concat!(
       "Offset of field: ",
@@ -12428,7 +12389,7 @@ fn bindgen_test_layout_pthread_mutex_t() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<pthread_mutex_t>())).__align as *const _ as usize },
+    ::std::mem::offset_of!(pthread_mutex_t, __align),
     0usize,
     concat!(
       "Offset of field: ",
@@ -12459,7 +12420,7 @@ fn bindgen_test_layout_pthread_cond_t() {
     concat!("Alignment of ", stringify!(pthread_cond_t))
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<pthread_cond_t>())).__data as *const _ as usize },
+    ::std::mem::offset_of!(pthread_cond_t, __data),
     0usize,
     concat!(
       "Offset of field: ",
@@ -12469,7 +12430,7 @@ fn bindgen_test_layout_pthread_cond_t() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<pthread_cond_t>())).__size as *const _ as usize },
+    ::std::mem::offset_of!(pthread_cond_t, __size),
     0usize,
     concat!(
       "Offset of field: ",
@@ -12479,7 +12440,7 @@ fn bindgen_test_layout_pthread_cond_t() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<pthread_cond_t>())).__align as *const _ as usize },
+    ::std::mem::offset_of!(pthread_cond_t, __align),
     0usize,
     concat!(
       "Offset of field: ",
@@ -12510,7 +12471,7 @@ fn bindgen_test_layout_pthread_rwlock_t() {
     concat!("Alignment of ", stringify!(pthread_rwlock_t))
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<pthread_rwlock_t>())).__data as *const _ as usize },
+    ::std::mem::offset_of!(pthread_rwlock_t, __data),
     0usize,
     concat!(
       "Offset of field: ",
@@ -12520,7 +12481,7 @@ fn bindgen_test_layout_pthread_rwlock_t() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<pthread_rwlock_t>())).__size as *const _ as usize },
+    ::std::mem::offset_of!(pthread_rwlock_t, __size),
     0usize,
     concat!(
       "Offset of field: ",
@@ -12530,7 +12491,7 @@ fn bindgen_test_layout_pthread_rwlock_t() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<pthread_rwlock_t>())).__align as *const _ as usize },
+    ::std::mem::offset_of!(pthread_rwlock_t, __align),
     0usize,
     concat!(
       "Offset of field: ",
@@ -12560,7 +12521,7 @@ fn bindgen_test_layout_pthread_rwlockattr_t() {
     concat!("Alignment of ", stringify!(pthread_rwlockattr_t))
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<pthread_rwlockattr_t>())).__size as *const _ as usize },
+    ::std::mem::offset_of!(pthread_rwlockattr_t, __size),
     0usize,
     concat!(
       "Offset of field: ",
@@ -12570,7 +12531,7 @@ fn bindgen_test_layout_pthread_rwlockattr_t() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<pthread_rwlockattr_t>())).__align as *const _ as usize },
+    ::std::mem::offset_of!(pthread_rwlockattr_t, __align),
     0usize,
     concat!(
       "Offset of field: ",
@@ -12601,7 +12562,7 @@ fn bindgen_test_layout_pthread_barrier_t() {
     concat!("Alignment of ", stringify!(pthread_barrier_t))
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<pthread_barrier_t>())).__size as *const _ as usize },
+    ::std::mem::offset_of!(pthread_barrier_t, __size),
     0usize,
     concat!(
       "Offset of field: ",
@@ -12611,7 +12572,7 @@ fn bindgen_test_layout_pthread_barrier_t() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<pthread_barrier_t>())).__align as *const _ as usize },
+    ::std::mem::offset_of!(pthread_barrier_t, __align),
     0usize,
     concat!(
       "Offset of field: ",
@@ -12641,7 +12602,7 @@ fn bindgen_test_layout_pthread_barrierattr_t() {
     concat!("Alignment of ", stringify!(pthread_barrierattr_t))
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<pthread_barrierattr_t>())).__size as *const _ as usize },
+    ::std::mem::offset_of!(pthread_barrierattr_t, __size),
     0usize,
     concat!(
       "Offset of field: ",
@@ -12651,7 +12612,7 @@ fn bindgen_test_layout_pthread_barrierattr_t() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<pthread_barrierattr_t>())).__align as *const _ as usize },
+    ::std::mem::offset_of!(pthread_barrierattr_t, __align),
     0usize,
     concat!(
       "Offset of field: ",
@@ -12701,7 +12662,7 @@ fn bindgen_test_layout_random_data() {
     concat!("Alignment of ", stringify!(random_data))
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<random_data>())).fptr as *const _ as usize },
+    ::std::mem::offset_of!(random_data, fptr),
     0usize,
     concat!(
       "Offset of field: ",
@@ -12711,7 +12672,7 @@ fn bindgen_test_layout_random_data() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<random_data>())).rptr as *const _ as usize },
+    ::std::mem::offset_of!(random_data, rptr),
     8usize,
     concat!(
       "Offset of field: ",
@@ -12721,7 +12682,7 @@ fn bindgen_test_layout_random_data() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<random_data>())).state as *const _ as usize },
+    ::std::mem::offset_of!(random_data, state),
     16usize,
     concat!(
       "Offset of field: ",
@@ -12731,7 +12692,7 @@ fn bindgen_test_layout_random_data() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<random_data>())).rand_type as *const _ as usize },
+    ::std::mem::offset_of!(random_data, rand_type),
     24usize,
     concat!(
       "Offset of field: ",
@@ -12741,7 +12702,7 @@ fn bindgen_test_layout_random_data() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<random_data>())).rand_deg as *const _ as usize },
+    ::std::mem::offset_of!(random_data, rand_deg),
     28usize,
     concat!(
       "Offset of field: ",
@@ -12751,7 +12712,7 @@ fn bindgen_test_layout_random_data() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<random_data>())).rand_sep as *const _ as usize },
+    ::std::mem::offset_of!(random_data, rand_sep),
     32usize,
     concat!(
       "Offset of field: ",
@@ -12761,7 +12722,7 @@ fn bindgen_test_layout_random_data() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<random_data>())).end_ptr as *const _ as usize },
+    ::std::mem::offset_of!(random_data, end_ptr),
     40usize,
     concat!(
       "Offset of field: ",
@@ -12852,7 +12813,7 @@ fn bindgen_test_layout_drand48_data() {
     concat!("Alignment of ", stringify!(drand48_data))
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<drand48_data>())).__x as *const _ as usize },
+    ::std::mem::offset_of!(drand48_data, __x),
     0usize,
     concat!(
       "Offset of field: ",
@@ -12862,7 +12823,7 @@ fn bindgen_test_layout_drand48_data() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<drand48_data>())).__old_x as *const _ as usize },
+    ::std::mem::offset_of!(drand48_data, __old_x),
     6usize,
     concat!(
       "Offset of field: ",
@@ -12872,7 +12833,7 @@ fn bindgen_test_layout_drand48_data() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<drand48_data>())).__c as *const _ as usize },
+    ::std::mem::offset_of!(drand48_data, __c),
     12usize,
     concat!(
       "Offset of field: ",
@@ -12882,7 +12843,7 @@ fn bindgen_test_layout_drand48_data() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<drand48_data>())).__init as *const _ as usize },
+    ::std::mem::offset_of!(drand48_data, __init),
     14usize,
     concat!(
       "Offset of field: ",
@@ -12892,7 +12853,7 @@ fn bindgen_test_layout_drand48_data() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<drand48_data>())).__a as *const _ as usize },
+    ::std::mem::offset_of!(drand48_data, __a),
     16usize,
     concat!(
       "Offset of field: ",
@@ -13347,7 +13308,7 @@ fn bindgen_test_layout__xlinkHandler() {
     concat!("Alignment of ", stringify!(_xlinkHandler))
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xlinkHandler>())).simple as *const _ as usize },
+    ::std::mem::offset_of!(_xlinkHandler, simple),
     0usize,
     concat!(
       "Offset of field: ",
@@ -13357,7 +13318,7 @@ fn bindgen_test_layout__xlinkHandler() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xlinkHandler>())).extended as *const _ as usize },
+    ::std::mem::offset_of!(_xlinkHandler, extended),
     8usize,
     concat!(
       "Offset of field: ",
@@ -13367,7 +13328,7 @@ fn bindgen_test_layout__xlinkHandler() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xlinkHandler>())).set as *const _ as usize },
+    ::std::mem::offset_of!(_xlinkHandler, set),
     16usize,
     concat!(
       "Offset of field: ",
@@ -14031,7 +13992,7 @@ fn bindgen_test_layout__xmlGlobalState() {
     concat!("Alignment of ", stringify!(_xmlGlobalState))
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlGlobalState>())).xmlParserVersion as *const _ as usize },
+    ::std::mem::offset_of!(_xmlGlobalState, xmlParserVersion),
     0usize,
     concat!(
       "Offset of field: ",
@@ -14041,9 +14002,7 @@ fn bindgen_test_layout__xmlGlobalState() {
     )
   );
   assert_eq!(
-    unsafe {
-      &(*(::std::ptr::null::<_xmlGlobalState>())).xmlDefaultSAXLocator as *const _ as usize
-    },
+    ::std::mem::offset_of!(_xmlGlobalState, xmlDefaultSAXLocator),
     8usize,
     concat!(
       "Offset of field: ",
@@ -14053,9 +14012,7 @@ fn bindgen_test_layout__xmlGlobalState() {
     )
   );
   assert_eq!(
-    unsafe {
-      &(*(::std::ptr::null::<_xmlGlobalState>())).xmlDefaultSAXHandler as *const _ as usize
-    },
+    ::std::mem::offset_of!(_xmlGlobalState, xmlDefaultSAXHandler),
     40usize,
     concat!(
       "Offset of field: ",
@@ -14065,9 +14022,7 @@ fn bindgen_test_layout__xmlGlobalState() {
     )
   );
   assert_eq!(
-    unsafe {
-      &(*(::std::ptr::null::<_xmlGlobalState>())).docbDefaultSAXHandler as *const _ as usize
-    },
+    ::std::mem::offset_of!(_xmlGlobalState, docbDefaultSAXHandler),
     264usize,
     concat!(
       "Offset of field: ",
@@ -14077,9 +14032,7 @@ fn bindgen_test_layout__xmlGlobalState() {
     )
   );
   assert_eq!(
-    unsafe {
-      &(*(::std::ptr::null::<_xmlGlobalState>())).htmlDefaultSAXHandler as *const _ as usize
-    },
+    ::std::mem::offset_of!(_xmlGlobalState, htmlDefaultSAXHandler),
     488usize,
     concat!(
       "Offset of field: ",
@@ -14089,7 +14042,7 @@ fn bindgen_test_layout__xmlGlobalState() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlGlobalState>())).xmlFree as *const _ as usize },
+    ::std::mem::offset_of!(_xmlGlobalState, xmlFree),
     712usize,
     concat!(
       "Offset of field: ",
@@ -14099,7 +14052,7 @@ fn bindgen_test_layout__xmlGlobalState() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlGlobalState>())).xmlMalloc as *const _ as usize },
+    ::std::mem::offset_of!(_xmlGlobalState, xmlMalloc),
     720usize,
     concat!(
       "Offset of field: ",
@@ -14109,7 +14062,7 @@ fn bindgen_test_layout__xmlGlobalState() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlGlobalState>())).xmlMemStrdup as *const _ as usize },
+    ::std::mem::offset_of!(_xmlGlobalState, xmlMemStrdup),
     728usize,
     concat!(
       "Offset of field: ",
@@ -14119,7 +14072,7 @@ fn bindgen_test_layout__xmlGlobalState() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlGlobalState>())).xmlRealloc as *const _ as usize },
+    ::std::mem::offset_of!(_xmlGlobalState, xmlRealloc),
     736usize,
     concat!(
       "Offset of field: ",
@@ -14129,7 +14082,7 @@ fn bindgen_test_layout__xmlGlobalState() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlGlobalState>())).xmlGenericError as *const _ as usize },
+    ::std::mem::offset_of!(_xmlGlobalState, xmlGenericError),
     744usize,
     concat!(
       "Offset of field: ",
@@ -14139,7 +14092,7 @@ fn bindgen_test_layout__xmlGlobalState() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlGlobalState>())).xmlStructuredError as *const _ as usize },
+    ::std::mem::offset_of!(_xmlGlobalState, xmlStructuredError),
     752usize,
     concat!(
       "Offset of field: ",
@@ -14149,9 +14102,7 @@ fn bindgen_test_layout__xmlGlobalState() {
     )
   );
   assert_eq!(
-    unsafe {
-      &(*(::std::ptr::null::<_xmlGlobalState>())).xmlGenericErrorContext as *const _ as usize
-    },
+    ::std::mem::offset_of!(_xmlGlobalState, xmlGenericErrorContext),
     760usize,
     concat!(
       "Offset of field: ",
@@ -14161,9 +14112,7 @@ fn bindgen_test_layout__xmlGlobalState() {
     )
   );
   assert_eq!(
-    unsafe {
-      &(*(::std::ptr::null::<_xmlGlobalState>())).oldXMLWDcompatibility as *const _ as usize
-    },
+    ::std::mem::offset_of!(_xmlGlobalState, oldXMLWDcompatibility),
     768usize,
     concat!(
       "Offset of field: ",
@@ -14173,9 +14122,7 @@ fn bindgen_test_layout__xmlGlobalState() {
     )
   );
   assert_eq!(
-    unsafe {
-      &(*(::std::ptr::null::<_xmlGlobalState>())).xmlBufferAllocScheme as *const _ as usize
-    },
+    ::std::mem::offset_of!(_xmlGlobalState, xmlBufferAllocScheme),
     772usize,
     concat!(
       "Offset of field: ",
@@ -14185,9 +14132,7 @@ fn bindgen_test_layout__xmlGlobalState() {
     )
   );
   assert_eq!(
-    unsafe {
-      &(*(::std::ptr::null::<_xmlGlobalState>())).xmlDefaultBufferSize as *const _ as usize
-    },
+    ::std::mem::offset_of!(_xmlGlobalState, xmlDefaultBufferSize),
     776usize,
     concat!(
       "Offset of field: ",
@@ -14197,10 +14142,7 @@ fn bindgen_test_layout__xmlGlobalState() {
     )
   );
   assert_eq!(
-    unsafe {
-      &(*(::std::ptr::null::<_xmlGlobalState>())).xmlSubstituteEntitiesDefaultValue as *const _
-        as usize
-    },
+    ::std::mem::offset_of!(_xmlGlobalState, xmlSubstituteEntitiesDefaultValue),
     780usize,
     concat!(
       "Offset of field: ",
@@ -14210,10 +14152,7 @@ fn bindgen_test_layout__xmlGlobalState() {
     )
   );
   assert_eq!(
-    unsafe {
-      &(*(::std::ptr::null::<_xmlGlobalState>())).xmlDoValidityCheckingDefaultValue as *const _
-        as usize
-    },
+    ::std::mem::offset_of!(_xmlGlobalState, xmlDoValidityCheckingDefaultValue),
     784usize,
     concat!(
       "Offset of field: ",
@@ -14223,9 +14162,7 @@ fn bindgen_test_layout__xmlGlobalState() {
     )
   );
   assert_eq!(
-    unsafe {
-      &(*(::std::ptr::null::<_xmlGlobalState>())).xmlGetWarningsDefaultValue as *const _ as usize
-    },
+    ::std::mem::offset_of!(_xmlGlobalState, xmlGetWarningsDefaultValue),
     788usize,
     concat!(
       "Offset of field: ",
@@ -14235,9 +14172,7 @@ fn bindgen_test_layout__xmlGlobalState() {
     )
   );
   assert_eq!(
-    unsafe {
-      &(*(::std::ptr::null::<_xmlGlobalState>())).xmlKeepBlanksDefaultValue as *const _ as usize
-    },
+    ::std::mem::offset_of!(_xmlGlobalState, xmlKeepBlanksDefaultValue),
     792usize,
     concat!(
       "Offset of field: ",
@@ -14247,9 +14182,7 @@ fn bindgen_test_layout__xmlGlobalState() {
     )
   );
   assert_eq!(
-    unsafe {
-      &(*(::std::ptr::null::<_xmlGlobalState>())).xmlLineNumbersDefaultValue as *const _ as usize
-    },
+    ::std::mem::offset_of!(_xmlGlobalState, xmlLineNumbersDefaultValue),
     796usize,
     concat!(
       "Offset of field: ",
@@ -14259,9 +14192,7 @@ fn bindgen_test_layout__xmlGlobalState() {
     )
   );
   assert_eq!(
-    unsafe {
-      &(*(::std::ptr::null::<_xmlGlobalState>())).xmlLoadExtDtdDefaultValue as *const _ as usize
-    },
+    ::std::mem::offset_of!(_xmlGlobalState, xmlLoadExtDtdDefaultValue),
     800usize,
     concat!(
       "Offset of field: ",
@@ -14271,9 +14202,7 @@ fn bindgen_test_layout__xmlGlobalState() {
     )
   );
   assert_eq!(
-    unsafe {
-      &(*(::std::ptr::null::<_xmlGlobalState>())).xmlParserDebugEntities as *const _ as usize
-    },
+    ::std::mem::offset_of!(_xmlGlobalState, xmlParserDebugEntities),
     804usize,
     concat!(
       "Offset of field: ",
@@ -14283,9 +14212,7 @@ fn bindgen_test_layout__xmlGlobalState() {
     )
   );
   assert_eq!(
-    unsafe {
-      &(*(::std::ptr::null::<_xmlGlobalState>())).xmlPedanticParserDefaultValue as *const _ as usize
-    },
+    ::std::mem::offset_of!(_xmlGlobalState, xmlPedanticParserDefaultValue),
     808usize,
     concat!(
       "Offset of field: ",
@@ -14295,7 +14222,7 @@ fn bindgen_test_layout__xmlGlobalState() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlGlobalState>())).xmlSaveNoEmptyTags as *const _ as usize },
+    ::std::mem::offset_of!(_xmlGlobalState, xmlSaveNoEmptyTags),
     812usize,
     concat!(
       "Offset of field: ",
@@ -14305,7 +14232,7 @@ fn bindgen_test_layout__xmlGlobalState() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlGlobalState>())).xmlIndentTreeOutput as *const _ as usize },
+    ::std::mem::offset_of!(_xmlGlobalState, xmlIndentTreeOutput),
     816usize,
     concat!(
       "Offset of field: ",
@@ -14315,7 +14242,7 @@ fn bindgen_test_layout__xmlGlobalState() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlGlobalState>())).xmlTreeIndentString as *const _ as usize },
+    ::std::mem::offset_of!(_xmlGlobalState, xmlTreeIndentString),
     824usize,
     concat!(
       "Offset of field: ",
@@ -14325,9 +14252,7 @@ fn bindgen_test_layout__xmlGlobalState() {
     )
   );
   assert_eq!(
-    unsafe {
-      &(*(::std::ptr::null::<_xmlGlobalState>())).xmlRegisterNodeDefaultValue as *const _ as usize
-    },
+    ::std::mem::offset_of!(_xmlGlobalState, xmlRegisterNodeDefaultValue),
     832usize,
     concat!(
       "Offset of field: ",
@@ -14337,9 +14262,7 @@ fn bindgen_test_layout__xmlGlobalState() {
     )
   );
   assert_eq!(
-    unsafe {
-      &(*(::std::ptr::null::<_xmlGlobalState>())).xmlDeregisterNodeDefaultValue as *const _ as usize
-    },
+    ::std::mem::offset_of!(_xmlGlobalState, xmlDeregisterNodeDefaultValue),
     840usize,
     concat!(
       "Offset of field: ",
@@ -14349,7 +14272,7 @@ fn bindgen_test_layout__xmlGlobalState() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlGlobalState>())).xmlMallocAtomic as *const _ as usize },
+    ::std::mem::offset_of!(_xmlGlobalState, xmlMallocAtomic),
     848usize,
     concat!(
       "Offset of field: ",
@@ -14359,7 +14282,7 @@ fn bindgen_test_layout__xmlGlobalState() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlGlobalState>())).xmlLastError as *const _ as usize },
+    ::std::mem::offset_of!(_xmlGlobalState, xmlLastError),
     856usize,
     concat!(
       "Offset of field: ",
@@ -14369,10 +14292,7 @@ fn bindgen_test_layout__xmlGlobalState() {
     )
   );
   assert_eq!(
-    unsafe {
-      &(*(::std::ptr::null::<_xmlGlobalState>())).xmlParserInputBufferCreateFilenameValue
-        as *const _ as usize
-    },
+    ::std::mem::offset_of!(_xmlGlobalState, xmlParserInputBufferCreateFilenameValue),
     944usize,
     concat!(
       "Offset of field: ",
@@ -14382,10 +14302,7 @@ fn bindgen_test_layout__xmlGlobalState() {
     )
   );
   assert_eq!(
-    unsafe {
-      &(*(::std::ptr::null::<_xmlGlobalState>())).xmlOutputBufferCreateFilenameValue as *const _
-        as usize
-    },
+    ::std::mem::offset_of!(_xmlGlobalState, xmlOutputBufferCreateFilenameValue),
     952usize,
     concat!(
       "Offset of field: ",
@@ -14395,9 +14312,7 @@ fn bindgen_test_layout__xmlGlobalState() {
     )
   );
   assert_eq!(
-    unsafe {
-      &(*(::std::ptr::null::<_xmlGlobalState>())).xmlStructuredErrorContext as *const _ as usize
-    },
+    ::std::mem::offset_of!(_xmlGlobalState, xmlStructuredErrorContext),
     960usize,
     concat!(
       "Offset of field: ",
@@ -14788,7 +14703,7 @@ fn bindgen_test_layout__xmlParserInputBuffer() {
     concat!("Alignment of ", stringify!(_xmlParserInputBuffer))
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserInputBuffer>())).context as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserInputBuffer, context),
     0usize,
     concat!(
       "Offset of field: ",
@@ -14798,7 +14713,7 @@ fn bindgen_test_layout__xmlParserInputBuffer() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserInputBuffer>())).readcallback as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserInputBuffer, readcallback),
     8usize,
     concat!(
       "Offset of field: ",
@@ -14808,7 +14723,7 @@ fn bindgen_test_layout__xmlParserInputBuffer() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserInputBuffer>())).closecallback as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserInputBuffer, closecallback),
     16usize,
     concat!(
       "Offset of field: ",
@@ -14818,7 +14733,7 @@ fn bindgen_test_layout__xmlParserInputBuffer() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserInputBuffer>())).encoder as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserInputBuffer, encoder),
     24usize,
     concat!(
       "Offset of field: ",
@@ -14828,7 +14743,7 @@ fn bindgen_test_layout__xmlParserInputBuffer() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserInputBuffer>())).buffer as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserInputBuffer, buffer),
     32usize,
     concat!(
       "Offset of field: ",
@@ -14838,7 +14753,7 @@ fn bindgen_test_layout__xmlParserInputBuffer() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserInputBuffer>())).raw as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserInputBuffer, raw),
     40usize,
     concat!(
       "Offset of field: ",
@@ -14848,7 +14763,7 @@ fn bindgen_test_layout__xmlParserInputBuffer() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserInputBuffer>())).compressed as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserInputBuffer, compressed),
     48usize,
     concat!(
       "Offset of field: ",
@@ -14858,7 +14773,7 @@ fn bindgen_test_layout__xmlParserInputBuffer() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserInputBuffer>())).error as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserInputBuffer, error),
     52usize,
     concat!(
       "Offset of field: ",
@@ -14868,7 +14783,7 @@ fn bindgen_test_layout__xmlParserInputBuffer() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlParserInputBuffer>())).rawconsumed as *const _ as usize },
+    ::std::mem::offset_of!(_xmlParserInputBuffer, rawconsumed),
     56usize,
     concat!(
       "Offset of field: ",
@@ -14903,7 +14818,7 @@ fn bindgen_test_layout__xmlOutputBuffer() {
     concat!("Alignment of ", stringify!(_xmlOutputBuffer))
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlOutputBuffer>())).context as *const _ as usize },
+    ::std::mem::offset_of!(_xmlOutputBuffer, context),
     0usize,
     concat!(
       "Offset of field: ",
@@ -14913,7 +14828,7 @@ fn bindgen_test_layout__xmlOutputBuffer() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlOutputBuffer>())).writecallback as *const _ as usize },
+    ::std::mem::offset_of!(_xmlOutputBuffer, writecallback),
     8usize,
     concat!(
       "Offset of field: ",
@@ -14923,7 +14838,7 @@ fn bindgen_test_layout__xmlOutputBuffer() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlOutputBuffer>())).closecallback as *const _ as usize },
+    ::std::mem::offset_of!(_xmlOutputBuffer, closecallback),
     16usize,
     concat!(
       "Offset of field: ",
@@ -14933,7 +14848,7 @@ fn bindgen_test_layout__xmlOutputBuffer() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlOutputBuffer>())).encoder as *const _ as usize },
+    ::std::mem::offset_of!(_xmlOutputBuffer, encoder),
     24usize,
     concat!(
       "Offset of field: ",
@@ -14943,7 +14858,7 @@ fn bindgen_test_layout__xmlOutputBuffer() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlOutputBuffer>())).buffer as *const _ as usize },
+    ::std::mem::offset_of!(_xmlOutputBuffer, buffer),
     32usize,
     concat!(
       "Offset of field: ",
@@ -14953,7 +14868,7 @@ fn bindgen_test_layout__xmlOutputBuffer() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlOutputBuffer>())).conv as *const _ as usize },
+    ::std::mem::offset_of!(_xmlOutputBuffer, conv),
     40usize,
     concat!(
       "Offset of field: ",
@@ -14963,7 +14878,7 @@ fn bindgen_test_layout__xmlOutputBuffer() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlOutputBuffer>())).written as *const _ as usize },
+    ::std::mem::offset_of!(_xmlOutputBuffer, written),
     48usize,
     concat!(
       "Offset of field: ",
@@ -14973,7 +14888,7 @@ fn bindgen_test_layout__xmlOutputBuffer() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlOutputBuffer>())).error as *const _ as usize },
+    ::std::mem::offset_of!(_xmlOutputBuffer, error),
     52usize,
     concat!(
       "Offset of field: ",
@@ -15764,7 +15679,7 @@ fn bindgen_test_layout__htmlElemDesc() {
     concat!("Alignment of ", stringify!(_htmlElemDesc))
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_htmlElemDesc>())).name as *const _ as usize },
+    ::std::mem::offset_of!(_htmlElemDesc, name),
     0usize,
     concat!(
       "Offset of field: ",
@@ -15774,7 +15689,7 @@ fn bindgen_test_layout__htmlElemDesc() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_htmlElemDesc>())).startTag as *const _ as usize },
+    ::std::mem::offset_of!(_htmlElemDesc, startTag),
     8usize,
     concat!(
       "Offset of field: ",
@@ -15784,7 +15699,7 @@ fn bindgen_test_layout__htmlElemDesc() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_htmlElemDesc>())).endTag as *const _ as usize },
+    ::std::mem::offset_of!(_htmlElemDesc, endTag),
     9usize,
     concat!(
       "Offset of field: ",
@@ -15794,7 +15709,7 @@ fn bindgen_test_layout__htmlElemDesc() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_htmlElemDesc>())).saveEndTag as *const _ as usize },
+    ::std::mem::offset_of!(_htmlElemDesc, saveEndTag),
     10usize,
     concat!(
       "Offset of field: ",
@@ -15804,7 +15719,7 @@ fn bindgen_test_layout__htmlElemDesc() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_htmlElemDesc>())).empty as *const _ as usize },
+    ::std::mem::offset_of!(_htmlElemDesc, empty),
     11usize,
     concat!(
       "Offset of field: ",
@@ -15814,7 +15729,7 @@ fn bindgen_test_layout__htmlElemDesc() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_htmlElemDesc>())).depr as *const _ as usize },
+    ::std::mem::offset_of!(_htmlElemDesc, depr),
     12usize,
     concat!(
       "Offset of field: ",
@@ -15824,7 +15739,7 @@ fn bindgen_test_layout__htmlElemDesc() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_htmlElemDesc>())).dtd as *const _ as usize },
+    ::std::mem::offset_of!(_htmlElemDesc, dtd),
     13usize,
     concat!(
       "Offset of field: ",
@@ -15834,7 +15749,7 @@ fn bindgen_test_layout__htmlElemDesc() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_htmlElemDesc>())).isinline as *const _ as usize },
+    ::std::mem::offset_of!(_htmlElemDesc, isinline),
     14usize,
     concat!(
       "Offset of field: ",
@@ -15844,7 +15759,7 @@ fn bindgen_test_layout__htmlElemDesc() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_htmlElemDesc>())).desc as *const _ as usize },
+    ::std::mem::offset_of!(_htmlElemDesc, desc),
     16usize,
     concat!(
       "Offset of field: ",
@@ -15854,7 +15769,7 @@ fn bindgen_test_layout__htmlElemDesc() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_htmlElemDesc>())).subelts as *const _ as usize },
+    ::std::mem::offset_of!(_htmlElemDesc, subelts),
     24usize,
     concat!(
       "Offset of field: ",
@@ -15864,7 +15779,7 @@ fn bindgen_test_layout__htmlElemDesc() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_htmlElemDesc>())).defaultsubelt as *const _ as usize },
+    ::std::mem::offset_of!(_htmlElemDesc, defaultsubelt),
     32usize,
     concat!(
       "Offset of field: ",
@@ -15874,7 +15789,7 @@ fn bindgen_test_layout__htmlElemDesc() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_htmlElemDesc>())).attrs_opt as *const _ as usize },
+    ::std::mem::offset_of!(_htmlElemDesc, attrs_opt),
     40usize,
     concat!(
       "Offset of field: ",
@@ -15884,7 +15799,7 @@ fn bindgen_test_layout__htmlElemDesc() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_htmlElemDesc>())).attrs_depr as *const _ as usize },
+    ::std::mem::offset_of!(_htmlElemDesc, attrs_depr),
     48usize,
     concat!(
       "Offset of field: ",
@@ -15894,7 +15809,7 @@ fn bindgen_test_layout__htmlElemDesc() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_htmlElemDesc>())).attrs_req as *const _ as usize },
+    ::std::mem::offset_of!(_htmlElemDesc, attrs_req),
     56usize,
     concat!(
       "Offset of field: ",
@@ -15926,7 +15841,7 @@ fn bindgen_test_layout__htmlEntityDesc() {
     concat!("Alignment of ", stringify!(_htmlEntityDesc))
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_htmlEntityDesc>())).value as *const _ as usize },
+    ::std::mem::offset_of!(_htmlEntityDesc, value),
     0usize,
     concat!(
       "Offset of field: ",
@@ -15936,7 +15851,7 @@ fn bindgen_test_layout__htmlEntityDesc() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_htmlEntityDesc>())).name as *const _ as usize },
+    ::std::mem::offset_of!(_htmlEntityDesc, name),
     8usize,
     concat!(
       "Offset of field: ",
@@ -15946,7 +15861,7 @@ fn bindgen_test_layout__htmlEntityDesc() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_htmlEntityDesc>())).desc as *const _ as usize },
+    ::std::mem::offset_of!(_htmlEntityDesc, desc),
     16usize,
     concat!(
       "Offset of field: ",
@@ -16228,7 +16143,7 @@ fn bindgen_test_layout__xmlChSRange() {
     concat!("Alignment of ", stringify!(_xmlChSRange))
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlChSRange>())).low as *const _ as usize },
+    ::std::mem::offset_of!(_xmlChSRange, low),
     0usize,
     concat!(
       "Offset of field: ",
@@ -16238,7 +16153,7 @@ fn bindgen_test_layout__xmlChSRange() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlChSRange>())).high as *const _ as usize },
+    ::std::mem::offset_of!(_xmlChSRange, high),
     2usize,
     concat!(
       "Offset of field: ",
@@ -16269,7 +16184,7 @@ fn bindgen_test_layout__xmlChLRange() {
     concat!("Alignment of ", stringify!(_xmlChLRange))
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlChLRange>())).low as *const _ as usize },
+    ::std::mem::offset_of!(_xmlChLRange, low),
     0usize,
     concat!(
       "Offset of field: ",
@@ -16279,7 +16194,7 @@ fn bindgen_test_layout__xmlChLRange() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlChLRange>())).high as *const _ as usize },
+    ::std::mem::offset_of!(_xmlChLRange, high),
     4usize,
     concat!(
       "Offset of field: ",
@@ -16312,7 +16227,7 @@ fn bindgen_test_layout__xmlChRangeGroup() {
     concat!("Alignment of ", stringify!(_xmlChRangeGroup))
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlChRangeGroup>())).nbShortRange as *const _ as usize },
+    ::std::mem::offset_of!(_xmlChRangeGroup, nbShortRange),
     0usize,
     concat!(
       "Offset of field: ",
@@ -16322,7 +16237,7 @@ fn bindgen_test_layout__xmlChRangeGroup() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlChRangeGroup>())).nbLongRange as *const _ as usize },
+    ::std::mem::offset_of!(_xmlChRangeGroup, nbLongRange),
     4usize,
     concat!(
       "Offset of field: ",
@@ -16332,7 +16247,7 @@ fn bindgen_test_layout__xmlChRangeGroup() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlChRangeGroup>())).shortRange as *const _ as usize },
+    ::std::mem::offset_of!(_xmlChRangeGroup, shortRange),
     8usize,
     concat!(
       "Offset of field: ",
@@ -16342,7 +16257,7 @@ fn bindgen_test_layout__xmlChRangeGroup() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlChRangeGroup>())).longRange as *const _ as usize },
+    ::std::mem::offset_of!(_xmlChRangeGroup, longRange),
     16usize,
     concat!(
       "Offset of field: ",
@@ -17029,7 +16944,7 @@ fn bindgen_test_layout__xmlNodeSet() {
     concat!("Alignment of ", stringify!(_xmlNodeSet))
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlNodeSet>())).nodeNr as *const _ as usize },
+    ::std::mem::offset_of!(_xmlNodeSet, nodeNr),
     0usize,
     concat!(
       "Offset of field: ",
@@ -17039,7 +16954,7 @@ fn bindgen_test_layout__xmlNodeSet() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlNodeSet>())).nodeMax as *const _ as usize },
+    ::std::mem::offset_of!(_xmlNodeSet, nodeMax),
     4usize,
     concat!(
       "Offset of field: ",
@@ -17049,7 +16964,7 @@ fn bindgen_test_layout__xmlNodeSet() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlNodeSet>())).nodeTab as *const _ as usize },
+    ::std::mem::offset_of!(_xmlNodeSet, nodeTab),
     8usize,
     concat!(
       "Offset of field: ",
@@ -17098,7 +17013,7 @@ fn bindgen_test_layout__xmlXPathObject() {
     concat!("Alignment of ", stringify!(_xmlXPathObject))
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlXPathObject>())).type_ as *const _ as usize },
+    ::std::mem::offset_of!(_xmlXPathObject, type_),
     0usize,
     concat!(
       "Offset of field: ",
@@ -17108,7 +17023,7 @@ fn bindgen_test_layout__xmlXPathObject() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlXPathObject>())).nodesetval as *const _ as usize },
+    ::std::mem::offset_of!(_xmlXPathObject, nodesetval),
     8usize,
     concat!(
       "Offset of field: ",
@@ -17118,7 +17033,7 @@ fn bindgen_test_layout__xmlXPathObject() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlXPathObject>())).boolval as *const _ as usize },
+    ::std::mem::offset_of!(_xmlXPathObject, boolval),
     16usize,
     concat!(
       "Offset of field: ",
@@ -17128,7 +17043,7 @@ fn bindgen_test_layout__xmlXPathObject() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlXPathObject>())).floatval as *const _ as usize },
+    ::std::mem::offset_of!(_xmlXPathObject, floatval),
     24usize,
     concat!(
       "Offset of field: ",
@@ -17138,7 +17053,7 @@ fn bindgen_test_layout__xmlXPathObject() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlXPathObject>())).stringval as *const _ as usize },
+    ::std::mem::offset_of!(_xmlXPathObject, stringval),
     32usize,
     concat!(
       "Offset of field: ",
@@ -17148,7 +17063,7 @@ fn bindgen_test_layout__xmlXPathObject() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlXPathObject>())).user as *const _ as usize },
+    ::std::mem::offset_of!(_xmlXPathObject, user),
     40usize,
     concat!(
       "Offset of field: ",
@@ -17158,7 +17073,7 @@ fn bindgen_test_layout__xmlXPathObject() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlXPathObject>())).index as *const _ as usize },
+    ::std::mem::offset_of!(_xmlXPathObject, index),
     48usize,
     concat!(
       "Offset of field: ",
@@ -17168,7 +17083,7 @@ fn bindgen_test_layout__xmlXPathObject() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlXPathObject>())).user2 as *const _ as usize },
+    ::std::mem::offset_of!(_xmlXPathObject, user2),
     56usize,
     concat!(
       "Offset of field: ",
@@ -17178,7 +17093,7 @@ fn bindgen_test_layout__xmlXPathObject() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlXPathObject>())).index2 as *const _ as usize },
+    ::std::mem::offset_of!(_xmlXPathObject, index2),
     64usize,
     concat!(
       "Offset of field: ",
@@ -17223,7 +17138,7 @@ fn bindgen_test_layout__xmlXPathType() {
     concat!("Alignment of ", stringify!(_xmlXPathType))
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlXPathType>())).name as *const _ as usize },
+    ::std::mem::offset_of!(_xmlXPathType, name),
     0usize,
     concat!(
       "Offset of field: ",
@@ -17233,7 +17148,7 @@ fn bindgen_test_layout__xmlXPathType() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlXPathType>())).func as *const _ as usize },
+    ::std::mem::offset_of!(_xmlXPathType, func),
     8usize,
     concat!(
       "Offset of field: ",
@@ -17264,7 +17179,7 @@ fn bindgen_test_layout__xmlXPathVariable() {
     concat!("Alignment of ", stringify!(_xmlXPathVariable))
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlXPathVariable>())).name as *const _ as usize },
+    ::std::mem::offset_of!(_xmlXPathVariable, name),
     0usize,
     concat!(
       "Offset of field: ",
@@ -17274,7 +17189,7 @@ fn bindgen_test_layout__xmlXPathVariable() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlXPathVariable>())).value as *const _ as usize },
+    ::std::mem::offset_of!(_xmlXPathVariable, value),
     8usize,
     concat!(
       "Offset of field: ",
@@ -17313,7 +17228,7 @@ fn bindgen_test_layout__xmlXPathFunct() {
     concat!("Alignment of ", stringify!(_xmlXPathFunct))
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlXPathFunct>())).name as *const _ as usize },
+    ::std::mem::offset_of!(_xmlXPathFunct, name),
     0usize,
     concat!(
       "Offset of field: ",
@@ -17323,7 +17238,7 @@ fn bindgen_test_layout__xmlXPathFunct() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlXPathFunct>())).func as *const _ as usize },
+    ::std::mem::offset_of!(_xmlXPathFunct, func),
     8usize,
     concat!(
       "Offset of field: ",
@@ -17366,7 +17281,7 @@ fn bindgen_test_layout__xmlXPathAxis() {
     concat!("Alignment of ", stringify!(_xmlXPathAxis))
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlXPathAxis>())).name as *const _ as usize },
+    ::std::mem::offset_of!(_xmlXPathAxis, name),
     0usize,
     concat!(
       "Offset of field: ",
@@ -17376,7 +17291,7 @@ fn bindgen_test_layout__xmlXPathAxis() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlXPathAxis>())).func as *const _ as usize },
+    ::std::mem::offset_of!(_xmlXPathAxis, func),
     8usize,
     concat!(
       "Offset of field: ",
@@ -17498,7 +17413,7 @@ fn bindgen_test_layout__xmlXPathContext() {
     concat!("Alignment of ", stringify!(_xmlXPathContext))
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlXPathContext>())).doc as *const _ as usize },
+    ::std::mem::offset_of!(_xmlXPathContext, doc),
     0usize,
     concat!(
       "Offset of field: ",
@@ -17508,7 +17423,7 @@ fn bindgen_test_layout__xmlXPathContext() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlXPathContext>())).node as *const _ as usize },
+    ::std::mem::offset_of!(_xmlXPathContext, node),
     8usize,
     concat!(
       "Offset of field: ",
@@ -17518,9 +17433,7 @@ fn bindgen_test_layout__xmlXPathContext() {
     )
   );
   assert_eq!(
-    unsafe {
-      &(*(::std::ptr::null::<_xmlXPathContext>())).nb_variables_unused as *const _ as usize
-    },
+    ::std::mem::offset_of!(_xmlXPathContext, nb_variables_unused),
     16usize,
     concat!(
       "Offset of field: ",
@@ -17530,9 +17443,7 @@ fn bindgen_test_layout__xmlXPathContext() {
     )
   );
   assert_eq!(
-    unsafe {
-      &(*(::std::ptr::null::<_xmlXPathContext>())).max_variables_unused as *const _ as usize
-    },
+    ::std::mem::offset_of!(_xmlXPathContext, max_variables_unused),
     20usize,
     concat!(
       "Offset of field: ",
@@ -17542,7 +17453,7 @@ fn bindgen_test_layout__xmlXPathContext() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlXPathContext>())).varHash as *const _ as usize },
+    ::std::mem::offset_of!(_xmlXPathContext, varHash),
     24usize,
     concat!(
       "Offset of field: ",
@@ -17552,7 +17463,7 @@ fn bindgen_test_layout__xmlXPathContext() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlXPathContext>())).nb_types as *const _ as usize },
+    ::std::mem::offset_of!(_xmlXPathContext, nb_types),
     32usize,
     concat!(
       "Offset of field: ",
@@ -17562,7 +17473,7 @@ fn bindgen_test_layout__xmlXPathContext() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlXPathContext>())).max_types as *const _ as usize },
+    ::std::mem::offset_of!(_xmlXPathContext, max_types),
     36usize,
     concat!(
       "Offset of field: ",
@@ -17572,7 +17483,7 @@ fn bindgen_test_layout__xmlXPathContext() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlXPathContext>())).types as *const _ as usize },
+    ::std::mem::offset_of!(_xmlXPathContext, types),
     40usize,
     concat!(
       "Offset of field: ",
@@ -17582,7 +17493,7 @@ fn bindgen_test_layout__xmlXPathContext() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlXPathContext>())).nb_funcs_unused as *const _ as usize },
+    ::std::mem::offset_of!(_xmlXPathContext, nb_funcs_unused),
     48usize,
     concat!(
       "Offset of field: ",
@@ -17592,7 +17503,7 @@ fn bindgen_test_layout__xmlXPathContext() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlXPathContext>())).max_funcs_unused as *const _ as usize },
+    ::std::mem::offset_of!(_xmlXPathContext, max_funcs_unused),
     52usize,
     concat!(
       "Offset of field: ",
@@ -17602,7 +17513,7 @@ fn bindgen_test_layout__xmlXPathContext() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlXPathContext>())).funcHash as *const _ as usize },
+    ::std::mem::offset_of!(_xmlXPathContext, funcHash),
     56usize,
     concat!(
       "Offset of field: ",
@@ -17612,7 +17523,7 @@ fn bindgen_test_layout__xmlXPathContext() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlXPathContext>())).nb_axis as *const _ as usize },
+    ::std::mem::offset_of!(_xmlXPathContext, nb_axis),
     64usize,
     concat!(
       "Offset of field: ",
@@ -17622,7 +17533,7 @@ fn bindgen_test_layout__xmlXPathContext() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlXPathContext>())).max_axis as *const _ as usize },
+    ::std::mem::offset_of!(_xmlXPathContext, max_axis),
     68usize,
     concat!(
       "Offset of field: ",
@@ -17632,7 +17543,7 @@ fn bindgen_test_layout__xmlXPathContext() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlXPathContext>())).axis as *const _ as usize },
+    ::std::mem::offset_of!(_xmlXPathContext, axis),
     72usize,
     concat!(
       "Offset of field: ",
@@ -17642,7 +17553,7 @@ fn bindgen_test_layout__xmlXPathContext() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlXPathContext>())).namespaces as *const _ as usize },
+    ::std::mem::offset_of!(_xmlXPathContext, namespaces),
     80usize,
     concat!(
       "Offset of field: ",
@@ -17652,7 +17563,7 @@ fn bindgen_test_layout__xmlXPathContext() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlXPathContext>())).nsNr as *const _ as usize },
+    ::std::mem::offset_of!(_xmlXPathContext, nsNr),
     88usize,
     concat!(
       "Offset of field: ",
@@ -17662,7 +17573,7 @@ fn bindgen_test_layout__xmlXPathContext() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlXPathContext>())).user as *const _ as usize },
+    ::std::mem::offset_of!(_xmlXPathContext, user),
     96usize,
     concat!(
       "Offset of field: ",
@@ -17672,7 +17583,7 @@ fn bindgen_test_layout__xmlXPathContext() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlXPathContext>())).contextSize as *const _ as usize },
+    ::std::mem::offset_of!(_xmlXPathContext, contextSize),
     104usize,
     concat!(
       "Offset of field: ",
@@ -17682,7 +17593,7 @@ fn bindgen_test_layout__xmlXPathContext() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlXPathContext>())).proximityPosition as *const _ as usize },
+    ::std::mem::offset_of!(_xmlXPathContext, proximityPosition),
     108usize,
     concat!(
       "Offset of field: ",
@@ -17692,7 +17603,7 @@ fn bindgen_test_layout__xmlXPathContext() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlXPathContext>())).xptr as *const _ as usize },
+    ::std::mem::offset_of!(_xmlXPathContext, xptr),
     112usize,
     concat!(
       "Offset of field: ",
@@ -17702,7 +17613,7 @@ fn bindgen_test_layout__xmlXPathContext() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlXPathContext>())).here as *const _ as usize },
+    ::std::mem::offset_of!(_xmlXPathContext, here),
     120usize,
     concat!(
       "Offset of field: ",
@@ -17712,7 +17623,7 @@ fn bindgen_test_layout__xmlXPathContext() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlXPathContext>())).origin as *const _ as usize },
+    ::std::mem::offset_of!(_xmlXPathContext, origin),
     128usize,
     concat!(
       "Offset of field: ",
@@ -17722,7 +17633,7 @@ fn bindgen_test_layout__xmlXPathContext() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlXPathContext>())).nsHash as *const _ as usize },
+    ::std::mem::offset_of!(_xmlXPathContext, nsHash),
     136usize,
     concat!(
       "Offset of field: ",
@@ -17732,7 +17643,7 @@ fn bindgen_test_layout__xmlXPathContext() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlXPathContext>())).varLookupFunc as *const _ as usize },
+    ::std::mem::offset_of!(_xmlXPathContext, varLookupFunc),
     144usize,
     concat!(
       "Offset of field: ",
@@ -17742,7 +17653,7 @@ fn bindgen_test_layout__xmlXPathContext() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlXPathContext>())).varLookupData as *const _ as usize },
+    ::std::mem::offset_of!(_xmlXPathContext, varLookupData),
     152usize,
     concat!(
       "Offset of field: ",
@@ -17752,7 +17663,7 @@ fn bindgen_test_layout__xmlXPathContext() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlXPathContext>())).extra as *const _ as usize },
+    ::std::mem::offset_of!(_xmlXPathContext, extra),
     160usize,
     concat!(
       "Offset of field: ",
@@ -17762,7 +17673,7 @@ fn bindgen_test_layout__xmlXPathContext() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlXPathContext>())).function as *const _ as usize },
+    ::std::mem::offset_of!(_xmlXPathContext, function),
     168usize,
     concat!(
       "Offset of field: ",
@@ -17772,7 +17683,7 @@ fn bindgen_test_layout__xmlXPathContext() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlXPathContext>())).functionURI as *const _ as usize },
+    ::std::mem::offset_of!(_xmlXPathContext, functionURI),
     176usize,
     concat!(
       "Offset of field: ",
@@ -17782,7 +17693,7 @@ fn bindgen_test_layout__xmlXPathContext() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlXPathContext>())).funcLookupFunc as *const _ as usize },
+    ::std::mem::offset_of!(_xmlXPathContext, funcLookupFunc),
     184usize,
     concat!(
       "Offset of field: ",
@@ -17792,7 +17703,7 @@ fn bindgen_test_layout__xmlXPathContext() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlXPathContext>())).funcLookupData as *const _ as usize },
+    ::std::mem::offset_of!(_xmlXPathContext, funcLookupData),
     192usize,
     concat!(
       "Offset of field: ",
@@ -17802,7 +17713,7 @@ fn bindgen_test_layout__xmlXPathContext() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlXPathContext>())).tmpNsList as *const _ as usize },
+    ::std::mem::offset_of!(_xmlXPathContext, tmpNsList),
     200usize,
     concat!(
       "Offset of field: ",
@@ -17812,7 +17723,7 @@ fn bindgen_test_layout__xmlXPathContext() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlXPathContext>())).tmpNsNr as *const _ as usize },
+    ::std::mem::offset_of!(_xmlXPathContext, tmpNsNr),
     208usize,
     concat!(
       "Offset of field: ",
@@ -17822,7 +17733,7 @@ fn bindgen_test_layout__xmlXPathContext() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlXPathContext>())).userData as *const _ as usize },
+    ::std::mem::offset_of!(_xmlXPathContext, userData),
     216usize,
     concat!(
       "Offset of field: ",
@@ -17832,7 +17743,7 @@ fn bindgen_test_layout__xmlXPathContext() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlXPathContext>())).error as *const _ as usize },
+    ::std::mem::offset_of!(_xmlXPathContext, error),
     224usize,
     concat!(
       "Offset of field: ",
@@ -17842,7 +17753,7 @@ fn bindgen_test_layout__xmlXPathContext() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlXPathContext>())).lastError as *const _ as usize },
+    ::std::mem::offset_of!(_xmlXPathContext, lastError),
     232usize,
     concat!(
       "Offset of field: ",
@@ -17852,7 +17763,7 @@ fn bindgen_test_layout__xmlXPathContext() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlXPathContext>())).debugNode as *const _ as usize },
+    ::std::mem::offset_of!(_xmlXPathContext, debugNode),
     320usize,
     concat!(
       "Offset of field: ",
@@ -17862,7 +17773,7 @@ fn bindgen_test_layout__xmlXPathContext() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlXPathContext>())).dict as *const _ as usize },
+    ::std::mem::offset_of!(_xmlXPathContext, dict),
     328usize,
     concat!(
       "Offset of field: ",
@@ -17872,7 +17783,7 @@ fn bindgen_test_layout__xmlXPathContext() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlXPathContext>())).flags as *const _ as usize },
+    ::std::mem::offset_of!(_xmlXPathContext, flags),
     336usize,
     concat!(
       "Offset of field: ",
@@ -17882,7 +17793,7 @@ fn bindgen_test_layout__xmlXPathContext() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlXPathContext>())).cache as *const _ as usize },
+    ::std::mem::offset_of!(_xmlXPathContext, cache),
     344usize,
     concat!(
       "Offset of field: ",
@@ -17932,7 +17843,7 @@ fn bindgen_test_layout__xmlXPathParserContext() {
     concat!("Alignment of ", stringify!(_xmlXPathParserContext))
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlXPathParserContext>())).cur as *const _ as usize },
+    ::std::mem::offset_of!(_xmlXPathParserContext, cur),
     0usize,
     concat!(
       "Offset of field: ",
@@ -17942,7 +17853,7 @@ fn bindgen_test_layout__xmlXPathParserContext() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlXPathParserContext>())).base as *const _ as usize },
+    ::std::mem::offset_of!(_xmlXPathParserContext, base),
     8usize,
     concat!(
       "Offset of field: ",
@@ -17952,7 +17863,7 @@ fn bindgen_test_layout__xmlXPathParserContext() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlXPathParserContext>())).error as *const _ as usize },
+    ::std::mem::offset_of!(_xmlXPathParserContext, error),
     16usize,
     concat!(
       "Offset of field: ",
@@ -17962,7 +17873,7 @@ fn bindgen_test_layout__xmlXPathParserContext() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlXPathParserContext>())).context as *const _ as usize },
+    ::std::mem::offset_of!(_xmlXPathParserContext, context),
     24usize,
     concat!(
       "Offset of field: ",
@@ -17972,7 +17883,7 @@ fn bindgen_test_layout__xmlXPathParserContext() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlXPathParserContext>())).value as *const _ as usize },
+    ::std::mem::offset_of!(_xmlXPathParserContext, value),
     32usize,
     concat!(
       "Offset of field: ",
@@ -17982,7 +17893,7 @@ fn bindgen_test_layout__xmlXPathParserContext() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlXPathParserContext>())).valueNr as *const _ as usize },
+    ::std::mem::offset_of!(_xmlXPathParserContext, valueNr),
     40usize,
     concat!(
       "Offset of field: ",
@@ -17992,7 +17903,7 @@ fn bindgen_test_layout__xmlXPathParserContext() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlXPathParserContext>())).valueMax as *const _ as usize },
+    ::std::mem::offset_of!(_xmlXPathParserContext, valueMax),
     44usize,
     concat!(
       "Offset of field: ",
@@ -18002,7 +17913,7 @@ fn bindgen_test_layout__xmlXPathParserContext() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlXPathParserContext>())).valueTab as *const _ as usize },
+    ::std::mem::offset_of!(_xmlXPathParserContext, valueTab),
     48usize,
     concat!(
       "Offset of field: ",
@@ -18012,7 +17923,7 @@ fn bindgen_test_layout__xmlXPathParserContext() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlXPathParserContext>())).comp as *const _ as usize },
+    ::std::mem::offset_of!(_xmlXPathParserContext, comp),
     56usize,
     concat!(
       "Offset of field: ",
@@ -18022,7 +17933,7 @@ fn bindgen_test_layout__xmlXPathParserContext() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlXPathParserContext>())).xptr as *const _ as usize },
+    ::std::mem::offset_of!(_xmlXPathParserContext, xptr),
     64usize,
     concat!(
       "Offset of field: ",
@@ -18032,7 +17943,7 @@ fn bindgen_test_layout__xmlXPathParserContext() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlXPathParserContext>())).ancestor as *const _ as usize },
+    ::std::mem::offset_of!(_xmlXPathParserContext, ancestor),
     72usize,
     concat!(
       "Offset of field: ",
@@ -18042,7 +17953,7 @@ fn bindgen_test_layout__xmlXPathParserContext() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlXPathParserContext>())).valueFrame as *const _ as usize },
+    ::std::mem::offset_of!(_xmlXPathParserContext, valueFrame),
     80usize,
     concat!(
       "Offset of field: ",
@@ -18506,7 +18417,7 @@ fn bindgen_test_layout__xmlShellCtxt() {
     concat!("Alignment of ", stringify!(_xmlShellCtxt))
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlShellCtxt>())).filename as *const _ as usize },
+    ::std::mem::offset_of!(_xmlShellCtxt, filename),
     0usize,
     concat!(
       "Offset of field: ",
@@ -18516,7 +18427,7 @@ fn bindgen_test_layout__xmlShellCtxt() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlShellCtxt>())).doc as *const _ as usize },
+    ::std::mem::offset_of!(_xmlShellCtxt, doc),
     8usize,
     concat!(
       "Offset of field: ",
@@ -18526,7 +18437,7 @@ fn bindgen_test_layout__xmlShellCtxt() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlShellCtxt>())).node as *const _ as usize },
+    ::std::mem::offset_of!(_xmlShellCtxt, node),
     16usize,
     concat!(
       "Offset of field: ",
@@ -18536,7 +18447,7 @@ fn bindgen_test_layout__xmlShellCtxt() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlShellCtxt>())).pctxt as *const _ as usize },
+    ::std::mem::offset_of!(_xmlShellCtxt, pctxt),
     24usize,
     concat!(
       "Offset of field: ",
@@ -18546,7 +18457,7 @@ fn bindgen_test_layout__xmlShellCtxt() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlShellCtxt>())).loaded as *const _ as usize },
+    ::std::mem::offset_of!(_xmlShellCtxt, loaded),
     32usize,
     concat!(
       "Offset of field: ",
@@ -18556,7 +18467,7 @@ fn bindgen_test_layout__xmlShellCtxt() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlShellCtxt>())).output as *const _ as usize },
+    ::std::mem::offset_of!(_xmlShellCtxt, output),
     40usize,
     concat!(
       "Offset of field: ",
@@ -18566,7 +18477,7 @@ fn bindgen_test_layout__xmlShellCtxt() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlShellCtxt>())).input as *const _ as usize },
+    ::std::mem::offset_of!(_xmlShellCtxt, input),
     48usize,
     concat!(
       "Offset of field: ",
@@ -19354,7 +19265,7 @@ fn bindgen_test_layout__xmlSchemaAnnot() {
     concat!("Alignment of ", stringify!(_xmlSchemaAnnot))
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaAnnot>())).next as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaAnnot, next),
     0usize,
     concat!(
       "Offset of field: ",
@@ -19364,7 +19275,7 @@ fn bindgen_test_layout__xmlSchemaAnnot() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaAnnot>())).content as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaAnnot, content),
     8usize,
     concat!(
       "Offset of field: ",
@@ -19414,7 +19325,7 @@ fn bindgen_test_layout__xmlSchemaAttribute() {
     concat!("Alignment of ", stringify!(_xmlSchemaAttribute))
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaAttribute>())).type_ as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaAttribute, type_),
     0usize,
     concat!(
       "Offset of field: ",
@@ -19424,7 +19335,7 @@ fn bindgen_test_layout__xmlSchemaAttribute() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaAttribute>())).next as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaAttribute, next),
     8usize,
     concat!(
       "Offset of field: ",
@@ -19434,7 +19345,7 @@ fn bindgen_test_layout__xmlSchemaAttribute() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaAttribute>())).name as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaAttribute, name),
     16usize,
     concat!(
       "Offset of field: ",
@@ -19444,7 +19355,7 @@ fn bindgen_test_layout__xmlSchemaAttribute() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaAttribute>())).id as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaAttribute, id),
     24usize,
     concat!(
       "Offset of field: ",
@@ -19454,7 +19365,7 @@ fn bindgen_test_layout__xmlSchemaAttribute() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaAttribute>())).ref_ as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaAttribute, ref_),
     32usize,
     concat!(
       "Offset of field: ",
@@ -19464,7 +19375,7 @@ fn bindgen_test_layout__xmlSchemaAttribute() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaAttribute>())).refNs as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaAttribute, refNs),
     40usize,
     concat!(
       "Offset of field: ",
@@ -19474,7 +19385,7 @@ fn bindgen_test_layout__xmlSchemaAttribute() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaAttribute>())).typeName as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaAttribute, typeName),
     48usize,
     concat!(
       "Offset of field: ",
@@ -19484,7 +19395,7 @@ fn bindgen_test_layout__xmlSchemaAttribute() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaAttribute>())).typeNs as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaAttribute, typeNs),
     56usize,
     concat!(
       "Offset of field: ",
@@ -19494,7 +19405,7 @@ fn bindgen_test_layout__xmlSchemaAttribute() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaAttribute>())).annot as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaAttribute, annot),
     64usize,
     concat!(
       "Offset of field: ",
@@ -19504,7 +19415,7 @@ fn bindgen_test_layout__xmlSchemaAttribute() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaAttribute>())).base as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaAttribute, base),
     72usize,
     concat!(
       "Offset of field: ",
@@ -19514,7 +19425,7 @@ fn bindgen_test_layout__xmlSchemaAttribute() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaAttribute>())).occurs as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaAttribute, occurs),
     80usize,
     concat!(
       "Offset of field: ",
@@ -19524,7 +19435,7 @@ fn bindgen_test_layout__xmlSchemaAttribute() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaAttribute>())).defValue as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaAttribute, defValue),
     88usize,
     concat!(
       "Offset of field: ",
@@ -19534,7 +19445,7 @@ fn bindgen_test_layout__xmlSchemaAttribute() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaAttribute>())).subtypes as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaAttribute, subtypes),
     96usize,
     concat!(
       "Offset of field: ",
@@ -19544,7 +19455,7 @@ fn bindgen_test_layout__xmlSchemaAttribute() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaAttribute>())).node as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaAttribute, node),
     104usize,
     concat!(
       "Offset of field: ",
@@ -19554,7 +19465,7 @@ fn bindgen_test_layout__xmlSchemaAttribute() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaAttribute>())).targetNamespace as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaAttribute, targetNamespace),
     112usize,
     concat!(
       "Offset of field: ",
@@ -19564,7 +19475,7 @@ fn bindgen_test_layout__xmlSchemaAttribute() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaAttribute>())).flags as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaAttribute, flags),
     120usize,
     concat!(
       "Offset of field: ",
@@ -19574,7 +19485,7 @@ fn bindgen_test_layout__xmlSchemaAttribute() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaAttribute>())).refPrefix as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaAttribute, refPrefix),
     128usize,
     concat!(
       "Offset of field: ",
@@ -19584,7 +19495,7 @@ fn bindgen_test_layout__xmlSchemaAttribute() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaAttribute>())).defVal as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaAttribute, defVal),
     136usize,
     concat!(
       "Offset of field: ",
@@ -19594,7 +19505,7 @@ fn bindgen_test_layout__xmlSchemaAttribute() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaAttribute>())).refDecl as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaAttribute, refDecl),
     144usize,
     concat!(
       "Offset of field: ",
@@ -19628,7 +19539,7 @@ fn bindgen_test_layout__xmlSchemaAttributeLink() {
     concat!("Alignment of ", stringify!(_xmlSchemaAttributeLink))
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaAttributeLink>())).next as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaAttributeLink, next),
     0usize,
     concat!(
       "Offset of field: ",
@@ -19638,7 +19549,7 @@ fn bindgen_test_layout__xmlSchemaAttributeLink() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaAttributeLink>())).attr as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaAttributeLink, attr),
     8usize,
     concat!(
       "Offset of field: ",
@@ -19671,7 +19582,7 @@ fn bindgen_test_layout__xmlSchemaWildcardNs() {
     concat!("Alignment of ", stringify!(_xmlSchemaWildcardNs))
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaWildcardNs>())).next as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaWildcardNs, next),
     0usize,
     concat!(
       "Offset of field: ",
@@ -19681,7 +19592,7 @@ fn bindgen_test_layout__xmlSchemaWildcardNs() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaWildcardNs>())).value as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaWildcardNs, value),
     8usize,
     concat!(
       "Offset of field: ",
@@ -19723,7 +19634,7 @@ fn bindgen_test_layout__xmlSchemaWildcard() {
     concat!("Alignment of ", stringify!(_xmlSchemaWildcard))
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaWildcard>())).type_ as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaWildcard, type_),
     0usize,
     concat!(
       "Offset of field: ",
@@ -19733,7 +19644,7 @@ fn bindgen_test_layout__xmlSchemaWildcard() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaWildcard>())).id as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaWildcard, id),
     8usize,
     concat!(
       "Offset of field: ",
@@ -19743,7 +19654,7 @@ fn bindgen_test_layout__xmlSchemaWildcard() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaWildcard>())).annot as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaWildcard, annot),
     16usize,
     concat!(
       "Offset of field: ",
@@ -19753,7 +19664,7 @@ fn bindgen_test_layout__xmlSchemaWildcard() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaWildcard>())).node as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaWildcard, node),
     24usize,
     concat!(
       "Offset of field: ",
@@ -19763,7 +19674,7 @@ fn bindgen_test_layout__xmlSchemaWildcard() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaWildcard>())).minOccurs as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaWildcard, minOccurs),
     32usize,
     concat!(
       "Offset of field: ",
@@ -19773,7 +19684,7 @@ fn bindgen_test_layout__xmlSchemaWildcard() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaWildcard>())).maxOccurs as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaWildcard, maxOccurs),
     36usize,
     concat!(
       "Offset of field: ",
@@ -19783,7 +19694,7 @@ fn bindgen_test_layout__xmlSchemaWildcard() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaWildcard>())).processContents as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaWildcard, processContents),
     40usize,
     concat!(
       "Offset of field: ",
@@ -19793,7 +19704,7 @@ fn bindgen_test_layout__xmlSchemaWildcard() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaWildcard>())).any as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaWildcard, any),
     44usize,
     concat!(
       "Offset of field: ",
@@ -19803,7 +19714,7 @@ fn bindgen_test_layout__xmlSchemaWildcard() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaWildcard>())).nsSet as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaWildcard, nsSet),
     48usize,
     concat!(
       "Offset of field: ",
@@ -19813,7 +19724,7 @@ fn bindgen_test_layout__xmlSchemaWildcard() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaWildcard>())).negNsSet as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaWildcard, negNsSet),
     56usize,
     concat!(
       "Offset of field: ",
@@ -19823,7 +19734,7 @@ fn bindgen_test_layout__xmlSchemaWildcard() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaWildcard>())).flags as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaWildcard, flags),
     64usize,
     concat!(
       "Offset of field: ",
@@ -19871,7 +19782,7 @@ fn bindgen_test_layout__xmlSchemaAttributeGroup() {
     concat!("Alignment of ", stringify!(_xmlSchemaAttributeGroup))
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaAttributeGroup>())).type_ as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaAttributeGroup, type_),
     0usize,
     concat!(
       "Offset of field: ",
@@ -19881,7 +19792,7 @@ fn bindgen_test_layout__xmlSchemaAttributeGroup() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaAttributeGroup>())).next as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaAttributeGroup, next),
     8usize,
     concat!(
       "Offset of field: ",
@@ -19891,7 +19802,7 @@ fn bindgen_test_layout__xmlSchemaAttributeGroup() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaAttributeGroup>())).name as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaAttributeGroup, name),
     16usize,
     concat!(
       "Offset of field: ",
@@ -19901,7 +19812,7 @@ fn bindgen_test_layout__xmlSchemaAttributeGroup() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaAttributeGroup>())).id as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaAttributeGroup, id),
     24usize,
     concat!(
       "Offset of field: ",
@@ -19911,7 +19822,7 @@ fn bindgen_test_layout__xmlSchemaAttributeGroup() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaAttributeGroup>())).ref_ as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaAttributeGroup, ref_),
     32usize,
     concat!(
       "Offset of field: ",
@@ -19921,7 +19832,7 @@ fn bindgen_test_layout__xmlSchemaAttributeGroup() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaAttributeGroup>())).refNs as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaAttributeGroup, refNs),
     40usize,
     concat!(
       "Offset of field: ",
@@ -19931,7 +19842,7 @@ fn bindgen_test_layout__xmlSchemaAttributeGroup() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaAttributeGroup>())).annot as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaAttributeGroup, annot),
     48usize,
     concat!(
       "Offset of field: ",
@@ -19941,7 +19852,7 @@ fn bindgen_test_layout__xmlSchemaAttributeGroup() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaAttributeGroup>())).attributes as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaAttributeGroup, attributes),
     56usize,
     concat!(
       "Offset of field: ",
@@ -19951,7 +19862,7 @@ fn bindgen_test_layout__xmlSchemaAttributeGroup() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaAttributeGroup>())).node as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaAttributeGroup, node),
     64usize,
     concat!(
       "Offset of field: ",
@@ -19961,7 +19872,7 @@ fn bindgen_test_layout__xmlSchemaAttributeGroup() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaAttributeGroup>())).flags as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaAttributeGroup, flags),
     72usize,
     concat!(
       "Offset of field: ",
@@ -19971,9 +19882,7 @@ fn bindgen_test_layout__xmlSchemaAttributeGroup() {
     )
   );
   assert_eq!(
-    unsafe {
-      &(*(::std::ptr::null::<_xmlSchemaAttributeGroup>())).attributeWildcard as *const _ as usize
-    },
+    ::std::mem::offset_of!(_xmlSchemaAttributeGroup, attributeWildcard),
     80usize,
     concat!(
       "Offset of field: ",
@@ -19983,7 +19892,7 @@ fn bindgen_test_layout__xmlSchemaAttributeGroup() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaAttributeGroup>())).refPrefix as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaAttributeGroup, refPrefix),
     88usize,
     concat!(
       "Offset of field: ",
@@ -19993,7 +19902,7 @@ fn bindgen_test_layout__xmlSchemaAttributeGroup() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaAttributeGroup>())).refItem as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaAttributeGroup, refItem),
     96usize,
     concat!(
       "Offset of field: ",
@@ -20003,9 +19912,7 @@ fn bindgen_test_layout__xmlSchemaAttributeGroup() {
     )
   );
   assert_eq!(
-    unsafe {
-      &(*(::std::ptr::null::<_xmlSchemaAttributeGroup>())).targetNamespace as *const _ as usize
-    },
+    ::std::mem::offset_of!(_xmlSchemaAttributeGroup, targetNamespace),
     104usize,
     concat!(
       "Offset of field: ",
@@ -20015,7 +19922,7 @@ fn bindgen_test_layout__xmlSchemaAttributeGroup() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaAttributeGroup>())).attrUses as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaAttributeGroup, attrUses),
     112usize,
     concat!(
       "Offset of field: ",
@@ -20049,7 +19956,7 @@ fn bindgen_test_layout__xmlSchemaTypeLink() {
     concat!("Alignment of ", stringify!(_xmlSchemaTypeLink))
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaTypeLink>())).next as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaTypeLink, next),
     0usize,
     concat!(
       "Offset of field: ",
@@ -20059,7 +19966,7 @@ fn bindgen_test_layout__xmlSchemaTypeLink() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaTypeLink>())).type_ as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaTypeLink, type_),
     8usize,
     concat!(
       "Offset of field: ",
@@ -20092,7 +19999,7 @@ fn bindgen_test_layout__xmlSchemaFacetLink() {
     concat!("Alignment of ", stringify!(_xmlSchemaFacetLink))
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaFacetLink>())).next as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaFacetLink, next),
     0usize,
     concat!(
       "Offset of field: ",
@@ -20102,7 +20009,7 @@ fn bindgen_test_layout__xmlSchemaFacetLink() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaFacetLink>())).facet as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaFacetLink, facet),
     8usize,
     concat!(
       "Offset of field: ",
@@ -20162,7 +20069,7 @@ fn bindgen_test_layout__xmlSchemaType() {
     concat!("Alignment of ", stringify!(_xmlSchemaType))
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaType>())).type_ as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaType, type_),
     0usize,
     concat!(
       "Offset of field: ",
@@ -20172,7 +20079,7 @@ fn bindgen_test_layout__xmlSchemaType() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaType>())).next as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaType, next),
     8usize,
     concat!(
       "Offset of field: ",
@@ -20182,7 +20089,7 @@ fn bindgen_test_layout__xmlSchemaType() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaType>())).name as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaType, name),
     16usize,
     concat!(
       "Offset of field: ",
@@ -20192,7 +20099,7 @@ fn bindgen_test_layout__xmlSchemaType() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaType>())).id as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaType, id),
     24usize,
     concat!(
       "Offset of field: ",
@@ -20202,7 +20109,7 @@ fn bindgen_test_layout__xmlSchemaType() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaType>())).ref_ as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaType, ref_),
     32usize,
     concat!(
       "Offset of field: ",
@@ -20212,7 +20119,7 @@ fn bindgen_test_layout__xmlSchemaType() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaType>())).refNs as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaType, refNs),
     40usize,
     concat!(
       "Offset of field: ",
@@ -20222,7 +20129,7 @@ fn bindgen_test_layout__xmlSchemaType() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaType>())).annot as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaType, annot),
     48usize,
     concat!(
       "Offset of field: ",
@@ -20232,7 +20139,7 @@ fn bindgen_test_layout__xmlSchemaType() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaType>())).subtypes as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaType, subtypes),
     56usize,
     concat!(
       "Offset of field: ",
@@ -20242,7 +20149,7 @@ fn bindgen_test_layout__xmlSchemaType() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaType>())).attributes as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaType, attributes),
     64usize,
     concat!(
       "Offset of field: ",
@@ -20252,7 +20159,7 @@ fn bindgen_test_layout__xmlSchemaType() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaType>())).node as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaType, node),
     72usize,
     concat!(
       "Offset of field: ",
@@ -20262,7 +20169,7 @@ fn bindgen_test_layout__xmlSchemaType() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaType>())).minOccurs as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaType, minOccurs),
     80usize,
     concat!(
       "Offset of field: ",
@@ -20272,7 +20179,7 @@ fn bindgen_test_layout__xmlSchemaType() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaType>())).maxOccurs as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaType, maxOccurs),
     84usize,
     concat!(
       "Offset of field: ",
@@ -20282,7 +20189,7 @@ fn bindgen_test_layout__xmlSchemaType() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaType>())).flags as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaType, flags),
     88usize,
     concat!(
       "Offset of field: ",
@@ -20292,7 +20199,7 @@ fn bindgen_test_layout__xmlSchemaType() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaType>())).contentType as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaType, contentType),
     92usize,
     concat!(
       "Offset of field: ",
@@ -20302,7 +20209,7 @@ fn bindgen_test_layout__xmlSchemaType() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaType>())).base as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaType, base),
     96usize,
     concat!(
       "Offset of field: ",
@@ -20312,7 +20219,7 @@ fn bindgen_test_layout__xmlSchemaType() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaType>())).baseNs as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaType, baseNs),
     104usize,
     concat!(
       "Offset of field: ",
@@ -20322,7 +20229,7 @@ fn bindgen_test_layout__xmlSchemaType() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaType>())).baseType as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaType, baseType),
     112usize,
     concat!(
       "Offset of field: ",
@@ -20332,7 +20239,7 @@ fn bindgen_test_layout__xmlSchemaType() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaType>())).facets as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaType, facets),
     120usize,
     concat!(
       "Offset of field: ",
@@ -20342,7 +20249,7 @@ fn bindgen_test_layout__xmlSchemaType() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaType>())).redef as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaType, redef),
     128usize,
     concat!(
       "Offset of field: ",
@@ -20352,7 +20259,7 @@ fn bindgen_test_layout__xmlSchemaType() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaType>())).recurse as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaType, recurse),
     136usize,
     concat!(
       "Offset of field: ",
@@ -20362,7 +20269,7 @@ fn bindgen_test_layout__xmlSchemaType() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaType>())).attributeUses as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaType, attributeUses),
     144usize,
     concat!(
       "Offset of field: ",
@@ -20372,7 +20279,7 @@ fn bindgen_test_layout__xmlSchemaType() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaType>())).attributeWildcard as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaType, attributeWildcard),
     152usize,
     concat!(
       "Offset of field: ",
@@ -20382,7 +20289,7 @@ fn bindgen_test_layout__xmlSchemaType() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaType>())).builtInType as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaType, builtInType),
     160usize,
     concat!(
       "Offset of field: ",
@@ -20392,7 +20299,7 @@ fn bindgen_test_layout__xmlSchemaType() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaType>())).memberTypes as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaType, memberTypes),
     168usize,
     concat!(
       "Offset of field: ",
@@ -20402,7 +20309,7 @@ fn bindgen_test_layout__xmlSchemaType() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaType>())).facetSet as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaType, facetSet),
     176usize,
     concat!(
       "Offset of field: ",
@@ -20412,7 +20319,7 @@ fn bindgen_test_layout__xmlSchemaType() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaType>())).refPrefix as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaType, refPrefix),
     184usize,
     concat!(
       "Offset of field: ",
@@ -20422,7 +20329,7 @@ fn bindgen_test_layout__xmlSchemaType() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaType>())).contentTypeDef as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaType, contentTypeDef),
     192usize,
     concat!(
       "Offset of field: ",
@@ -20432,7 +20339,7 @@ fn bindgen_test_layout__xmlSchemaType() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaType>())).contModel as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaType, contModel),
     200usize,
     concat!(
       "Offset of field: ",
@@ -20442,7 +20349,7 @@ fn bindgen_test_layout__xmlSchemaType() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaType>())).targetNamespace as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaType, targetNamespace),
     208usize,
     concat!(
       "Offset of field: ",
@@ -20452,7 +20359,7 @@ fn bindgen_test_layout__xmlSchemaType() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaType>())).attrUses as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaType, attrUses),
     216usize,
     concat!(
       "Offset of field: ",
@@ -20507,7 +20414,7 @@ fn bindgen_test_layout__xmlSchemaElement() {
     concat!("Alignment of ", stringify!(_xmlSchemaElement))
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaElement>())).type_ as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaElement, type_),
     0usize,
     concat!(
       "Offset of field: ",
@@ -20517,7 +20424,7 @@ fn bindgen_test_layout__xmlSchemaElement() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaElement>())).next as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaElement, next),
     8usize,
     concat!(
       "Offset of field: ",
@@ -20527,7 +20434,7 @@ fn bindgen_test_layout__xmlSchemaElement() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaElement>())).name as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaElement, name),
     16usize,
     concat!(
       "Offset of field: ",
@@ -20537,7 +20444,7 @@ fn bindgen_test_layout__xmlSchemaElement() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaElement>())).id as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaElement, id),
     24usize,
     concat!(
       "Offset of field: ",
@@ -20547,7 +20454,7 @@ fn bindgen_test_layout__xmlSchemaElement() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaElement>())).ref_ as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaElement, ref_),
     32usize,
     concat!(
       "Offset of field: ",
@@ -20557,7 +20464,7 @@ fn bindgen_test_layout__xmlSchemaElement() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaElement>())).refNs as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaElement, refNs),
     40usize,
     concat!(
       "Offset of field: ",
@@ -20567,7 +20474,7 @@ fn bindgen_test_layout__xmlSchemaElement() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaElement>())).annot as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaElement, annot),
     48usize,
     concat!(
       "Offset of field: ",
@@ -20577,7 +20484,7 @@ fn bindgen_test_layout__xmlSchemaElement() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaElement>())).subtypes as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaElement, subtypes),
     56usize,
     concat!(
       "Offset of field: ",
@@ -20587,7 +20494,7 @@ fn bindgen_test_layout__xmlSchemaElement() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaElement>())).attributes as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaElement, attributes),
     64usize,
     concat!(
       "Offset of field: ",
@@ -20597,7 +20504,7 @@ fn bindgen_test_layout__xmlSchemaElement() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaElement>())).node as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaElement, node),
     72usize,
     concat!(
       "Offset of field: ",
@@ -20607,7 +20514,7 @@ fn bindgen_test_layout__xmlSchemaElement() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaElement>())).minOccurs as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaElement, minOccurs),
     80usize,
     concat!(
       "Offset of field: ",
@@ -20617,7 +20524,7 @@ fn bindgen_test_layout__xmlSchemaElement() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaElement>())).maxOccurs as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaElement, maxOccurs),
     84usize,
     concat!(
       "Offset of field: ",
@@ -20627,7 +20534,7 @@ fn bindgen_test_layout__xmlSchemaElement() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaElement>())).flags as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaElement, flags),
     88usize,
     concat!(
       "Offset of field: ",
@@ -20637,7 +20544,7 @@ fn bindgen_test_layout__xmlSchemaElement() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaElement>())).targetNamespace as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaElement, targetNamespace),
     96usize,
     concat!(
       "Offset of field: ",
@@ -20647,7 +20554,7 @@ fn bindgen_test_layout__xmlSchemaElement() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaElement>())).namedType as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaElement, namedType),
     104usize,
     concat!(
       "Offset of field: ",
@@ -20657,7 +20564,7 @@ fn bindgen_test_layout__xmlSchemaElement() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaElement>())).namedTypeNs as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaElement, namedTypeNs),
     112usize,
     concat!(
       "Offset of field: ",
@@ -20667,7 +20574,7 @@ fn bindgen_test_layout__xmlSchemaElement() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaElement>())).substGroup as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaElement, substGroup),
     120usize,
     concat!(
       "Offset of field: ",
@@ -20677,7 +20584,7 @@ fn bindgen_test_layout__xmlSchemaElement() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaElement>())).substGroupNs as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaElement, substGroupNs),
     128usize,
     concat!(
       "Offset of field: ",
@@ -20687,7 +20594,7 @@ fn bindgen_test_layout__xmlSchemaElement() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaElement>())).scope as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaElement, scope),
     136usize,
     concat!(
       "Offset of field: ",
@@ -20697,7 +20604,7 @@ fn bindgen_test_layout__xmlSchemaElement() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaElement>())).value as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaElement, value),
     144usize,
     concat!(
       "Offset of field: ",
@@ -20707,7 +20614,7 @@ fn bindgen_test_layout__xmlSchemaElement() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaElement>())).refDecl as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaElement, refDecl),
     152usize,
     concat!(
       "Offset of field: ",
@@ -20717,7 +20624,7 @@ fn bindgen_test_layout__xmlSchemaElement() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaElement>())).contModel as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaElement, contModel),
     160usize,
     concat!(
       "Offset of field: ",
@@ -20727,7 +20634,7 @@ fn bindgen_test_layout__xmlSchemaElement() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaElement>())).contentType as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaElement, contentType),
     168usize,
     concat!(
       "Offset of field: ",
@@ -20737,7 +20644,7 @@ fn bindgen_test_layout__xmlSchemaElement() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaElement>())).refPrefix as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaElement, refPrefix),
     176usize,
     concat!(
       "Offset of field: ",
@@ -20747,7 +20654,7 @@ fn bindgen_test_layout__xmlSchemaElement() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaElement>())).defVal as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaElement, defVal),
     184usize,
     concat!(
       "Offset of field: ",
@@ -20757,7 +20664,7 @@ fn bindgen_test_layout__xmlSchemaElement() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaElement>())).idcs as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaElement, idcs),
     192usize,
     concat!(
       "Offset of field: ",
@@ -20795,7 +20702,7 @@ fn bindgen_test_layout__xmlSchemaFacet() {
     concat!("Alignment of ", stringify!(_xmlSchemaFacet))
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaFacet>())).type_ as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaFacet, type_),
     0usize,
     concat!(
       "Offset of field: ",
@@ -20805,7 +20712,7 @@ fn bindgen_test_layout__xmlSchemaFacet() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaFacet>())).next as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaFacet, next),
     8usize,
     concat!(
       "Offset of field: ",
@@ -20815,7 +20722,7 @@ fn bindgen_test_layout__xmlSchemaFacet() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaFacet>())).value as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaFacet, value),
     16usize,
     concat!(
       "Offset of field: ",
@@ -20825,7 +20732,7 @@ fn bindgen_test_layout__xmlSchemaFacet() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaFacet>())).id as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaFacet, id),
     24usize,
     concat!(
       "Offset of field: ",
@@ -20835,7 +20742,7 @@ fn bindgen_test_layout__xmlSchemaFacet() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaFacet>())).annot as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaFacet, annot),
     32usize,
     concat!(
       "Offset of field: ",
@@ -20845,7 +20752,7 @@ fn bindgen_test_layout__xmlSchemaFacet() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaFacet>())).node as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaFacet, node),
     40usize,
     concat!(
       "Offset of field: ",
@@ -20855,7 +20762,7 @@ fn bindgen_test_layout__xmlSchemaFacet() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaFacet>())).fixed as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaFacet, fixed),
     48usize,
     concat!(
       "Offset of field: ",
@@ -20865,7 +20772,7 @@ fn bindgen_test_layout__xmlSchemaFacet() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaFacet>())).whitespace as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaFacet, whitespace),
     52usize,
     concat!(
       "Offset of field: ",
@@ -20875,7 +20782,7 @@ fn bindgen_test_layout__xmlSchemaFacet() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaFacet>())).val as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaFacet, val),
     56usize,
     concat!(
       "Offset of field: ",
@@ -20885,7 +20792,7 @@ fn bindgen_test_layout__xmlSchemaFacet() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaFacet>())).regexp as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaFacet, regexp),
     64usize,
     concat!(
       "Offset of field: ",
@@ -20920,7 +20827,7 @@ fn bindgen_test_layout__xmlSchemaNotation() {
     concat!("Alignment of ", stringify!(_xmlSchemaNotation))
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaNotation>())).type_ as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaNotation, type_),
     0usize,
     concat!(
       "Offset of field: ",
@@ -20930,7 +20837,7 @@ fn bindgen_test_layout__xmlSchemaNotation() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaNotation>())).name as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaNotation, name),
     8usize,
     concat!(
       "Offset of field: ",
@@ -20940,7 +20847,7 @@ fn bindgen_test_layout__xmlSchemaNotation() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaNotation>())).annot as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaNotation, annot),
     16usize,
     concat!(
       "Offset of field: ",
@@ -20950,7 +20857,7 @@ fn bindgen_test_layout__xmlSchemaNotation() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaNotation>())).identifier as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaNotation, identifier),
     24usize,
     concat!(
       "Offset of field: ",
@@ -20960,7 +20867,7 @@ fn bindgen_test_layout__xmlSchemaNotation() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchemaNotation>())).targetNamespace as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchemaNotation, targetNamespace),
     32usize,
     concat!(
       "Offset of field: ",
@@ -21011,7 +20918,7 @@ fn bindgen_test_layout__xmlSchema() {
     concat!("Alignment of ", stringify!(_xmlSchema))
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchema>())).name as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchema, name),
     0usize,
     concat!(
       "Offset of field: ",
@@ -21021,7 +20928,7 @@ fn bindgen_test_layout__xmlSchema() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchema>())).targetNamespace as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchema, targetNamespace),
     8usize,
     concat!(
       "Offset of field: ",
@@ -21031,7 +20938,7 @@ fn bindgen_test_layout__xmlSchema() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchema>())).version as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchema, version),
     16usize,
     concat!(
       "Offset of field: ",
@@ -21041,7 +20948,7 @@ fn bindgen_test_layout__xmlSchema() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchema>())).id as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchema, id),
     24usize,
     concat!(
       "Offset of field: ",
@@ -21051,7 +20958,7 @@ fn bindgen_test_layout__xmlSchema() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchema>())).doc as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchema, doc),
     32usize,
     concat!(
       "Offset of field: ",
@@ -21061,7 +20968,7 @@ fn bindgen_test_layout__xmlSchema() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchema>())).annot as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchema, annot),
     40usize,
     concat!(
       "Offset of field: ",
@@ -21071,7 +20978,7 @@ fn bindgen_test_layout__xmlSchema() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchema>())).flags as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchema, flags),
     48usize,
     concat!(
       "Offset of field: ",
@@ -21081,7 +20988,7 @@ fn bindgen_test_layout__xmlSchema() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchema>())).typeDecl as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchema, typeDecl),
     56usize,
     concat!(
       "Offset of field: ",
@@ -21091,7 +20998,7 @@ fn bindgen_test_layout__xmlSchema() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchema>())).attrDecl as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchema, attrDecl),
     64usize,
     concat!(
       "Offset of field: ",
@@ -21101,7 +21008,7 @@ fn bindgen_test_layout__xmlSchema() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchema>())).attrgrpDecl as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchema, attrgrpDecl),
     72usize,
     concat!(
       "Offset of field: ",
@@ -21111,7 +21018,7 @@ fn bindgen_test_layout__xmlSchema() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchema>())).elemDecl as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchema, elemDecl),
     80usize,
     concat!(
       "Offset of field: ",
@@ -21121,7 +21028,7 @@ fn bindgen_test_layout__xmlSchema() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchema>())).notaDecl as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchema, notaDecl),
     88usize,
     concat!(
       "Offset of field: ",
@@ -21131,7 +21038,7 @@ fn bindgen_test_layout__xmlSchema() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchema>())).schemasImports as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchema, schemasImports),
     96usize,
     concat!(
       "Offset of field: ",
@@ -21141,7 +21048,7 @@ fn bindgen_test_layout__xmlSchema() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchema>()))._private as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchema, _private),
     104usize,
     concat!(
       "Offset of field: ",
@@ -21151,7 +21058,7 @@ fn bindgen_test_layout__xmlSchema() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchema>())).groupDecl as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchema, groupDecl),
     112usize,
     concat!(
       "Offset of field: ",
@@ -21161,7 +21068,7 @@ fn bindgen_test_layout__xmlSchema() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchema>())).dict as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchema, dict),
     120usize,
     concat!(
       "Offset of field: ",
@@ -21171,7 +21078,7 @@ fn bindgen_test_layout__xmlSchema() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchema>())).includes as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchema, includes),
     128usize,
     concat!(
       "Offset of field: ",
@@ -21181,7 +21088,7 @@ fn bindgen_test_layout__xmlSchema() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchema>())).preserve as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchema, preserve),
     136usize,
     concat!(
       "Offset of field: ",
@@ -21191,7 +21098,7 @@ fn bindgen_test_layout__xmlSchema() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchema>())).counter as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchema, counter),
     140usize,
     concat!(
       "Offset of field: ",
@@ -21201,7 +21108,7 @@ fn bindgen_test_layout__xmlSchema() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchema>())).idcDef as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchema, idcDef),
     144usize,
     concat!(
       "Offset of field: ",
@@ -21211,7 +21118,7 @@ fn bindgen_test_layout__xmlSchema() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlSchema>())).volatiles as *const _ as usize },
+    ::std::mem::offset_of!(_xmlSchema, volatiles),
     152usize,
     concat!(
       "Offset of field: ",
@@ -21389,7 +21296,7 @@ fn bindgen_test_layout__xmlURI() {
     concat!("Alignment of ", stringify!(_xmlURI))
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlURI>())).scheme as *const _ as usize },
+    ::std::mem::offset_of!(_xmlURI, scheme),
     0usize,
     concat!(
       "Offset of field: ",
@@ -21399,7 +21306,7 @@ fn bindgen_test_layout__xmlURI() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlURI>())).opaque as *const _ as usize },
+    ::std::mem::offset_of!(_xmlURI, opaque),
     8usize,
     concat!(
       "Offset of field: ",
@@ -21409,7 +21316,7 @@ fn bindgen_test_layout__xmlURI() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlURI>())).authority as *const _ as usize },
+    ::std::mem::offset_of!(_xmlURI, authority),
     16usize,
     concat!(
       "Offset of field: ",
@@ -21419,7 +21326,7 @@ fn bindgen_test_layout__xmlURI() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlURI>())).server as *const _ as usize },
+    ::std::mem::offset_of!(_xmlURI, server),
     24usize,
     concat!(
       "Offset of field: ",
@@ -21429,7 +21336,7 @@ fn bindgen_test_layout__xmlURI() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlURI>())).user as *const _ as usize },
+    ::std::mem::offset_of!(_xmlURI, user),
     32usize,
     concat!(
       "Offset of field: ",
@@ -21439,7 +21346,7 @@ fn bindgen_test_layout__xmlURI() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlURI>())).port as *const _ as usize },
+    ::std::mem::offset_of!(_xmlURI, port),
     40usize,
     concat!(
       "Offset of field: ",
@@ -21449,7 +21356,7 @@ fn bindgen_test_layout__xmlURI() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlURI>())).path as *const _ as usize },
+    ::std::mem::offset_of!(_xmlURI, path),
     48usize,
     concat!(
       "Offset of field: ",
@@ -21459,7 +21366,7 @@ fn bindgen_test_layout__xmlURI() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlURI>())).query as *const _ as usize },
+    ::std::mem::offset_of!(_xmlURI, query),
     56usize,
     concat!(
       "Offset of field: ",
@@ -21469,7 +21376,7 @@ fn bindgen_test_layout__xmlURI() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlURI>())).fragment as *const _ as usize },
+    ::std::mem::offset_of!(_xmlURI, fragment),
     64usize,
     concat!(
       "Offset of field: ",
@@ -21479,7 +21386,7 @@ fn bindgen_test_layout__xmlURI() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlURI>())).cleanup as *const _ as usize },
+    ::std::mem::offset_of!(_xmlURI, cleanup),
     72usize,
     concat!(
       "Offset of field: ",
@@ -21489,7 +21396,7 @@ fn bindgen_test_layout__xmlURI() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlURI>())).query_raw as *const _ as usize },
+    ::std::mem::offset_of!(_xmlURI, query_raw),
     80usize,
     concat!(
       "Offset of field: ",
@@ -24141,7 +24048,7 @@ fn bindgen_test_layout__xmlLocationSet() {
     concat!("Alignment of ", stringify!(_xmlLocationSet))
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlLocationSet>())).locNr as *const _ as usize },
+    ::std::mem::offset_of!(_xmlLocationSet, locNr),
     0usize,
     concat!(
       "Offset of field: ",
@@ -24151,7 +24058,7 @@ fn bindgen_test_layout__xmlLocationSet() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlLocationSet>())).locMax as *const _ as usize },
+    ::std::mem::offset_of!(_xmlLocationSet, locMax),
     4usize,
     concat!(
       "Offset of field: ",
@@ -24161,7 +24068,7 @@ fn bindgen_test_layout__xmlLocationSet() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<_xmlLocationSet>())).locTab as *const _ as usize },
+    ::std::mem::offset_of!(_xmlLocationSet, locTab),
     8usize,
     concat!(
       "Offset of field: ",
@@ -24271,7 +24178,7 @@ fn bindgen_test_layout___va_list_tag() {
     concat!("Alignment of ", stringify!(__va_list_tag))
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<__va_list_tag>())).gp_offset as *const _ as usize },
+    ::std::mem::offset_of!(__va_list_tag, gp_offset),
     0usize,
     concat!(
       "Offset of field: ",
@@ -24281,7 +24188,7 @@ fn bindgen_test_layout___va_list_tag() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<__va_list_tag>())).fp_offset as *const _ as usize },
+    ::std::mem::offset_of!(__va_list_tag, fp_offset),
     4usize,
     concat!(
       "Offset of field: ",
@@ -24291,7 +24198,7 @@ fn bindgen_test_layout___va_list_tag() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<__va_list_tag>())).overflow_arg_area as *const _ as usize },
+    ::std::mem::offset_of!(__va_list_tag, overflow_arg_area),
     8usize,
     concat!(
       "Offset of field: ",
@@ -24301,7 +24208,7 @@ fn bindgen_test_layout___va_list_tag() {
     )
   );
   assert_eq!(
-    unsafe { &(*(::std::ptr::null::<__va_list_tag>())).reg_save_area as *const _ as usize },
+    ::std::mem::offset_of!(__va_list_tag, reg_save_area),
     16usize,
     concat!(
       "Offset of field: ",
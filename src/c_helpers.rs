@@ -3,6 +3,7 @@
 
 use crate::bindings::*;
 use libc::{c_char, c_int, size_t};
+use std::collections::HashSet;
 use std::os::raw::c_void;
 use std::ptr;
 use std::slice;
@@ -11,14 +12,15 @@ use std::slice;
 // pub fn xmlThrDefSetGenericErrorFunc(ctx: *mut c_void, handler: *mut c_void);
 
 // Taken from Nokogiri (https://github.com/sparklemotion/nokogiri/blob/24bb843327306d2d71e4b2dc337c1e327cbf4516/ext/nokogiri/xml_document.c#L64)
-pub fn xmlNodeRecursivelyRemoveNs(node: xmlNodePtr) {
+// The removed declaration lists are handed over in `removed`, rather than freed
+pub fn xmlNodeRecursivelyRemoveNs(node: xmlNodePtr, removed: &mut Vec<xmlNsPtr>) {
   unsafe {
     let mut property: xmlAttrPtr;
 
     xmlSetNs(node, ptr::null_mut());
     let mut child: xmlNodePtr = (*node).children;
     while !child.is_null() {
-      xmlNodeRecursivelyRemoveNs(child);
+      xmlNodeRecursivelyRemoveNs(child, removed);
       child = (*child).next;
     }
 
@@ -27,7 +29,7 @@ pub fn xmlNodeRecursivelyRemoveNs(node: xmlNodePtr) {
       || ((*node).type_ == xmlElementType_XML_XINCLUDE_END))
       && !(*node).nsDef.is_null()
     {
-      xmlFreeNsList((*node).nsDef);
+      removed.push((*node).nsDef);
       (*node).nsDef = ptr::null_mut();
     }
 
@@ -43,6 +45,9 @@ pub fn xmlNodeRecursivelyRemoveNs(node: xmlNodePtr) {
   }
 }
 pub fn xmlGetDoc(cur: xmlNodePtr) -> xmlDocPtr {
+  if cur.is_null() {
+    return ptr::null_mut();
+  }
   unsafe { (*cur).doc }
 }
 pub fn xmlNextNsSibling(ns: xmlNsPtr) -> xmlNsPtr {
@@ -55,9 +60,15 @@ pub fn xmlNsHref(ns: xmlNsPtr) -> *const c_char {
   unsafe { (*ns).href as *const c_char }
 }
 pub fn xmlNodeNsDeclarations(cur: xmlNodePtr) -> xmlNsPtr {
+  if cur.is_null() {
+    return ptr::null_mut();
+  }
   unsafe { (*cur).nsDef }
 }
 pub fn xmlNodeNs(cur: xmlNodePtr) -> xmlNsPtr {
+  if cur.is_null() {
+    return ptr::null_mut();
+  }
   unsafe { (*cur).ns }
 }
 
@@ -68,31 +79,72 @@ pub fn xmlAttrName(attr: xmlAttrPtr) -> *const c_char {
   unsafe { (*attr).name as *const c_char }
 }
 pub fn xmlGetFirstProperty(node: xmlNodePtr) -> xmlAttrPtr {
+  if node.is_null() {
+    return ptr::null_mut();
+  }
   unsafe { (*node).properties }
 }
 pub fn xmlGetNodeType(cur: xmlNodePtr) -> u32 {
+  if cur.is_null() {
+    return 0;
+  }
   unsafe { (*cur).type_ }
 }
 
 pub fn xmlGetParent(cur: xmlNodePtr) -> xmlNodePtr {
+  if cur.is_null() {
+    return ptr::null_mut();
+  }
   unsafe { (*cur).parent }
 }
 pub fn xmlGetFirstChild(cur: xmlNodePtr) -> xmlNodePtr {
+  if cur.is_null() {
+    return ptr::null_mut();
+  }
   unsafe { (*cur).children }
 }
 pub fn xmlPrevSibling(cur: xmlNodePtr) -> xmlNodePtr {
+  if cur.is_null() {
+    return ptr::null_mut();
+  }
   unsafe { (*cur).prev }
 }
 
 // helper for tree
 pub fn xmlNextSibling(cur: xmlNodePtr) -> xmlNodePtr {
+  if cur.is_null() {
+    return ptr::null_mut();
+  }
   unsafe { (*cur).next }
 }
 
 pub fn xmlNodeGetName(cur: xmlNodePtr) -> *const c_char {
+  if cur.is_null() {
+    return ptr::null();
+  }
   unsafe { (*cur).name as *const c_char }
 }
 
+pub fn xmlEntityKind(ent: xmlEntityPtr) -> u32 {
+  unsafe { (*ent).etype }
+}
+pub fn xmlEntityContent(ent: xmlEntityPtr) -> *const c_char {
+  unsafe { (*ent).content as *const c_char }
+}
+pub fn xmlEntityExternalID(ent: xmlEntityPtr) -> *const c_char {
+  unsafe { (*ent).ExternalID as *const c_char }
+}
+pub fn xmlEntitySystemID(ent: xmlEntityPtr) -> *const c_char {
+  unsafe { (*ent).SystemID as *const c_char }
+}
+
+pub fn xmlDtdExternalID(dtd: xmlDtdPtr) -> *const c_char {
+  unsafe { (*dtd).ExternalID as *const c_char }
+}
+pub fn xmlDtdSystemID(dtd: xmlDtdPtr) -> *const c_char {
+  unsafe { (*dtd).SystemID as *const c_char }
+}
+
 pub fn setIndentTreeOutput(indent: c_int) {
   unsafe {
     let xml_indent_tree_output_ptr = __xmlIndentTreeOutput();
@@ -109,7 +161,6 @@ fn _ignoreInvalidTagsErrorFunc(_user_data: *mut c_void, error: xmlErrorPtr) {
       HACKY_WELL_FORMED = true;
     }
   }
-  return;
 }
 
 pub fn setWellFormednessHandler(ctxt: *mut xmlParserCtxt) {
@@ -136,6 +187,67 @@ pub fn xmlXPathObjectNumberOfNodes(val: xmlXPathObjectPtr) -> c_int {
   }
 }
 
+// Looks up a variable registered on the context, bypassing any registered lookup callback
+pub fn xmlXPathRegisteredVariableLookup(
+  ctxt: xmlXPathContextPtr,
+  name: *const xmlChar,
+  ns_uri: *const xmlChar,
+) -> xmlXPathObjectPtr {
+  unsafe {
+    if (*ctxt).varHash.is_null() {
+      ptr::null_mut()
+    } else {
+      // xmlXPathObjectCopy is NULL-safe
+      xmlXPathObjectCopy(xmlHashLookup2((*ctxt).varHash, name, ns_uri) as xmlXPathObjectPtr)
+    }
+  }
+}
+
 pub fn xmlXPathObjectGetNodes(val: xmlXPathObjectPtr, size: size_t) -> Vec<xmlNodePtr> {
   unsafe { slice::from_raw_parts((*(*val).nodesetval).nodeTab, size).to_vec() }
 }
+
+// Collects the pointers of a node, its attributes and all of its descendants
+pub fn xmlCollectSubtree(root: xmlNodePtr, nodes: &mut HashSet<xmlNodePtr>) {
+  let mut pending = vec![root];
+  while let Some(node) = pending.pop() {
+    if node.is_null() || !nodes.insert(node) {
+      continue;
+    }
+    let node_type = xmlGetNodeType(node);
+    if node_type == xmlElementType_XML_ELEMENT_NODE {
+      let mut attr = xmlGetFirstProperty(node);
+      while !attr.is_null() {
+        pending.push(attr as xmlNodePtr);
+        attr = xmlNextPropertySibling(attr);
+      }
+    }
+    // entity references share their children with the entity declaration
+    if node_type != xmlElementType_XML_ENTITY_REF_NODE {
+      let mut child = xmlGetFirstChild(node);
+      while !child.is_null() {
+        pending.push(child);
+        child = xmlNextSibling(child);
+      }
+    }
+  }
+}
+
+// Links the unlinked `elem` behind `cur`, without merging adjacent text nodes as xmlAddNextSibling does
+pub fn xmlLinkNextSibling(cur: xmlNodePtr, elem: xmlNodePtr) {
+  unsafe {
+    let parent = (*cur).parent;
+    let next = (*cur).next;
+    (*elem).parent = parent;
+    (*elem).prev = cur;
+    (*elem).next = next;
+    if next.is_null() {
+      if !parent.is_null() {
+        (*parent).last = elem;
+      }
+    } else {
+      (*next).prev = elem;
+    }
+    (*cur).next = elem;
+  }
+}
@@ -0,0 +1,491 @@
+//! CSS selector support, by translation to `XPath`
+//!
+//! Covers the CSS Selectors Level 3 features commonly used for scraping: type, universal,
+//! class, id and attribute selectors, the descendant, child and sibling combinators,
+//! and the structural pseudo-classes, as well as `:not`.
+//!
+//! The `*-of-type` pseudo-classes require a type selector in their compound, e.g.
+//! `li.a:first-of-type` rather than `.a:first-of-type`, since `XPath` 1.0 can not compare
+//! an element's siblings against its own name.
+
+use std::error::Error;
+use std::fmt;
+
+use crate::xpath::XPathError;
+
+/// Errors of CSS selector queries
+#[derive(Clone, Debug, PartialEq)]
+pub enum SelectorError {
+  /// The selector is not valid, or uses an unsupported feature
+  Syntax {
+    /// A human-readable description of the problem
+    message: String,
+    /// The character offset into the selector at which the problem was detected
+    offset: usize,
+  },
+  /// The translated `XPath` failed to evaluate
+  XPath(XPathError),
+}
+
+impl fmt::Display for SelectorError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match *self {
+      SelectorError::Syntax {
+        ref message,
+        offset,
+      } => write!(f, "CSS selector error: {} at offset {}", message, offset),
+      SelectorError::XPath(ref error) => error.fmt(f),
+    }
+  }
+}
+
+impl Error for SelectorError {}
+
+impl From<XPathError> for SelectorError {
+  fn from(error: XPathError) -> Self {
+    SelectorError::XPath(error)
+  }
+}
+
+/// Translates a CSS selector (group) into an `XPath` expression selecting the matching
+/// descendants of the context node.
+/// Names match case-sensitively, as in XML documents.
+pub fn css_to_xpath(selector: &str) -> Result<String, SelectorError> {
+  selector_to_xpath(selector, false)
+}
+
+/// Translates a CSS selector, lowercasing type and attribute names for HTML documents,
+/// where libxml2's parser lowercases them
+pub(crate) fn selector_to_xpath(selector: &str, html: bool) -> Result<String, SelectorError> {
+  SelectorParser::new(selector, html).parse_group()
+}
+
+/// Escapes a string as an `XPath` 1.0 literal
+fn xpath_literal(value: &str) -> String {
+  if !value.contains('\'') {
+    format!("'{}'", value)
+  } else if !value.contains('"') {
+    format!("\"{}\"", value)
+  } else {
+    // XPath 1.0 literals have no escapes, so mixed quotes need to be concatenated
+    let parts: Vec<String> = value
+      .split('\'')
+      .map(|part| format!("'{}'", part))
+      .collect();
+    format!("concat({})", parts.join(", \"'\", "))
+  }
+}
+
+/// Builds the condition matching an element's 1-based `position` against `an+b`
+fn nth_condition(position: &str, (a, b): (i64, i64)) -> String {
+  if a == 0 {
+    format!("{} = {}", position, b)
+  } else if a > 0 {
+    format!(
+      "{p} >= {b} and ({p} - {b}) mod {a} = 0",
+      p = position,
+      a = a,
+      b = b
+    )
+  } else {
+    format!(
+      "{p} <= {b} and ({b} - {p}) mod {a} = 0",
+      p = position,
+      a = -a,
+      b = b
+    )
+  }
+}
+
+/// A compound selector, as an element test and its conditions
+struct Compound {
+  element: String,
+  conditions: Vec<String>,
+}
+
+impl Compound {
+  fn to_step(&self, axis: &str) -> String {
+    let mut step = format!("{}::{}", axis, self.element);
+    for condition in &self.conditions {
+      step.push('[');
+      step.push_str(condition);
+      step.push(']');
+    }
+    step
+  }
+}
+
+/// A recursive descent parser for CSS selectors, emitting `XPath` as it goes
+struct SelectorParser {
+  chars: Vec<char>,
+  pos: usize,
+  /// whether names are matched case-insensitively, as lowercase
+  html: bool,
+}
+
+impl SelectorParser {
+  fn new(selector: &str, html: bool) -> Self {
+    SelectorParser {
+      chars: selector.chars().collect(),
+      pos: 0,
+      html,
+    }
+  }
+
+  fn error<T>(&self, message: &str) -> Result<T, SelectorError> {
+    Err(SelectorError::Syntax {
+      message: message.to_owned(),
+      offset: self.pos,
+    })
+  }
+
+  fn peek(&self) -> Option<char> {
+    self.chars.get(self.pos).cloned()
+  }
+
+  fn eat(&mut self, c: char) -> bool {
+    if self.peek() == Some(c) {
+      self.pos += 1;
+      true
+    } else {
+      false
+    }
+  }
+
+  fn expect(&mut self, c: char) -> Result<(), SelectorError> {
+    if self.eat(c) {
+      Ok(())
+    } else {
+      self.error(&format!("expected {:?}", c))
+    }
+  }
+
+  /// skips whitespace, returning whether there was any
+  fn skip_whitespace(&mut self) -> bool {
+    let start = self.pos;
+    while self.peek().is_some_and(char::is_whitespace) {
+      self.pos += 1;
+    }
+    self.pos > start
+  }
+
+  fn parse_group(&mut self) -> Result<String, SelectorError> {
+    let mut paths = Vec::new();
+    loop {
+      self.skip_whitespace();
+      paths.push(self.parse_selector()?);
+      if !self.eat(',') {
+        break;
+      }
+    }
+    if self.peek().is_some() {
+      return self.error("unexpected character");
+    }
+    Ok(paths.join(" | "))
+  }
+
+  fn parse_selector(&mut self) -> Result<String, SelectorError> {
+    let mut path = self.parse_compound()?.to_step("descendant");
+    loop {
+      let whitespace = self.skip_whitespace();
+      let combinator = match self.peek() {
+        Some(c) if c == '>' || c == '+' || c == '~' => {
+          self.pos += 1;
+          self.skip_whitespace();
+          c
+        }
+        Some(c) if whitespace && c != ',' => ' ',
+        _ => return Ok(path),
+      };
+      let compound = self.parse_compound()?;
+      path.push('/');
+      match combinator {
+        '>' => path.push_str(&compound.to_step("child")),
+        '+' => {
+          path.push_str("following-sibling::*[1]/");
+          path.push_str(&compound.to_step("self"));
+        }
+        '~' => path.push_str(&compound.to_step("following-sibling")),
+        _ => path.push_str(&compound.to_step("descendant")),
+      }
+    }
+  }
+
+  fn parse_compound(&mut self) -> Result<Compound, SelectorError> {
+    let start = self.pos;
+    let element = if self.eat('*') {
+      "*".to_owned()
+    } else if self.peek().is_some_and(is_name_start) {
+      self.parse_markup_name()?
+    } else {
+      "*".to_owned()
+    };
+    let mut conditions = Vec::new();
+    while let Some(c) = self.peek() {
+      match c {
+        '.' | '#' | '[' | ':' => conditions.push(self.parse_simple(&element)?),
+        _ => break,
+      }
+    }
+    if self.pos == start {
+      return self.error("expected a selector");
+    }
+    Ok(Compound {
+      element,
+      conditions,
+    })
+  }
+
+  /// parses a simple selector other than a type selector, as an `XPath` condition
+  fn parse_simple(&mut self, element: &str) -> Result<String, SelectorError> {
+    match self.peek() {
+      Some('.') => {
+        self.pos += 1;
+        let class = self.parse_ident()?;
+        // class attributes are split on single spaces, as in `Node::get_class_names`
+        Ok(format!(
+          "contains(concat(' ', @class, ' '), {})",
+          xpath_literal(&format!(" {} ", class))
+        ))
+      }
+      Some('#') => {
+        self.pos += 1;
+        let id = self.parse_name()?;
+        Ok(format!("@id = {}", xpath_literal(&id)))
+      }
+      Some('[') => self.parse_attribute(),
+      Some(':') => self.parse_pseudo(element),
+      _ => self.error("expected a simple selector"),
+    }
+  }
+
+  fn parse_attribute(&mut self) -> Result<String, SelectorError> {
+    self.expect('[')?;
+    self.skip_whitespace();
+    let name = self.parse_markup_name()?;
+    let attribute = format!("@{}", name);
+    self.skip_whitespace();
+    if self.eat(']') {
+      return Ok(attribute);
+    }
+    let operator = match self.peek() {
+      Some('=') => '=',
+      Some(c) if "~|^$*".contains(c) => {
+        self.pos += 1;
+        c
+      }
+      _ => return self.error("expected an attribute operator"),
+    };
+    self.expect('=')?;
+    self.skip_whitespace();
+    let value = match self.peek() {
+      Some('"') | Some('\'') => self.parse_string()?,
+      _ => self.parse_ident()?,
+    };
+    self.skip_whitespace();
+    self.expect(']')?;
+
+    let literal = xpath_literal(&value);
+    Ok(match operator {
+      '=' => format!("{} = {}", attribute, literal),
+      '~' => {
+        if value.is_empty() || value.contains(char::is_whitespace) {
+          // can never match a whitespace-separated word
+          "false()".to_owned()
+        } else {
+          format!(
+            "contains(concat(' ', normalize-space({}), ' '), {})",
+            attribute,
+            xpath_literal(&format!(" {} ", value))
+          )
+        }
+      }
+      '|' => format!(
+        "{a} = {v} or starts-with({a}, {p})",
+        a = attribute,
+        v = literal,
+        p = xpath_literal(&format!("{}-", value))
+      ),
+      // the substring matching operators never match an empty value
+      _ if value.is_empty() => "false()".to_owned(),
+      '^' => format!("starts-with({}, {})", attribute, literal),
+      '$' => format!(
+        "substring({a}, string-length({a}) - {n}) = {v}",
+        a = attribute,
+        n = value.chars().count() - 1,
+        v = literal
+      ),
+      _ => format!("contains({}, {})", attribute, literal),
+    })
+  }
+
+  fn parse_pseudo(&mut self, element: &str) -> Result<String, SelectorError> {
+    self.expect(':')?;
+    if self.peek() == Some(':') {
+      return self.error("pseudo-elements are not supported");
+    }
+    let start = self.pos;
+    let name = self.parse_ident()?.to_lowercase();
+    // the sibling axes of the *-of-type pseudo-classes need to know the element type
+    let typed_siblings = |axis: &str| {
+      if element == "*" {
+        Err(SelectorError::Syntax {
+          message: format!(
+            ":{} requires a type selector in its compound, e.g. li:{}",
+            name, name
+          ),
+          offset: start,
+        })
+      } else {
+        Ok(format!("{}::{}", axis, element))
+      }
+    };
+    match name.as_str() {
+      "first-child" => Ok("not(preceding-sibling::*)".to_owned()),
+      "last-child" => Ok("not(following-sibling::*)".to_owned()),
+      "only-child" => Ok("not(preceding-sibling::*) and not(following-sibling::*)".to_owned()),
+      "first-of-type" => Ok(format!("not({})", typed_siblings("preceding-sibling")?)),
+      "last-of-type" => Ok(format!("not({})", typed_siblings("following-sibling")?)),
+      "only-of-type" => Ok(format!(
+        "not({}) and not({})",
+        typed_siblings("preceding-sibling")?,
+        typed_siblings("following-sibling")?
+      )),
+      "root" => Ok("not(parent::*)".to_owned()),
+      "empty" => Ok("not(*) and not(text())".to_owned()),
+      "nth-child" | "nth-last-child" | "nth-of-type" | "nth-last-of-type" => {
+        let siblings = match name.as_str() {
+          "nth-child" => "preceding-sibling::*".to_owned(),
+          "nth-last-child" => "following-sibling::*".to_owned(),
+          "nth-of-type" => typed_siblings("preceding-sibling")?,
+          _ => typed_siblings("following-sibling")?,
+        };
+        self.expect('(')?;
+        let nth = self.parse_nth()?;
+        self.expect(')')?;
+        Ok(nth_condition(&format!("(count({}) + 1)", siblings), nth))
+      }
+      "not" => {
+        self.expect('(')?;
+        self.skip_whitespace();
+        let condition = if self.eat('*') {
+          "true()".to_owned()
+        } else if self.peek().is_some_and(is_name_start) {
+          format!("self::{}", self.parse_markup_name()?)
+        } else if self.chars[self.pos..].starts_with(&[':', 'n', 'o', 't']) {
+          return self.error(":not can not be nested");
+        } else {
+          self.parse_simple(element)?
+        };
+        self.skip_whitespace();
+        self.expect(')')?;
+        Ok(format!("not({})", condition))
+      }
+      _ => Err(SelectorError::Syntax {
+        message: format!("unsupported pseudo-class :{}", name),
+        offset: start,
+      }),
+    }
+  }
+
+  /// parses the `an+b` argument of the nth pseudo-classes
+  fn parse_nth(&mut self) -> Result<(i64, i64), SelectorError> {
+    let start = self.pos;
+    let mut argument = String::new();
+    while let Some(c) = self.peek() {
+      if c == ')' {
+        break;
+      }
+      if !c.is_whitespace() {
+        argument.push(c.to_ascii_lowercase());
+      }
+      self.pos += 1;
+    }
+    let invalid = || SelectorError::Syntax {
+      message: format!("invalid nth argument {:?}", argument),
+      offset: start,
+    };
+    let parse_int = |s: &str| s.trim_start_matches('+').parse::<i64>();
+    match argument.as_str() {
+      "odd" => Ok((2, 1)),
+      "even" => Ok((2, 0)),
+      _ => match argument.find('n') {
+        None => Ok((0, parse_int(&argument).map_err(|_| invalid())?)),
+        Some(n) => {
+          let a = match &argument[..n] {
+            "" | "+" => 1,
+            "-" => -1,
+            a => parse_int(a).map_err(|_| invalid())?,
+          };
+          let b = match &argument[n + 1..] {
+            "" => 0,
+            b if b.starts_with('+') || b.starts_with('-') => b.parse().map_err(|_| invalid())?,
+            _ => return Err(invalid()),
+          };
+          Ok((a, b))
+        }
+      },
+    }
+  }
+
+  fn parse_string(&mut self) -> Result<String, SelectorError> {
+    let quote = self.peek().unwrap();
+    self.pos += 1;
+    let mut value = String::new();
+    loop {
+      match self.peek() {
+        None => return self.error("unterminated string"),
+        Some(c) if c == quote => {
+          self.pos += 1;
+          return Ok(value);
+        }
+        Some('\\') if self.chars.get(self.pos + 1).is_some() => {
+          value.push(self.chars[self.pos + 1]);
+          self.pos += 2;
+        }
+        Some(c) => {
+          value.push(c);
+          self.pos += 1;
+        }
+      }
+    }
+  }
+
+  fn parse_ident(&mut self) -> Result<String, SelectorError> {
+    let start = self.pos;
+    self.eat('-');
+    if !self.peek().is_some_and(is_name_start) {
+      self.pos = start;
+      return self.error("expected an identifier");
+    }
+    while self.peek().is_some_and(is_name_char) {
+      self.pos += 1;
+    }
+    Ok(self.chars[start..self.pos].iter().collect())
+  }
+
+  /// parses an element or attribute name, lowercased for HTML documents
+  fn parse_markup_name(&mut self) -> Result<String, SelectorError> {
+    let name = self.parse_ident()?;
+    Ok(if self.html { name.to_lowercase() } else { name })
+  }
+
+  fn parse_name(&mut self) -> Result<String, SelectorError> {
+    let start = self.pos;
+    while self.peek().is_some_and(is_name_char) {
+      self.pos += 1;
+    }
+    if self.pos == start {
+      return self.error("expected a name");
+    }
+    Ok(self.chars[start..self.pos].iter().collect())
+  }
+}
+
+fn is_name_start(c: char) -> bool {
+  c == '_' || c.is_alphabetic() || !c.is_ascii()
+}
+
+fn is_name_char(c: char) -> bool {
+  c == '-' || c.is_alphanumeric() || is_name_start(c)
+}
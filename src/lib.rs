@@ -5,11 +5,16 @@
 #![deny(missing_docs)]
 // Our new methods return Result<Self, _> types
 #![allow(clippy::new_ret_no_self)]
+// Unit errors are part of the established public API
+#![allow(clippy::result_unit_err)]
 
 /// Bindings to the C interface
 pub mod bindings;
 mod c_helpers;
 
+/// CSS selector queries, translated to `XPath`
+pub mod css;
+
 /// XML and HTML parsing
 pub mod parser;
 /// Manipulations on the DOM representation
@@ -11,6 +11,7 @@ use std::str;
 
 enum XmlParserOption {
   Recover = 1, // Relaxed parsing
+  Noent = 2,   // substitute entities
   // XML_PARSE_NODEFDTD = 4, // do not default a doctype if not found
   Noerror = 32, // suppress error reports
   Nowarning = 64, // suppress warning reports
@@ -61,12 +62,14 @@ pub enum ParseFormat {
 pub struct Parser {
   /// The `ParseFormat` for this parser
   pub format: ParseFormat,
+  substitute_entities: bool,
 }
 impl Default for Parser {
   /// Create a parser for XML documents
   fn default() -> Self {
     Parser {
       format: ParseFormat::XML,
+      substitute_entities: false,
     }
   }
 }
@@ -75,10 +78,28 @@ impl Parser {
   pub fn default_html() -> Self {
     Parser {
       format: ParseFormat::HTML,
+      substitute_entities: false,
     }
   }
 
-  ///Parses the XML/HTML file `filename` to generate a new `Document`
+  /// Replace XML entity references by their content while parsing, instead of keeping them
+  pub fn with_entity_substitution(mut self, substitute: bool) -> Self {
+    self.substitute_entities = substitute;
+    self
+  }
+
+  /// The libxml2 options used for parsing XML
+  fn xml_options(&self) -> i32 {
+    let mut options: i32 = XmlParserOption::Recover as i32
+      + XmlParserOption::Noerror as i32
+      + XmlParserOption::Nowarning as i32;
+    if self.substitute_entities {
+      options += XmlParserOption::Noent as i32;
+    }
+    options
+  }
+
+  ///Parses the XML/HTML file `filename` to generate a new `Document`, whose URL is the filename
   pub fn parse_file(&self, filename: &str) -> Result<Document, XmlParseError> {
     let c_filename = CString::new(filename).unwrap();
     let c_utf8 = CString::new("utf-8").unwrap();
@@ -87,9 +108,7 @@ impl Parser {
     }
     match self.format {
       ParseFormat::XML => {
-        let options: i32 = XmlParserOption::Recover as i32
-          + XmlParserOption::Noerror as i32
-          + XmlParserOption::Nowarning as i32;
+        let options = self.xml_options();
         unsafe {
           let doc_ptr = xmlReadFile(c_filename.as_ptr(), c_utf8.as_ptr(), options);
           if doc_ptr.is_null() {
@@ -116,19 +135,16 @@ impl Parser {
     }
   }
 
-  ///Parses the XML/HTML string `input_string` to generate a new `Document`
+  ///Parses the XML/HTML string `input_string` to generate a new `Document`, without a URL
   pub fn parse_string(&self, input_string: &str) -> Result<Document, XmlParseError> {
     let c_string = CString::new(input_string).unwrap();
     let c_utf8 = CString::new("utf-8").unwrap();
-    let c_url = CString::new("").unwrap();
     match self.format {
       ParseFormat::XML => unsafe {
-        let options: i32 = XmlParserOption::Recover as i32
-          + XmlParserOption::Noerror as i32
-          + XmlParserOption::Nowarning as i32;
+        let options = self.xml_options();
         let docptr = xmlReadDoc(
           c_string.as_bytes().as_ptr(),
-          c_url.as_ptr(),
+          ptr::null(),
           c_utf8.as_ptr(),
           options,
         );
@@ -144,7 +160,7 @@ impl Parser {
           + HtmlParserOption::Nowarning as i32;
         let docptr = htmlReadDoc(
           c_string.as_bytes().as_ptr(),
-          c_url.as_ptr(),
+          ptr::null(),
           c_utf8.as_ptr(),
           options,
         );
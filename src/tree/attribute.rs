@@ -0,0 +1,69 @@
+//! Attribute feature set
+//!
+use std::error::Error;
+
+use crate::bindings::*;
+use crate::tree::namespace::Namespace;
+use crate::tree::node::Node;
+
+/// An xml attribute, owned by an element
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Attribute(Node);
+
+impl Attribute {
+  /// Wraps an attribute node
+  pub(crate) fn wrap(node: Node) -> Self {
+    Attribute(node)
+  }
+
+  /// Immutably borrows the underlying libxml2 `xmlAttrPtr` pointer
+  pub fn attr_ptr(&self) -> xmlAttrPtr {
+    self.0.node_ptr() as xmlAttrPtr
+  }
+
+  /// The attribute as a generic `Node`
+  pub fn as_node(&self) -> &Node {
+    &self.0
+  }
+
+  /// The qualified name of the attribute, e.g. `xlink:href`
+  pub fn name(&self) -> String {
+    let local_name = self.local_name();
+    match self.namespace() {
+      Some(ns) if !ns.get_prefix().is_empty() => format!("{}:{}", ns.get_prefix(), local_name),
+      _ => local_name,
+    }
+  }
+
+  /// The name of the attribute, without its namespace prefix
+  pub fn local_name(&self) -> String {
+    self.0.get_name()
+  }
+
+  /// The namespace of the attribute, if any
+  pub fn namespace(&self) -> Option<Namespace> {
+    self.0.get_namespace()
+  }
+
+  /// The value of the attribute
+  pub fn value(&self) -> String {
+    self.0.get_content()
+  }
+
+  /// Sets the value of the attribute
+  pub fn set_value(&mut self, value: &str) -> Result<(), Box<dyn Error>> {
+    self.0.set_content(value)
+  }
+
+  /// The element owning the attribute, unless it was removed
+  pub fn owner_element(&self) -> Option<Node> {
+    self.0.get_parent()
+  }
+
+  /// Removes the attribute from its owner element, freeing it along with its last handle
+  pub fn remove(mut self) -> Result<(), Box<dyn Error>> {
+    self.0.node_ptr_mut()?;
+    self.0.unlink_node();
+    Ok(())
+  }
+}
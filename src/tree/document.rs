@@ -4,7 +4,7 @@
 use libc;
 use libc::{c_char, c_int, c_void};
 use std::cell::RefCell;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::ffi::{CStr, CString};
 use std::ptr;
 use std::rc::{Rc, Weak};
@@ -12,7 +12,11 @@ use std::str;
 
 use crate::bindings::*;
 use crate::c_helpers::*;
-use crate::tree::node::Node;
+use crate::css::{selector_to_xpath, SelectorError};
+use crate::tree::entity::Entity;
+use crate::tree::node::{Node, NodeWeak};
+use crate::tree::nodetype::NodeType;
+use crate::xpath::{Context, XPathError, XPathErrorCode};
 
 pub(crate) type DocumentRef = Rc<RefCell<_Document>>;
 pub(crate) type DocumentWeak = Weak<RefCell<_Document>>;
@@ -21,23 +25,283 @@ pub(crate) type DocumentWeak = Weak<RefCell<_Document>>;
 pub(crate) struct _Document {
   /// pointer to a libxml document
   pub(crate) doc_ptr: xmlDocPtr,
-  /// hashed pointer-to-Node bookkeeping table
-  nodes: HashMap<xmlNodePtr, Node>,
+  /// hashed pointer-to-Node bookkeeping table, releasing each entry along with its last handle
+  nodes: HashMap<xmlNodePtr, NodeWeak>,
+  /// unlinked subtrees without handles, kept while handles or XPath results still refer into them
+  retained_nodes: HashSet<xmlNodePtr>,
+  /// deleted subtrees, unreachable but kept while XPath results may point into them
+  deleted_nodes: Vec<xmlNodePtr>,
+  /// every node of the deleted subtrees, which XPath results skip
+  deleted_subtree_nodes: HashSet<xmlNodePtr>,
+  /// number of live XPath results, which may still refer into this document
+  references: usize,
+  /// number of live `Namespace`s per declaration, kept from being freed along with their element
+  namespaces: HashMap<xmlNsPtr, usize>,
+  /// namespace declarations removed from the tree while referenced, freed along with the document
+  retired_namespaces: Vec<xmlNsPtr>,
 }
 
 impl _Document {
   /// Internal bookkeeping function, so far only used by `Node::wrap`
-  pub(crate) fn insert_node(&mut self, node_ptr: xmlNodePtr, node: Node) {
-    self.nodes.insert(node_ptr, node);
+  pub(crate) fn insert_node(&mut self, node_ptr: xmlNodePtr, node: &Node) {
+    self.nodes.insert(node_ptr, node.downgrade());
   }
   /// Internal bookkeeping function, so far only used by `Node::wrap`
-  pub(crate) fn get_node(&self, node_ptr: xmlNodePtr) -> Option<&Node> {
-    self.nodes.get(&node_ptr)
+  pub(crate) fn get_node(&self, node_ptr: xmlNodePtr) -> Option<Node> {
+    self.nodes.get(&node_ptr).and_then(NodeWeak::upgrade)
   }
   /// Internal bookkeeping function
   pub(crate) fn forget_node(&mut self, node_ptr: xmlNodePtr) {
     self.nodes.remove(&node_ptr);
   }
+  /// Collects the nodes that are still alive: the document tree, and every subtree
+  /// unlinked via the bookkeeping table
+  pub(crate) fn live_nodes(&self) -> HashSet<xmlNodePtr> {
+    let mut live = HashSet::new();
+    if !self.doc_ptr.is_null() {
+      xmlCollectSubtree(self.doc_ptr as xmlNodePtr, &mut live);
+    }
+    for (node_ptr, node) in &self.nodes {
+      if node.upgrade().is_some_and(|node| node.is_unlinked()) {
+        xmlCollectSubtree(*node_ptr, &mut live);
+      }
+    }
+    for node_ptr in &self.retained_nodes {
+      xmlCollectSubtree(*node_ptr, &mut live);
+    }
+    live
+  }
+  /// Bookkeeping of XPath results referring into this document
+  pub(crate) fn hold_reference(&mut self) {
+    self.references += 1;
+  }
+  /// Bookkeeping of XPath results referring into this document.
+  /// Once the last one is gone, the subtrees kept for them are freed.
+  pub(crate) fn release_reference(&mut self) {
+    self.references -= 1;
+    if self.references == 0 {
+      self.free_unreferenced_nodes();
+    }
+  }
+  /// Frees the deleted subtrees, and the retained ones without handles left
+  fn free_unreferenced_nodes(&mut self) {
+    self.deleted_subtree_nodes.clear();
+    for node_ptr in self.deleted_nodes.drain(..) {
+      unsafe { xmlFreeNode(node_ptr) };
+    }
+    let unreferenced: Vec<xmlNodePtr> = self
+      .retained_nodes
+      .iter()
+      .filter(|root| !self.is_referenced(**root))
+      .cloned()
+      .collect();
+    for root in unreferenced {
+      self.free_retained_node(root);
+    }
+  }
+  /// Frees the retained subtree `node_ptr` belongs to, if the last handle into it is gone
+  pub(crate) fn release_retained_subtree(&mut self, node_ptr: xmlNodePtr) {
+    if self.retained_nodes.is_empty() {
+      return;
+    }
+    let mut root = node_ptr;
+    while !xmlGetParent(root).is_null() {
+      root = xmlGetParent(root);
+    }
+    if self.retained_nodes.contains(&root) && !self.is_referenced(root) {
+      self.free_retained_node(root);
+    }
+  }
+  /// Frees a retained subtree nothing refers into anymore
+  fn free_retained_node(&mut self, root: xmlNodePtr) {
+    self.retained_nodes.remove(&root);
+    self.forget_subtree(root);
+    unsafe { xmlFreeNode(root) };
+  }
+  /// Whether the node was deleted via `Node::delete`, while XPath results still refer to it
+  pub(crate) fn is_deleted(&self, node_ptr: xmlNodePtr) -> bool {
+    self.deleted_subtree_nodes.contains(&node_ptr)
+  }
+  /// Whether this is an HTML document, whose element and attribute names are lowercase
+  pub(crate) fn is_html(&self) -> bool {
+    unsafe { (*self.doc_ptr).type_ == xmlElementType_XML_HTML_DOCUMENT_NODE }
+  }
+  /// While XPath results are alive, nodes replaced by mutations are retained until they are
+  /// gone, and namespace declarations until the document is dropped,
+  /// so they never refer to freed memory
+  pub(crate) fn retains_nodes(&self) -> bool {
+    self.references > 0
+  }
+  /// Keeps a list of namespace declarations removed from the tree until the document is dropped
+  pub(crate) fn retire_namespaces(&mut self, ns_list: xmlNsPtr) {
+    self.retired_namespaces.push(ns_list);
+  }
+  /// Checks if XPath results, or handles to nodes of the subtree at `root`, may refer into it
+  pub(crate) fn is_referenced(&self, root: xmlNodePtr) -> bool {
+    if self.retains_nodes() {
+      return true;
+    }
+    let mut subtree = HashSet::new();
+    xmlCollectSubtree(root, &mut subtree);
+    subtree
+      .iter()
+      .any(|node_ptr| self.nodes.get(node_ptr).is_some_and(NodeWeak::is_alive))
+  }
+  /// Drops the bookkeeping of the subtree at `root`, about to be freed.
+  /// Declarations of live `Namespace`s are taken off their elements and retired instead.
+  pub(crate) fn forget_subtree(&mut self, root: xmlNodePtr) {
+    let mut subtree = HashSet::new();
+    xmlCollectSubtree(root, &mut subtree);
+    for node_ptr in subtree {
+      self.nodes.remove(&node_ptr);
+      if xmlGetNodeType(node_ptr) == xmlElementType_XML_ELEMENT_NODE {
+        let ns_list = xmlNodeNsDeclarations(node_ptr);
+        if self.has_live_namespace(ns_list) {
+          unsafe { (*node_ptr).nsDef = ptr::null_mut() };
+          self.retire_namespaces(ns_list);
+        }
+      }
+    }
+  }
+  /// Bookkeeping of the `Namespace`s referring to a declaration
+  pub(crate) fn hold_namespace(&mut self, ns_ptr: xmlNsPtr) {
+    *self.namespaces.entry(ns_ptr).or_insert(0) += 1;
+  }
+  /// Bookkeeping of the `Namespace`s referring to a declaration
+  pub(crate) fn release_namespace(&mut self, ns_ptr: xmlNsPtr) {
+    if let Some(count) = self.namespaces.get_mut(&ns_ptr) {
+      *count -= 1;
+      if *count == 0 {
+        self.namespaces.remove(&ns_ptr);
+      }
+    }
+  }
+  /// Checks if a `Namespace` refers to a declaration of the list starting at `ns_list`
+  pub(crate) fn has_live_namespace(&self, ns_list: xmlNsPtr) -> bool {
+    let mut ns_ptr = ns_list;
+    while !ns_ptr.is_null() && !self.namespaces.is_empty() {
+      if self.namespaces.contains_key(&ns_ptr) {
+        return true;
+      }
+      ns_ptr = xmlNextNsSibling(ns_ptr);
+    }
+    false
+  }
+  /// Checks if a `Namespace` refers to a declaration made within the subtree at `root`
+  fn declares_live_namespace(&self, root: xmlNodePtr) -> bool {
+    if self.namespaces.is_empty() {
+      return false;
+    }
+    let mut subtree = HashSet::new();
+    xmlCollectSubtree(root, &mut subtree);
+    subtree.into_iter().any(|node_ptr| {
+      xmlGetNodeType(node_ptr) == xmlElementType_XML_ELEMENT_NODE
+        && self.has_live_namespace(xmlNodeNsDeclarations(node_ptr))
+    })
+  }
+  /// Removes the bookkept nodes of the subtree at `root`, handing them over to another document
+  pub(crate) fn take_subtree_nodes(&mut self, root: xmlNodePtr) -> Vec<(xmlNodePtr, Node)> {
+    let mut subtree = HashSet::new();
+    xmlCollectSubtree(root, &mut subtree);
+    subtree
+      .into_iter()
+      .filter_map(|node_ptr| {
+        let node = self.nodes.remove(&node_ptr)?.upgrade()?;
+        Some((node_ptr, node))
+      })
+      .collect()
+  }
+  /// Keeps the unlinked subtree at `root`, whose last handle was dropped, while still referenced
+  pub(crate) fn retain_node(&mut self, root: xmlNodePtr) {
+    self.retained_nodes.insert(root);
+  }
+  /// Frees the unlinked subtree at `root`, deleted via `Node::delete`, once XPath results
+  /// and namespaces no longer refer into the document. `subtree` lists its nodes.
+  pub(crate) fn free_deleted_node(&mut self, root: xmlNodePtr, subtree: HashSet<xmlNodePtr>) {
+    if self.retains_nodes() {
+      self.deleted_nodes.push(root);
+      self.deleted_subtree_nodes.extend(subtree);
+    } else {
+      unsafe { xmlFreeNode(root) };
+    }
+  }
+  /// Hands a retained subtree over to a new handle, returning whether it was retained
+  pub(crate) fn release_retained_node(&mut self, root: xmlNodePtr) -> bool {
+    self.retained_nodes.remove(&root)
+  }
+}
+
+/// The element or attributes of `element` bound to a namespace, with their namespace
+fn namespace_users(element: xmlNodePtr) -> Vec<(xmlNodePtr, xmlNsPtr)> {
+  let mut users = Vec::new();
+  if !xmlNodeNs(element).is_null() {
+    users.push((element, xmlNodeNs(element)));
+  }
+  let mut attr = xmlGetFirstProperty(element);
+  while !attr.is_null() {
+    let attr_ns = xmlNodeNs(attr as xmlNodePtr);
+    if !attr_ns.is_null() {
+      users.push((attr as xmlNodePtr, attr_ns));
+    }
+    attr = xmlNextPropertySibling(attr);
+  }
+  users
+}
+
+/// Checks if `prefix:` occurs in the text or attribute values of the subtree at `element`,
+/// as QName content such as `xsi:type="t:Foo"` refers to declarations by prefix
+fn prefix_used_in_content(element: xmlNodePtr, prefix: &[u8]) -> bool {
+  let is_name_byte = |b: u8| b.is_ascii_alphanumeric() || b"-_.".contains(&b) || b >= 0x80;
+  let mut subtree = HashSet::new();
+  xmlCollectSubtree(element, &mut subtree);
+  subtree.into_iter().any(|node_ptr| {
+    let node_type = xmlGetNodeType(node_ptr);
+    if node_type != xmlElementType_XML_TEXT_NODE
+      && node_type != xmlElementType_XML_CDATA_SECTION_NODE
+    {
+      return false;
+    }
+    let content_ptr = unsafe { (*node_ptr).content };
+    if content_ptr.is_null() {
+      return false;
+    }
+    let content = unsafe { CStr::from_ptr(content_ptr as *const c_char) }.to_bytes();
+    (0..content.len()).any(|start| {
+      content[start..].starts_with(prefix)
+        && content.get(start + prefix.len()) == Some(&b':')
+        && (start == 0 || !is_name_byte(content[start - 1]))
+    })
+  })
+}
+
+/// Copies a string owned by libxml2, which may be null
+pub(crate) fn optional_string(value_ptr: *const c_char) -> Option<String> {
+  if value_ptr.is_null() {
+    None
+  } else {
+    let c_string = unsafe { CStr::from_ptr(value_ptr) };
+    Some(c_string.to_string_lossy().into_owned())
+  }
+}
+
+/// Replaces a string owned by the document with a copy of `value`
+fn replace_string(field: &mut *const xmlChar, value: &str) {
+  let c_value = CString::new(value).unwrap();
+  unsafe {
+    libc::free(*field as *mut c_void);
+    *field = xmlStrdup(c_value.as_bytes().as_ptr());
+  }
+}
+
+/// The name and identifiers of a `<!DOCTYPE>` declaration
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Doctype {
+  /// The name of the root element, e.g. `html`
+  pub name: String,
+  /// The public identifier, e.g. `-//W3C//DTD XHTML 1.0 Strict//EN`
+  pub public_id: Option<String>,
+  /// The system identifier, usually the URL of the DTD
+  pub system_id: Option<String>,
 }
 
 /// A libxml2 Document
@@ -47,7 +311,17 @@ pub struct Document(pub(crate) DocumentRef);
 impl Drop for _Document {
   ///Free document when it goes out of scope
   fn drop(&mut self) {
+    // every handle is gone, unlinked subtrees left are the retained ones
     unsafe {
+      for node_ptr in self.retained_nodes.drain() {
+        xmlFreeNode(node_ptr);
+      }
+      for node_ptr in self.deleted_nodes.drain(..) {
+        xmlFreeNode(node_ptr);
+      }
+      for ns_list in self.retired_namespaces.drain(..) {
+        xmlFreeNsList(ns_list);
+      }
       if !self.doc_ptr.is_null() {
         xmlFreeDoc(self.doc_ptr);
       }
@@ -67,12 +341,29 @@ impl Document {
         let doc = _Document {
           doc_ptr,
           nodes: HashMap::new(),
+          retained_nodes: HashSet::new(),
+          deleted_nodes: Vec::new(),
+          deleted_subtree_nodes: HashSet::new(),
+          references: 0,
+          namespaces: HashMap::new(),
+          retired_namespaces: Vec::new(),
         };
         Ok(Document(Rc::new(RefCell::new(doc))))
       }
     }
   }
 
+  /// Creates a new HTML `Document`, declared as `<!DOCTYPE html>`
+  pub fn new_html() -> Result<Self, ()> {
+    let doc_ptr = unsafe { htmlNewDocNoDtD(ptr::null(), ptr::null()) };
+    if doc_ptr.is_null() {
+      return Err(());
+    }
+    let mut doc = Document::new_ptr(doc_ptr);
+    doc.create_internal_subset("html", None, None)?;
+    Ok(doc)
+  }
+
   /// Obtain the underlying libxml2 `xmlDocPtr` for this Document
   pub fn doc_ptr(&self) -> xmlDocPtr {
     self.0.borrow().doc_ptr
@@ -83,6 +374,12 @@ impl Document {
     let doc = _Document {
       doc_ptr,
       nodes: HashMap::new(),
+      retained_nodes: HashSet::new(),
+      deleted_nodes: Vec::new(),
+      deleted_subtree_nodes: HashSet::new(),
+      references: 0,
+      namespaces: HashMap::new(),
+      retired_namespaces: Vec::new(),
     };
     Document(Rc::new(RefCell::new(doc)))
   }
@@ -91,6 +388,12 @@ impl Document {
     Rc::new(RefCell::new(_Document {
       doc_ptr: ptr::null_mut(),
       nodes: HashMap::new(),
+      retained_nodes: HashSet::new(),
+      deleted_nodes: Vec::new(),
+      deleted_subtree_nodes: HashSet::new(),
+      references: 0,
+      namespaces: HashMap::new(),
+      retired_namespaces: Vec::new(),
     }))
   }
 
@@ -122,10 +425,13 @@ impl Document {
     }
   }
 
-  /// Sets the root element of the document
+  /// Sets the root element of the document.
+  /// A former root element is unlinked, and freed unless still referred to.
   pub fn set_root_element(&mut self, root: &Node) {
-    unsafe {
-      xmlDocSetRootElement(self.doc_ptr(), root.node_ptr());
+    let old_root_ptr = unsafe { xmlDocSetRootElement(self.doc_ptr(), root.node_ptr()) };
+    root.clone().set_linked();
+    if !old_root_ptr.is_null() && old_root_ptr != root.node_ptr() {
+      Node::wrap_new(old_root_ptr, &self.0);
     }
   }
 
@@ -138,21 +444,88 @@ impl Document {
     }
   }
 
-  /// Import a `Node` from another `Document`
-  pub fn import_node(&mut self, node: &mut Node) -> Result<Node, ()> {
-    if !node.is_unlinked() {
+  /// Import a copy of a `Node` from another `Document`, leaving the original untouched.
+  /// A `deep` import includes the descendants of the node, see `Node::copy_to`.
+  pub fn import_node(&self, node: &Node, deep: bool) -> Result<Node, ()> {
+    node.copy_to(self, deep)
+  }
+
+  /// Move a `Node` from another `Document` into this one, unlinking it from its former tree.
+  /// Namespaces the node relies on are declared on it, and the handles to the node
+  /// and its descendants belong to this document from now on.
+  /// Fails while XPath results of the former document are alive, or `Namespace`s
+  /// refer to declarations made within the moved subtree.
+  pub fn adopt_node(&mut self, mut node: Node) -> Result<Node, ()> {
+    match node.get_type() {
+      None
+      | Some(NodeType::DocumentNode)
+      | Some(NodeType::HtmlDocumentNode)
+      | Some(NodeType::DTDNode) => return Err(()),
+      _ => {}
+    }
+    let source = node.get_docref().upgrade().ok_or(())?;
+    if Rc::ptr_eq(&source, &self.0) {
+      node.unlink_node();
+      return Ok(node);
+    }
+    if source.borrow().retains_nodes() || source.borrow().declares_live_namespace(node.node_ptr()) {
+      return Err(());
+    }
+    node.unlink_node();
+    let source_doc_ptr = source.borrow().doc_ptr;
+    if node.get_type() == Some(NodeType::ElementNode) {
+      // declare the namespaces left behind on the node itself, before moving it
+      unsafe { xmlReconciliateNs(source_doc_ptr, node.node_ptr()) };
+    }
+    let status = unsafe {
+      xmlDOMWrapAdoptNode(
+        ptr::null_mut(),
+        source_doc_ptr,
+        node.node_ptr(),
+        self.doc_ptr(),
+        ptr::null_mut(),
+        0,
+      )
+    };
+    if status != 0 {
       return Err(());
     }
-    // Also remove this node from the prior document hash
-    node
-      .get_docref()
-      .upgrade()
-      .unwrap()
-      .borrow_mut()
-      .forget_node(node.node_ptr());
+    let moved = source.borrow_mut().take_subtree_nodes(node.node_ptr());
+    for (node_ptr, moved_node) in moved {
+      moved_node.rebind(&self.0);
+      self.0.borrow_mut().insert_node(node_ptr, &moved_node);
+    }
+    // a handle forgotten by its former document is bound here as well
+    node.rebind(&self.0);
+    self.0.borrow_mut().insert_node(node.node_ptr(), &node);
+    Ok(node)
+  }
 
-    let node_ptr = unsafe { xmlDocCopyNode(node.node_ptr(), self.doc_ptr(), 1) };
-    self.ptr_as_result(node_ptr)
+  /// Removes the text nodes holding nothing but whitespace from the document,
+  /// except within `xml:space="preserve"` scopes
+  pub fn strip_blank_nodes(&mut self) {
+    let doc_node = self.as_node();
+    let mut blank_nodes = Vec::new();
+    let mut pending = vec![self.doc_ptr() as xmlNodePtr];
+    while let Some(parent_ptr) = pending.pop() {
+      let preserve = unsafe { xmlNodeGetSpacePreserve(parent_ptr) } == 1;
+      let mut child_ptr = xmlGetFirstChild(parent_ptr);
+      while !child_ptr.is_null() {
+        let child_type = xmlGetNodeType(child_ptr);
+        if child_type == xmlElementType_XML_ELEMENT_NODE {
+          pending.push(child_ptr);
+        } else if child_type == xmlElementType_XML_TEXT_NODE
+          && !preserve
+          && unsafe { xmlIsBlankNode(child_ptr) } == 1
+        {
+          blank_nodes.push(child_ptr);
+        }
+        child_ptr = xmlNextSibling(child_ptr);
+      }
+    }
+    for node_ptr in blank_nodes {
+      doc_node.discard_ptr(node_ptr);
+    }
   }
 
   /// Serializes the `Document`
@@ -218,11 +591,393 @@ impl Document {
       if node_ptr.is_null() {
         Err(())
       } else {
-        Ok(self.register_node(node_ptr))
+        Ok(Node::wrap_new(node_ptr, &self.0))
       }
     }
   }
 
+  /// Creates the internal subset, i.e. the `<!DOCTYPE>` declaration, of the document.
+  /// Fails if the document already has one.
+  pub fn create_internal_subset(
+    &mut self,
+    name: &str,
+    external_id: Option<&str>,
+    system_id: Option<&str>,
+  ) -> Result<Node, ()> {
+    let c_name = CString::new(name).unwrap();
+    let c_external_id = external_id.map(|id| CString::new(id).unwrap());
+    let c_system_id = system_id.map(|id| CString::new(id).unwrap());
+    let dtd_ptr = unsafe {
+      xmlCreateIntSubset(
+        self.doc_ptr(),
+        c_name.as_bytes().as_ptr(),
+        c_external_id
+          .as_ref()
+          .map_or(ptr::null(), |id| id.as_bytes().as_ptr()),
+        c_system_id
+          .as_ref()
+          .map_or(ptr::null(), |id| id.as_bytes().as_ptr()),
+      )
+    };
+    self.ptr_as_result(dtd_ptr as xmlNodePtr)
+  }
+
+  /// The XML version declared by the document, none for HTML documents
+  pub fn version(&self) -> Option<String> {
+    optional_string(unsafe { (*self.doc_ptr()).version } as *const c_char)
+  }
+
+  /// The encoding declared by the document, if any
+  pub fn encoding(&self) -> Option<String> {
+    optional_string(unsafe { (*self.doc_ptr()).encoding } as *const c_char)
+  }
+
+  /// Sets the encoding declared by the document, used by `save_file`
+  pub fn set_encoding(&mut self, encoding: &str) {
+    replace_string(unsafe { &mut (*self.doc_ptr()).encoding }, encoding);
+  }
+
+  /// The standalone flag of the XML declaration, none if it is not declared
+  pub fn standalone(&self) -> Option<bool> {
+    match unsafe { (*self.doc_ptr()).standalone } {
+      1 => Some(true),
+      0 => Some(false),
+      _ => None,
+    }
+  }
+
+  /// Sets the standalone flag of the XML declaration, or leaves it out with `None`
+  pub fn set_standalone(&mut self, standalone: Option<bool>) {
+    let value = match standalone {
+      Some(true) => 1,
+      Some(false) => 0,
+      None => -1,
+    };
+    unsafe { (*self.doc_ptr()).standalone = value };
+  }
+
+  /// The URL the document was loaded from, against which relative references resolve
+  pub fn url(&self) -> Option<String> {
+    optional_string(unsafe { (*self.doc_ptr()).URL } as *const c_char)
+  }
+
+  /// Sets the URL of the document, the base of relative references
+  pub fn set_url(&mut self, url: &str) {
+    replace_string(unsafe { &mut (*self.doc_ptr()).URL }, url);
+  }
+
+  /// The `<!DOCTYPE>` declaration of the document, if any
+  pub fn doctype(&self) -> Option<Doctype> {
+    let dtd_ptr = unsafe { xmlGetIntSubset(self.doc_ptr()) };
+    if dtd_ptr.is_null() {
+      return None;
+    }
+    Some(Doctype {
+      name: optional_string(xmlNodeGetName(dtd_ptr as xmlNodePtr)).unwrap_or_default(),
+      public_id: optional_string(xmlDtdExternalID(dtd_ptr)),
+      system_id: optional_string(xmlDtdSystemID(dtd_ptr)),
+    })
+  }
+
+  /// Sets the `<!DOCTYPE>` declaration of the document, replacing the former one
+  /// along with the entities it declares
+  pub fn set_doctype(
+    &mut self,
+    name: &str,
+    public_id: Option<&str>,
+    system_id: Option<&str>,
+  ) -> Result<Node, ()> {
+    self.remove_doctype();
+    self.create_internal_subset(name, public_id, system_id)
+  }
+
+  /// Removes the `<!DOCTYPE>` declaration of the document, returning it as an unlinked node.
+  /// References to the entities it declares are left unresolved.
+  pub fn remove_doctype(&mut self) -> Option<Node> {
+    let dtd_ptr = unsafe { xmlGetIntSubset(self.doc_ptr()) } as xmlNodePtr;
+    if dtd_ptr.is_null() {
+      return None;
+    }
+    // entity references point to their declaration, which is about to go away
+    for node_ptr in self.0.borrow().live_nodes() {
+      if xmlGetNodeType(node_ptr) == xmlElementType_XML_ENTITY_REF_NODE
+        && xmlGetParent(xmlGetFirstChild(node_ptr)) == dtd_ptr
+      {
+        unsafe {
+          (*node_ptr).children = ptr::null_mut();
+          (*node_ptr).last = ptr::null_mut();
+        }
+      }
+    }
+    unsafe { xmlUnlinkNode(dtd_ptr) };
+    Some(Node::wrap_new(dtd_ptr, &self.0))
+  }
+
+  /// Declares an internal general entity, referred to as `&name;` in the document.
+  /// The document needs an internal subset, see `create_internal_subset`.
+  pub fn add_entity(&mut self, name: &str, content: &str) -> Result<Entity, ()> {
+    let c_content = CString::new(content).unwrap();
+    self.declare_entity(
+      name,
+      xmlEntityType_XML_INTERNAL_GENERAL_ENTITY,
+      None,
+      None,
+      Some(&c_content),
+    )
+  }
+
+  /// Declares an external parsed entity, whose replacement text is loaded from `system_id`.
+  /// The document needs an internal subset, see `create_internal_subset`.
+  pub fn add_external_entity(
+    &mut self,
+    name: &str,
+    external_id: Option<&str>,
+    system_id: &str,
+  ) -> Result<Entity, ()> {
+    let c_external_id = external_id.map(|id| CString::new(id).unwrap());
+    let c_system_id = CString::new(system_id).unwrap();
+    self.declare_entity(
+      name,
+      xmlEntityType_XML_EXTERNAL_GENERAL_PARSED_ENTITY,
+      c_external_id.as_ref(),
+      Some(&c_system_id),
+      None,
+    )
+  }
+
+  fn declare_entity(
+    &mut self,
+    name: &str,
+    kind: xmlEntityType,
+    external_id: Option<&CString>,
+    system_id: Option<&CString>,
+    content: Option<&CString>,
+  ) -> Result<Entity, ()> {
+    let c_name = CString::new(name).unwrap();
+    let as_ptr = |value: Option<&CString>| value.map_or(ptr::null(), |v| v.as_bytes().as_ptr());
+    let entity_ptr = unsafe {
+      if xmlGetIntSubset(self.doc_ptr()).is_null() {
+        return Err(());
+      }
+      xmlAddDocEntity(
+        self.doc_ptr(),
+        c_name.as_bytes().as_ptr(),
+        kind as c_int,
+        as_ptr(external_id),
+        as_ptr(system_id),
+        as_ptr(content),
+      )
+    };
+    self
+      .ptr_as_result(entity_ptr as xmlNodePtr)
+      .map(Entity::wrap)
+  }
+
+  /// Looks up a general entity declared in the internal or external subset.
+  /// The predefined entities, such as `lt`, are not part of any document.
+  pub fn get_entity(&self, name: &str) -> Option<Entity> {
+    let c_name = CString::new(name).unwrap();
+    let entity_ptr = unsafe { xmlGetDocEntity(self.doc_ptr(), c_name.as_bytes().as_ptr()) };
+    if entity_ptr.is_null()
+      || xmlEntityKind(entity_ptr) == xmlEntityType_XML_INTERNAL_PREDEFINED_ENTITY
+    {
+      None
+    } else {
+      Some(Entity::wrap(self.register_node(entity_ptr as xmlNodePtr)))
+    }
+  }
+
+  /// The general entities declared in the internal subset, followed by those of the external subset
+  pub fn get_entities(&self) -> Vec<Entity> {
+    let doc_ptr = self.doc_ptr();
+    let subsets = unsafe { [(*doc_ptr).intSubset, (*doc_ptr).extSubset] };
+    let mut entities = Vec::new();
+    for dtd_ptr in subsets {
+      let mut child_ptr = xmlGetFirstChild(dtd_ptr as xmlNodePtr);
+      while !child_ptr.is_null() {
+        if xmlGetNodeType(child_ptr) == xmlElementType_XML_ENTITY_DECL
+          && xmlEntityKind(child_ptr as xmlEntityPtr)
+            <= xmlEntityType_XML_EXTERNAL_GENERAL_UNPARSED_ENTITY
+        {
+          entities.push(Entity::wrap(self.register_node(child_ptr)));
+        }
+        child_ptr = xmlNextSibling(child_ptr);
+      }
+    }
+    entities
+  }
+
+  /// find nodes via xpath, at the document root
+  pub fn findnodes(&self, xpath: &str) -> Result<Vec<Node>, XPathError> {
+    let mut context = self.xpath_context(xpath, &[])?;
+    context.findnodes(xpath, None)
+  }
+
+  /// find a literal value via xpath, at the document root.
+  /// `namespaces` lists the prefix-href pairs used in the expression.
+  pub fn findvalue(&self, xpath: &str, namespaces: &[(&str, &str)]) -> Result<String, XPathError> {
+    let mut context = self.xpath_context(xpath, namespaces)?;
+    context.findvalue(xpath, None)
+  }
+
+  /// find the first node via xpath, at the document root
+  pub fn find_first(
+    &self,
+    xpath: &str,
+    namespaces: &[(&str, &str)],
+  ) -> Result<Option<Node>, XPathError> {
+    let mut context = self.xpath_context(xpath, namespaces)?;
+    context.find_first(xpath, None)
+  }
+
+  /// find a number via xpath, at the document root
+  pub fn find_number(&self, xpath: &str, namespaces: &[(&str, &str)]) -> Result<f64, XPathError> {
+    let mut context = self.xpath_context(xpath, namespaces)?;
+    context.find_number(xpath, None)
+  }
+
+  /// find a boolean via xpath, at the document root
+  pub fn find_bool(&self, xpath: &str, namespaces: &[(&str, &str)]) -> Result<bool, XPathError> {
+    let mut context = self.xpath_context(xpath, namespaces)?;
+    context.find_bool(xpath, None)
+  }
+
+  /// check if an xpath matches anything, at the document root
+  pub fn exists(&self, xpath: &str, namespaces: &[(&str, &str)]) -> Result<bool, XPathError> {
+    let mut context = self.xpath_context(xpath, namespaces)?;
+    context.exists(xpath, None)
+  }
+
+  /// find the elements of the document matching a CSS selector.
+  /// Type and attribute selectors match case-insensitively in HTML documents.
+  pub fn select(&self, selector: &str) -> Result<Vec<Node>, SelectorError> {
+    let xpath = selector_to_xpath(selector, self.0.borrow().is_html())?;
+    Ok(self.findnodes(&xpath)?)
+  }
+
+  /// internal helper to set up an xpath context for this document
+  fn xpath_context(&self, xpath: &str, namespaces: &[(&str, &str)]) -> Result<Context, XPathError> {
+    let invalid_context = |_| XPathError::new(XPathErrorCode::InvalidCtxt, Some(xpath));
+    let mut context = Context::new(self).map_err(invalid_context)?;
+    // evaluate relative expressions at the document node
+    context
+      .set_context_node(&self.as_node())
+      .map_err(invalid_context)?;
+    context.register_namespaces(namespaces)?;
+    Ok(context)
+  }
+
+  /// Normalizes the namespace declarations of the document: prefixed declarations repeated
+  /// throughout the tree are hoisted to the root element, as long as their prefix is bound
+  /// to a single href, and declarations no element or attribute uses are removed.
+  /// Default namespace declarations are left in place, as are declarations whose prefix
+  /// occurs in text or attribute values in their scope, e.g. as QName `xsi:type="t:Foo"`.
+  pub fn normalize_namespaces(&mut self) -> Result<(), ()> {
+    let root = unsafe { xmlDocGetRootElement(self.doc_ptr()) };
+    if root.is_null() {
+      return Ok(());
+    }
+    let mut elements = Vec::new();
+    let mut pending = vec![root];
+    while let Some(element) = pending.pop() {
+      elements.push(element);
+      let mut child = xmlGetFirstChild(element);
+      while !child.is_null() {
+        if xmlGetNodeType(child) == xmlElementType_XML_ELEMENT_NODE {
+          pending.push(child);
+        }
+        child = xmlNextSibling(child);
+      }
+    }
+
+    // prefixed declarations, and the hrefs each prefix is bound to
+    let mut declarations: HashMap<xmlNsPtr, Vec<u8>> = HashMap::new();
+    let mut bindings: HashMap<Vec<u8>, HashSet<Vec<u8>>> = HashMap::new();
+    for element in &elements {
+      let mut ns = xmlNodeNsDeclarations(*element);
+      while !ns.is_null() {
+        if !xmlNsPrefix(ns).is_null() {
+          let prefix = unsafe { CStr::from_ptr(xmlNsPrefix(ns)) }
+            .to_bytes()
+            .to_vec();
+          let href = unsafe { CStr::from_ptr(xmlNsHref(ns)) }.to_bytes().to_vec();
+          bindings.entry(prefix.clone()).or_default().insert(href);
+          declarations.insert(ns, prefix);
+        }
+        ns = xmlNextNsSibling(ns);
+      }
+    }
+
+    // hoist the used, unambiguous prefixes to the root
+    let mut hoisted: HashMap<Vec<u8>, xmlNsPtr> = HashMap::new();
+    for element in &elements {
+      for (owner, ns) in namespace_users(*element) {
+        let prefix = match declarations.get(&ns) {
+          Some(prefix) if bindings[prefix].len() == 1 => prefix,
+          _ => continue,
+        };
+        let target = match hoisted.get(prefix) {
+          Some(target) => *target,
+          None => {
+            // reuse the root's own declaration, if any
+            let mut target = xmlNodeNsDeclarations(root);
+            while !target.is_null() && declarations.get(&target) != Some(prefix) {
+              target = xmlNextNsSibling(target);
+            }
+            if target.is_null() {
+              let c_prefix = CString::new(prefix.clone()).unwrap();
+              target = unsafe {
+                xmlNewNs(
+                  root,
+                  xmlNsHref(ns) as *const xmlChar,
+                  c_prefix.as_bytes().as_ptr(),
+                )
+              };
+              if target.is_null() {
+                return Err(());
+              }
+            }
+            hoisted.insert(prefix.clone(), target);
+            target
+          }
+        };
+        unsafe { (*owner).ns = target };
+      }
+    }
+
+    // remove the declarations left unused
+    let mut used = HashSet::new();
+    for element in &elements {
+      used.extend(namespace_users(*element).into_iter().map(|(_, ns)| ns));
+    }
+    for element in &elements {
+      let mut previous: xmlNsPtr = ptr::null_mut();
+      let mut ns = xmlNodeNsDeclarations(*element);
+      while !ns.is_null() {
+        let next = xmlNextNsSibling(ns);
+        if xmlNsPrefix(ns).is_null()
+          || used.contains(&ns)
+          || prefix_used_in_content(*element, &declarations[&ns])
+        {
+          previous = ns;
+        } else {
+          unsafe {
+            if previous.is_null() {
+              (**element).nsDef = next;
+            } else {
+              (*previous).next = next;
+            }
+            (*ns).next = ptr::null_mut();
+          }
+          // detached nodes and `Namespace`s may still point to the declaration
+          self.0.borrow_mut().retire_namespaces(ns);
+        }
+        ns = next;
+      }
+    }
+    Ok(())
+  }
+
   /// Cast the document as a libxml Node
   pub fn as_node(&self) -> Node {
     // Note: this method is important to keep, as it enables certain low-level libxml2 idioms
@@ -242,6 +997,12 @@ impl Document {
       let doc = _Document {
         doc_ptr,
         nodes: HashMap::new(),
+        retained_nodes: HashSet::new(),
+        deleted_nodes: Vec::new(),
+        deleted_subtree_nodes: HashSet::new(),
+        references: 0,
+        namespaces: HashMap::new(),
+        retired_namespaces: Vec::new(),
       };
       Ok(Document(Rc::new(RefCell::new(doc))))
     }
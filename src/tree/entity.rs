@@ -0,0 +1,52 @@
+//! Entity feature set
+//!
+use crate::bindings::*;
+use crate::c_helpers::*;
+use crate::tree::document::optional_string;
+use crate::tree::node::Node;
+
+/// A general entity declared in the DTD of a document
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Entity(Node);
+
+impl Entity {
+  /// Wraps an entity declaration node
+  pub(crate) fn wrap(node: Node) -> Self {
+    Entity(node)
+  }
+
+  /// Immutably borrows the underlying libxml2 `xmlEntityPtr` pointer
+  pub fn entity_ptr(&self) -> xmlEntityPtr {
+    self.0.node_ptr() as xmlEntityPtr
+  }
+
+  /// The entity declaration as a generic `Node`
+  pub fn as_node(&self) -> &Node {
+    &self.0
+  }
+
+  /// The name of the entity, as used in a reference such as `&name;`
+  pub fn name(&self) -> String {
+    self.0.get_name()
+  }
+
+  /// The replacement text of an internal entity
+  pub fn content(&self) -> Option<String> {
+    optional_string(xmlEntityContent(self.entity_ptr()))
+  }
+
+  /// The public identifier of an external entity, if declared
+  pub fn external_id(&self) -> Option<String> {
+    optional_string(xmlEntityExternalID(self.entity_ptr()))
+  }
+
+  /// The system identifier of an external entity
+  pub fn system_id(&self) -> Option<String> {
+    optional_string(xmlEntitySystemID(self.entity_ptr()))
+  }
+
+  /// Whether the entity refers to an external resource
+  pub fn is_external(&self) -> bool {
+    xmlEntityKind(self.entity_ptr()) != xmlEntityType_XML_INTERNAL_GENERAL_ENTITY
+  }
+}
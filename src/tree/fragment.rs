@@ -0,0 +1,43 @@
+//! Document fragment feature set
+//!
+use std::ops::{Deref, DerefMut};
+
+use crate::tree::document::Document;
+use crate::tree::node::Node;
+
+/// A lightweight container of nodes, built up outside of the document tree.
+/// Adding the fragment to a `Node`, via `add_child`, `add_prev_sibling` or `add_next_sibling`,
+/// moves all of its children there at once, leaving the fragment empty for reuse.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DocumentFragment(Node);
+
+impl DocumentFragment {
+  /// Creates a new, empty fragment bound to a given document
+  pub fn new(doc: &Document) -> Result<Self, ()> {
+    Node::new_document_fragment(doc).map(DocumentFragment)
+  }
+
+  /// The fragment as a generic `Node`
+  pub fn as_node(&self) -> &Node {
+    &self.0
+  }
+
+  /// Checks if the fragment has no children
+  pub fn is_empty(&self) -> bool {
+    self.0.get_first_child().is_none()
+  }
+}
+
+impl Deref for DocumentFragment {
+  type Target = Node;
+
+  fn deref(&self) -> &Node {
+    &self.0
+  }
+}
+
+impl DerefMut for DocumentFragment {
+  fn deref_mut(&mut self) -> &mut Node {
+    &mut self.0
+  }
+}
@@ -1,14 +1,21 @@
 //! The tree functionality
 //!
 
+pub mod attribute;
 pub mod document;
+pub mod entity;
+pub mod fragment;
 pub mod namespace;
 pub mod node;
 pub mod nodetype;
 
-pub use self::document::Document;
+pub use self::attribute::Attribute;
+pub use self::document::{Doctype, Document};
 pub(crate) use self::document::{DocumentRef, DocumentWeak};
+pub use self::entity::Entity;
+pub use self::fragment::DocumentFragment;
 pub use self::namespace::Namespace;
+#[allow(deprecated)]
 pub use self::node::set_node_rc_guard;
-pub use self::node::{Node, NODE_RC_MAX_GUARD};
+pub use self::node::Node;
 pub use self::nodetype::NodeType;
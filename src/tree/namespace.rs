@@ -2,23 +2,58 @@
 //!
 use std::error::Error;
 use std::ffi::{CStr, CString};
+use std::fmt;
 use std::ptr;
 use std::str;
 
 use crate::bindings::*;
 use crate::c_helpers::*;
 use crate::tree::node::Node;
+use crate::tree::DocumentRef;
 
-///An xml namespace
-#[derive(Clone)]
+///An xml namespace.
+///The underlying declaration is owned by its element, so a `Namespace` keeps the element's
+///`Document` alive and is freed along with it.
 pub struct Namespace {
   ///libxml's xmlNsPtr
   pub(crate) ns_ptr: xmlNsPtr,
+  ///Document owning the declaration
+  document: DocumentRef,
+}
+
+impl fmt::Debug for Namespace {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    f.debug_struct("Namespace")
+      .field("prefix", &self.get_prefix())
+      .field("href", &self.get_href())
+      .finish()
+  }
+}
+
+impl PartialEq for Namespace {
+  /// Two namespaces are considered equal, if they point to the same declaration.
+  fn eq(&self, other: &Namespace) -> bool {
+    self.ns_ptr == other.ns_ptr
+  }
+}
+
+impl Eq for Namespace {}
+
+impl Clone for Namespace {
+  fn clone(&self) -> Self {
+    Namespace::wrap(self.ns_ptr, &self.document)
+  }
+}
+
+impl Drop for Namespace {
+  fn drop(&mut self) {
+    self.document.borrow_mut().release_namespace(self.ns_ptr);
+  }
 }
 
 impl Namespace {
   /// Creates a new namespace
-  pub fn new(prefix: &str, href: &str, node: &mut Node) -> Result<Self, Box<Error>> {
+  pub fn new(prefix: &str, href: &str, node: &mut Node) -> Result<Self, Box<dyn Error>> {
     let c_href = CString::new(href).unwrap();
     let c_prefix = CString::new(prefix).unwrap();
     let c_prefix_ptr = if prefix.is_empty() {
@@ -27,6 +62,14 @@ impl Namespace {
       c_prefix.as_ptr()
     };
 
+    let document = match node.get_docref().upgrade() {
+      Some(document) => document,
+      None => {
+        return Err(From::from(
+          "Namespace::new requires a Node bound to a Document",
+        ))
+      }
+    };
     unsafe {
       let ns = xmlNewNs(
         node.node_ptr_mut()?,
@@ -36,11 +79,20 @@ impl Namespace {
       if ns.is_null() {
         Err(From::from("xmlNewNs returned NULL"))
       } else {
-        Ok(Namespace { ns_ptr: ns })
+        Ok(Namespace::wrap(ns, &document))
       }
     }
   }
 
+  /// Wraps a declaration owned by a node of `document`
+  pub(crate) fn wrap(ns_ptr: xmlNsPtr, document: &DocumentRef) -> Self {
+    document.borrow_mut().hold_namespace(ns_ptr);
+    Namespace {
+      ns_ptr,
+      document: document.clone(),
+    }
+  }
+
   /// Immutably borrows the underlying libxml2 `xmlNsPtr` pointer
   pub fn ns_ptr(&self) -> xmlNsPtr {
     self.ns_ptr
@@ -76,8 +128,7 @@ impl Namespace {
     }
   }
 
-  /// Explicit free method, until (if?) we implement automatic+safe free-on-drop
-  pub fn free(&mut self) {
-    unsafe { xmlFreeNs(self.ns_ptr()) }
-  }
+  /// No-op, kept for compatibility: declarations are freed along with their `Document`
+  #[deprecated(note = "namespaces are freed along with their Document")]
+  pub fn free(&mut self) {}
 }
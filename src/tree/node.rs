@@ -1,42 +1,45 @@
 //! Node, and related, feature set
 //!
-use libc::{c_char, c_void};
+use libc::{c_char, c_int, c_void};
 use std::cell::RefCell;
 use std::collections::{HashMap, HashSet};
 use std::error::Error;
 use std::ffi::{CStr, CString};
 use std::hash::{Hash, Hasher};
-use std::mem;
 use std::ptr;
-use std::rc::Rc;
+use std::rc::{Rc, Weak};
 use std::str;
 
 use crate::bindings::*;
 use crate::c_helpers::*;
+use crate::css::{selector_to_xpath, SelectorError};
+use crate::tree::attribute::Attribute;
 use crate::tree::namespace::Namespace;
 use crate::tree::nodetype::NodeType;
 use crate::tree::{Document, DocumentRef, DocumentWeak};
-use crate::xpath::Context;
+use crate::xpath::{Context, XPathError, XPathErrorCode, XPathExpression};
 
-/// Guard treshold for enforcing runtime mutability checks for Nodes
-pub static mut NODE_RC_MAX_GUARD: usize = 2;
-
-/// Set the guard value for the max Rc "strong count" allowed for mutable use of a Node
-/// Default is 2
-pub fn set_node_rc_guard(value: usize) {
-  unsafe {
-    NODE_RC_MAX_GUARD = value;
-  }
-}
+/// Formerly set the max Rc "strong count" allowed for mutable use of a Node.
+/// Nodes referred to by handles are never freed by mutations, so any handle may mutate.
+#[deprecated(note = "shared nodes can always be mutated, the guard no longer exists")]
+pub fn set_node_rc_guard(_value: usize) {}
 
 type NodeRef = Rc<RefCell<_Node>>;
 
+/// Where `Node::insert_ptr` places a node, relative to an anchor node
+#[derive(Clone, Copy)]
+enum Insertion {
+  LastChild,
+  Before,
+  After,
+}
+
 #[derive(Debug)]
 struct _Node {
   /// libxml's xmlNodePtr
   node_ptr: xmlNodePtr,
-  /// Reference to parent `Document`
-  document: DocumentWeak,
+  /// Reference to parent `Document`, kept alive by its nodes
+  document: DocumentRef,
   /// Bookkeep removal from a parent
   unlinked: bool,
 }
@@ -45,6 +48,22 @@ struct _Node {
 #[derive(Clone, Debug)]
 pub struct Node(NodeRef);
 
+/// A weak reference to a `Node`, as kept by the bookkeeping of its document
+#[derive(Debug)]
+pub(crate) struct NodeWeak(Weak<RefCell<_Node>>);
+
+impl NodeWeak {
+  /// The `Node`, if any handle to it is left
+  pub(crate) fn upgrade(&self) -> Option<Node> {
+    self.0.upgrade().map(Node)
+  }
+
+  /// Checks if any handle to the `Node` is left
+  pub(crate) fn is_alive(&self) -> bool {
+    self.0.strong_count() > 0
+  }
+}
+
 impl Hash for Node {
   /// Generates a hash value from the `node_ptr` value.
   fn hash<H: Hasher>(&self, state: &mut H) {
@@ -62,16 +81,27 @@ impl PartialEq for Node {
 impl Eq for Node {}
 
 impl Drop for _Node {
-  /// Free node if it isn't bound in some document
-  /// Warning: xmlFreeNode is RECURSIVE into the node's children, so this may lead to segfaults if used carelessly
+  /// Release the bookkeeping of the node, and free it if it isn't bound in some document.
+  /// As xmlFreeNode is RECURSIVE into the node's children, the subtree is retained instead
+  /// while handles or XPath results still refer into it, until the last of them is dropped.
   fn drop(&mut self) {
+    let node_ptr = self.node_ptr;
+    if node_ptr.is_null() {
+      return;
+    }
+    let mut document = self.document.borrow_mut();
+    document.forget_node(node_ptr);
     if self.unlinked {
-      let node_ptr = self.node_ptr;
-      if !node_ptr.is_null() {
+      if document.is_referenced(node_ptr) {
+        document.retain_node(node_ptr);
+      } else {
+        document.forget_subtree(node_ptr);
         unsafe {
           xmlFreeNode(node_ptr);
         }
       }
+    } else {
+      document.release_retained_subtree(node_ptr);
     }
   }
 }
@@ -96,7 +126,7 @@ impl Node {
       if node.is_null() {
         Err(())
       } else {
-        Ok(Node::wrap(node, &doc.0))
+        Ok(Node::wrap_new(node, &doc.0))
       }
     }
   }
@@ -106,26 +136,16 @@ impl Node {
     self.0.borrow().node_ptr
   }
 
-  /// Mutably borrows the underlying libxml2 `xmlNodePtr` pointer
-  /// Also protects against mutability conflicts at runtime.
+  /// Mutably borrows the underlying libxml2 `xmlNodePtr` pointer.
+  /// Mutations never free nodes other handles, or XPath results, may still refer to:
+  /// such nodes are taken out of the tree instead, and kept until they are no longer referred to.
+  /// Fails for null nodes, including deleted ones.
   pub fn node_ptr_mut(&mut self) -> Result<xmlNodePtr, String> {
-    let weak_count = Rc::weak_count(&self.0);
-    let strong_count = Rc::strong_count(&self.0);
-
-    // The basic idea would be to use `Rc::get_mut` to guard against multiple borrows.
-    // However, our approach to bookkeeping nodes implies there is *always* a second Rc reference
-    // in the document.nodes Hash. So rather than use `get_mut` directly, the
-    // correct check would be to have a weak count of 0 and a strong count <=2 (one for self, one for .nodes)
-    let guard_ok = unsafe { weak_count == 0 && strong_count <= NODE_RC_MAX_GUARD };
-    if guard_ok {
-      Ok(self.0.borrow_mut().node_ptr)
+    let node_ptr = self.0.borrow_mut().node_ptr;
+    if node_ptr.is_null() {
+      Err(String::from("Can not mutate a null or deleted Node"))
     } else {
-      Err(format!(
-        "Can not mutably reference a shared Node {:?}! Rc: weak count: {:?}; strong count: {:?}",
-        self.get_name(),
-        weak_count,
-        strong_count,
-      ))
+      Ok(node_ptr)
     }
   }
 
@@ -133,21 +153,32 @@ impl Node {
   pub(crate) fn wrap(node_ptr: xmlNodePtr, document: &DocumentRef) -> Node {
     // If already seen, return saved Node
     if let Some(node) = document.borrow().get_node(node_ptr) {
-      return node.clone();
+      return node;
     }
-    // If newly encountered pointer, wrap
+    // If newly encountered pointer, wrap, taking over a retained subtree
+    let unlinked = document.borrow_mut().release_retained_node(node_ptr);
     let node = _Node {
       node_ptr,
-      document: Rc::downgrade(&document),
-      unlinked: false,
+      document: document.clone(),
+      unlinked,
     };
     let wrapped_node = Node(Rc::new(RefCell::new(node)));
-    document
-      .borrow_mut()
-      .insert_node(node_ptr, wrapped_node.clone());
+    document.borrow_mut().insert_node(node_ptr, &wrapped_node);
     wrapped_node
   }
 
+  /// Wrap a libxml node ptr outside of the tree, freed along with its last handle unless inserted
+  pub(crate) fn wrap_new(node_ptr: xmlNodePtr, document: &DocumentRef) -> Node {
+    let mut node = Node::wrap(node_ptr, document);
+    node.set_unlinked();
+    node
+  }
+
+  /// Weak reference for the bookkeeping table of the document
+  pub(crate) fn downgrade(&self) -> NodeWeak {
+    NodeWeak(Rc::downgrade(&self.0))
+  }
+
   /// Create a new text node, bound to a given document
   pub fn new_text(content: &str, doc: &Document) -> Result<Self, ()> {
     // We will only allow to work with document-bound nodes for now, to avoid the problems of memory management.
@@ -157,31 +188,120 @@ impl Node {
       if node.is_null() {
         Err(())
       } else {
-        Ok(Node::wrap(node, &doc.0))
+        Ok(Node::wrap_new(node, &doc.0))
+      }
+    }
+  }
+
+  /// Create a new comment node, bound to a given document
+  pub fn new_comment(content: &str, doc: &Document) -> Result<Self, ()> {
+    let c_content = CString::new(content).unwrap();
+    unsafe {
+      let node = xmlNewDocComment(doc.doc_ptr(), c_content.as_bytes().as_ptr());
+      if node.is_null() {
+        Err(())
+      } else {
+        Ok(Node::wrap_new(node, &doc.0))
+      }
+    }
+  }
+
+  /// Create a new CDATA section node, bound to a given document
+  pub fn new_cdata(content: &str, doc: &Document) -> Result<Self, ()> {
+    let c_content = CString::new(content).unwrap();
+    unsafe {
+      let node = xmlNewCDataBlock(
+        doc.doc_ptr(),
+        c_content.as_bytes().as_ptr(),
+        content.len() as c_int,
+      );
+      if node.is_null() {
+        Err(())
+      } else {
+        Ok(Node::wrap_new(node, &doc.0))
+      }
+    }
+  }
+
+  /// Create a new reference to the entity `name`, given with or without the `&` and `;` delimiters,
+  /// bound to a given document
+  pub fn new_entity_reference(name: &str, doc: &Document) -> Result<Self, ()> {
+    let c_name = CString::new(name).unwrap();
+    unsafe {
+      let node = xmlNewReference(doc.doc_ptr(), c_name.as_bytes().as_ptr());
+      if node.is_null() {
+        Err(())
+      } else {
+        Ok(Node::wrap_new(node, &doc.0))
+      }
+    }
+  }
+
+  /// Create a new document fragment node, bound to a given document
+  pub fn new_document_fragment(doc: &Document) -> Result<Self, ()> {
+    unsafe {
+      let node = xmlNewDocFragment(doc.doc_ptr());
+      if node.is_null() {
+        Err(())
+      } else {
+        // fragments are never part of the tree
+        Ok(Node::wrap_new(node, &doc.0))
       }
     }
   }
+
+  /// Copy this `Node` within its document. A `deep` copy includes the descendants,
+  /// a shallow one only the attributes and namespace declarations.
+  /// The copy is unlinked, until inserted into the tree.
+  pub fn clone_node(&self, deep: bool) -> Result<Node, ()> {
+    let doc_ref = self.get_docref().upgrade().ok_or(())?;
+    self.copy_into(&doc_ref, deep)
+  }
+
+  /// Copy this `Node` into another document, see `clone_node`.
+  /// Namespaces declared outside of the copied subtree are declared on the copy.
+  pub fn copy_to(&self, doc: &Document, deep: bool) -> Result<Node, ()> {
+    self.copy_into(&doc.0, deep)
+  }
+
+  fn copy_into(&self, doc_ref: &DocumentRef, deep: bool) -> Result<Node, ()> {
+    match self.get_type() {
+      None
+      | Some(NodeType::DocumentNode)
+      | Some(NodeType::HtmlDocumentNode)
+      | Some(NodeType::DTDNode) => return Err(()),
+      _ => {}
+    }
+    let recursive = if deep { 1 } else { 2 };
+    let copy_ptr = unsafe { xmlDocCopyNode(self.node_ptr(), doc_ref.borrow().doc_ptr, recursive) };
+    if copy_ptr.is_null() {
+      Err(())
+    } else {
+      Ok(Node::wrap_new(copy_ptr, doc_ref))
+    }
+  }
+
   /// Create a mock node, used for a placeholder argument
   pub fn mock(doc: &Document) -> Self {
-    Node::new("mock", None, &doc).unwrap()
+    Node::new("mock", None, doc).unwrap()
   }
 
   /// Create a mock node, used for a placeholder argument
   pub fn null() -> Self {
     Node(Rc::new(RefCell::new(_Node {
       node_ptr: ptr::null_mut(),
-      document: Rc::downgrade(&Document::null_ref()),
+      document: Document::null_ref(),
       unlinked: true,
     })))
   }
 
   /// `libc::c_void` isn't hashable and cannot be made hashable
   pub fn to_hashable(&self) -> usize {
-    unsafe { mem::transmute::<xmlNodePtr, usize>(self.node_ptr()) }
+    self.node_ptr() as usize
   }
 
   pub(crate) fn get_docref(&self) -> DocumentWeak {
-    self.0.borrow().document.clone()
+    Rc::downgrade(&self.0.borrow().document)
   }
 
   /// Returns the next sibling if it exists
@@ -262,27 +382,330 @@ impl Node {
     NodeType::from_int(xmlGetNodeType(self.node_ptr()))
   }
 
-  /// Add a previous sibling
-  pub fn add_prev_sibling(&mut self, new_sibling: &mut Node) -> Result<(), Box<Error>> {
-    new_sibling.set_linked();
-    unsafe {
-      if xmlAddPrevSibling(self.node_ptr_mut()?, new_sibling.node_ptr_mut()?).is_null() {
-        Err(From::from("add_prev_sibling returned NULL"))
-      } else {
-        Ok(())
+  /// Add a previous sibling.
+  /// Adding a document fragment moves all of its children in front of this `Node`.
+  pub fn add_prev_sibling(&mut self, new_sibling: &mut Node) -> Result<(), Box<dyn Error>> {
+    let anchor_ptr = self.node_ptr_mut()?;
+    self.add_prev_sibling_ptr(anchor_ptr, new_sibling, "add_prev_sibling")
+  }
+
+  /// Add a next sibling.
+  /// Adding a document fragment moves all of its children behind this `Node`.
+  pub fn add_next_sibling(&mut self, new_sibling: &mut Node) -> Result<(), Box<dyn Error>> {
+    let anchor_ptr = self.node_ptr_mut()?;
+    self.add_next_sibling_ptr(anchor_ptr, new_sibling, "add_next_sibling")
+  }
+
+  /// internal helper inserting `new_sibling`, or the children of a fragment, before `anchor_ptr`
+  fn add_prev_sibling_ptr(
+    &self,
+    anchor_ptr: xmlNodePtr,
+    new_sibling: &mut Node,
+    operation: &str,
+  ) -> Result<(), Box<dyn Error>> {
+    if new_sibling.get_type() == Some(NodeType::DocumentFragNode) {
+      for child_ptr in new_sibling.take_fragment_children()? {
+        self.insert_ptr(Insertion::Before, anchor_ptr, child_ptr, operation)?;
+      }
+      return Ok(());
+    }
+    let new_sibling_ptr = new_sibling.node_ptr_mut()?;
+    self.insert_ptr(Insertion::Before, anchor_ptr, new_sibling_ptr, operation)?;
+    Ok(())
+  }
+
+  /// internal helper inserting `new_sibling`, or the children of a fragment, after `anchor_ptr`
+  fn add_next_sibling_ptr(
+    &self,
+    mut anchor_ptr: xmlNodePtr,
+    new_sibling: &mut Node,
+    operation: &str,
+  ) -> Result<(), Box<dyn Error>> {
+    if new_sibling.get_type() == Some(NodeType::DocumentFragNode) {
+      for child_ptr in new_sibling.take_fragment_children()? {
+        anchor_ptr = self.insert_ptr(Insertion::After, anchor_ptr, child_ptr, operation)?;
       }
+      return Ok(());
     }
+    let new_sibling_ptr = new_sibling.node_ptr_mut()?;
+    self.insert_ptr(Insertion::After, anchor_ptr, new_sibling_ptr, operation)?;
+    Ok(())
   }
 
-  /// Add a next sibling
-  pub fn add_next_sibling(&mut self, new_sibling: &mut Node) -> Result<(), Box<Error>> {
-    new_sibling.set_linked();
-    unsafe {
-      if xmlAddNextSibling(self.node_ptr_mut()?, new_sibling.node_ptr_mut()?).is_null() {
-        Err(From::from("add_next_sibling returned NULL"))
-      } else {
-        Ok(())
+  /// internal helper detaching the children of this document fragment, in order
+  fn take_fragment_children(&mut self) -> Result<Vec<xmlNodePtr>, Box<dyn Error>> {
+    let fragment_ptr = self.node_ptr_mut()?;
+    let mut children = Vec::new();
+    let mut child_ptr = xmlGetFirstChild(fragment_ptr);
+    while !child_ptr.is_null() {
+      children.push(child_ptr);
+      child_ptr = xmlNextSibling(child_ptr);
+    }
+    for child_ptr in &children {
+      unsafe { xmlUnlinkNode(*child_ptr) };
+    }
+    Ok(children)
+  }
+
+  /// internal helper inserting `child_ptr` relative to `anchor_ptr`, returning the inserted node.
+  /// libxml2 frees text merged into an adjacent text node, and attributes replaced by
+  /// one of the same name, so referenced nodes are kept aside and a copy is merged instead.
+  fn insert_ptr(
+    &self,
+    insertion: Insertion,
+    anchor_ptr: xmlNodePtr,
+    child_ptr: xmlNodePtr,
+    operation: &str,
+  ) -> Result<xmlNodePtr, Box<dyn Error>> {
+    let doc_ref = self.get_docref().upgrade().unwrap();
+    let parent_ptr = match insertion {
+      Insertion::LastChild => anchor_ptr,
+      Insertion::Before | Insertion::After => xmlGetParent(anchor_ptr),
+    };
+    // libxml2 leaves moved attributes listed on their former element
+    unsafe { xmlUnlinkNode(child_ptr) };
+    let mut insert_ptr = child_ptr;
+    match NodeType::from_int(xmlGetNodeType(child_ptr)) {
+      Some(NodeType::TextNode) => {
+        let (prev_ptr, next_ptr) = match insertion {
+          Insertion::LastChild => (unsafe { xmlGetLastChild(anchor_ptr) }, anchor_ptr),
+          Insertion::Before => (xmlPrevSibling(anchor_ptr), anchor_ptr),
+          Insertion::After => (anchor_ptr, xmlNextSibling(anchor_ptr)),
+        };
+        let merges = [prev_ptr, next_ptr].iter().any(|&ptr| {
+          !ptr.is_null() && ptr != child_ptr && xmlGetNodeType(ptr) == xmlElementType_XML_TEXT_NODE
+        });
+        if merges && self.retain_detached(child_ptr) {
+          insert_ptr = unsafe { xmlDocCopyNode(child_ptr, doc_ref.borrow().doc_ptr, 1) };
+        }
+      }
+      Some(NodeType::AttributeNode) if !parent_ptr.is_null() => {
+        let ns_ptr = xmlNodeNs(child_ptr);
+        let href = if ns_ptr.is_null() {
+          ptr::null()
+        } else {
+          xmlNsHref(ns_ptr)
+        };
+        let existing_ptr = unsafe {
+          xmlHasNsProp(
+            parent_ptr,
+            xmlNodeGetName(child_ptr) as *const u8,
+            href as *const u8,
+          )
+        };
+        if !existing_ptr.is_null()
+          && existing_ptr as xmlNodePtr != child_ptr
+          && xmlGetNodeType(existing_ptr as xmlNodePtr) == xmlElementType_XML_ATTRIBUTE_NODE
+          && !self.retain_detached(existing_ptr as xmlNodePtr)
+        {
+          unsafe { xmlRemoveProp(existing_ptr) };
+        }
+      }
+      _ => {}
+    }
+    let new_ptr = unsafe {
+      match insertion {
+        Insertion::LastChild => xmlAddChild(anchor_ptr, insert_ptr),
+        Insertion::Before => xmlAddPrevSibling(anchor_ptr, insert_ptr),
+        Insertion::After => xmlAddNextSibling(anchor_ptr, insert_ptr),
+      }
+    };
+    if new_ptr.is_null() {
+      return Err(From::from(format!("{} returned NULL", operation)));
+    }
+    if new_ptr == child_ptr {
+      let inserted = doc_ref.borrow().get_node(child_ptr);
+      if let Some(mut inserted) = inserted {
+        inserted.set_linked();
+      }
+    }
+    Ok(new_ptr)
+  }
+
+  /// Inserts `new` as a child of this `Node`, in front of its child `reference`,
+  /// or as the last child when there is no `reference`
+  pub fn insert_before(
+    &mut self,
+    new: &mut Node,
+    reference: Option<&Node>,
+  ) -> Result<(), Box<dyn Error>> {
+    if self.is_self_or_descendant_of(new) {
+      return Err(From::from(
+        "insert_before can not insert a node into itself or its descendants",
+      ));
+    }
+    match reference {
+      None => self.add_child(new).map_err(From::from),
+      Some(reference) if reference == new => Ok(()),
+      Some(reference) => {
+        self.check_child(reference, "insert_before")?;
+        self.node_ptr_mut()?;
+        self.add_prev_sibling_ptr(reference.node_ptr(), new, "insert_before")
+      }
+    }
+  }
+
+  /// Inserts `new` as a child of this `Node`, behind its child `reference`,
+  /// or as the last child when there is no `reference`
+  pub fn insert_after(
+    &mut self,
+    new: &mut Node,
+    reference: Option<&Node>,
+  ) -> Result<(), Box<dyn Error>> {
+    if self.is_self_or_descendant_of(new) {
+      return Err(From::from(
+        "insert_after can not insert a node into itself or its descendants",
+      ));
+    }
+    match reference {
+      None => self.add_child(new).map_err(From::from),
+      Some(reference) if reference == new => Ok(()),
+      Some(reference) => {
+        self.check_child(reference, "insert_after")?;
+        self.node_ptr_mut()?;
+        self.add_next_sibling_ptr(reference.node_ptr(), new, "insert_after")
+      }
+    }
+  }
+
+  /// Adds `new` as the first child of this `Node`
+  pub fn prepend_child(&mut self, new: &mut Node) -> Result<(), Box<dyn Error>> {
+    let first_child = self.get_first_child();
+    self.insert_before(new, first_child.as_ref())
+  }
+
+  /// Puts `new` in the place of this `Node` in the tree, unlinking this `Node`.
+  /// Replacing with a document fragment puts all of its children in its place.
+  pub fn replace_with(&mut self, new: &mut Node) -> Result<(), Box<dyn Error>> {
+    if self == new {
+      return Ok(());
+    }
+    if self.is_self_or_descendant_of(new) {
+      return Err(From::from(
+        "replace_with can not replace a node with one of its ancestors",
+      ));
+    }
+    if self.get_parent().is_none() {
+      return Err(From::from("replace_with requires a node with a parent"));
+    }
+    if (self.get_type() == Some(NodeType::AttributeNode))
+      != (new.get_type() == Some(NodeType::AttributeNode))
+    {
+      return Err(From::from(
+        "replace_with can only replace attributes with attributes",
+      ));
+    }
+    let node_ptr = self.node_ptr_mut()?;
+    if new.get_type() == Some(NodeType::DocumentFragNode) {
+      // insert behind this node, which is never merged away, then take it out
+      self.add_next_sibling_ptr(node_ptr, new, "replace_with")?;
+    } else {
+      let new_ptr = new.node_ptr_mut()?;
+      if unsafe { xmlReplaceNode(node_ptr, new_ptr) }.is_null() {
+        return Err(From::from("xmlReplaceNode returned NULL"));
+      }
+      new.set_linked();
+    }
+    self.unlink_node();
+    Ok(())
+  }
+
+  /// Removes the `child` of this `Node` from the tree, returning it unlinked
+  pub fn remove_child(&mut self, mut child: Node) -> Result<Node, Box<dyn Error>> {
+    self.check_child(&child, "remove_child")?;
+    self.node_ptr_mut()?;
+    child.unlink_node();
+    Ok(child)
+  }
+
+  /// Removes all children of this `Node` from the tree, freeing those no handle refers to
+  pub fn remove_all_children(&mut self) -> Result<(), Box<dyn Error>> {
+    let node_ptr = self.node_ptr_mut()?;
+    let mut child_ptr = xmlGetFirstChild(node_ptr);
+    while !child_ptr.is_null() {
+      let next_ptr = xmlNextSibling(child_ptr);
+      self.discard_ptr(child_ptr);
+      child_ptr = next_ptr;
+    }
+    Ok(())
+  }
+
+  /// Puts the `element` in the place of this `Node`, then moves this `Node`
+  /// into it as its last child
+  pub fn wrap_in(&mut self, element: &mut Node) -> Result<(), Box<dyn Error>> {
+    if element.get_type() != Some(NodeType::ElementNode) {
+      return Err(From::from("wrap_in requires an element to wrap in"));
+    }
+    if self.get_type() == Some(NodeType::AttributeNode) {
+      return Err(From::from("wrap_in can not wrap an attribute"));
+    }
+    if self.is_self_or_descendant_of(element) {
+      return Err(From::from(
+        "wrap_in can not wrap a node in itself or its ancestors",
+      ));
+    }
+    if self.is_text_node()
+      && element
+        .get_last_child()
+        .is_some_and(|last_child| last_child.is_text_node())
+    {
+      // libxml2 would merge this node into the text
+      return Err(From::from("wrap_in can not append a text node to text"));
+    }
+    let node_ptr = self.node_ptr_mut()?;
+    let element_ptr = element.node_ptr_mut()?;
+    if !xmlGetParent(node_ptr).is_null() {
+      if unsafe { xmlReplaceNode(node_ptr, element_ptr) }.is_null() {
+        return Err(From::from("xmlReplaceNode returned NULL"));
+      }
+      element.set_linked();
+    }
+    self.insert_ptr(Insertion::LastChild, element_ptr, node_ptr, "wrap_in")?;
+    Ok(())
+  }
+
+  /// Replaces this element by its children, unlinking it
+  pub fn unwrap(&mut self) -> Result<(), Box<dyn Error>> {
+    if self.get_type() != Some(NodeType::ElementNode) {
+      return Err(From::from("unwrap requires an element"));
+    }
+    if self.get_parent().is_none() {
+      return Err(From::from("unwrap requires a node with a parent"));
+    }
+    let node_ptr = self.node_ptr_mut()?;
+    for child in self.get_child_nodes() {
+      let child_ptr = child.node_ptr();
+      unsafe { xmlUnlinkNode(child_ptr) };
+      self.insert_ptr(Insertion::Before, node_ptr, child_ptr, "unwrap")?;
+    }
+    self.unlink_node();
+    Ok(())
+  }
+
+  /// internal helper checking if `node` is this `Node` or one of its ancestors,
+  /// which can not be moved into this `Node`'s place or subtree
+  fn is_self_or_descendant_of(&self, node: &Node) -> bool {
+    let node_ptr = node.node_ptr();
+    let mut ancestor_ptr = self.node_ptr();
+    while !ancestor_ptr.is_null() {
+      if ancestor_ptr == node_ptr {
+        return true;
       }
+      ancestor_ptr = xmlGetParent(ancestor_ptr);
+    }
+    false
+  }
+
+  /// internal helper ensuring `child` is a child of this `Node`
+  fn check_child(&self, child: &Node, operation: &str) -> Result<(), Box<dyn Error>> {
+    if child.get_parent().as_ref() == Some(self) {
+      Ok(())
+    } else {
+      Err(From::from(format!(
+        "{} requires a child of {:?}",
+        operation,
+        self.get_name()
+      )))
     }
   }
 
@@ -291,6 +714,11 @@ impl Node {
     self.get_type() == Some(NodeType::TextNode)
   }
 
+  /// Checks if this is a text or CDATA node holding nothing but whitespace
+  pub fn is_blank(&self) -> bool {
+    unsafe { xmlIsBlankNode(self.node_ptr()) == 1 }
+  }
+
   /// Checks if the given node is an Element
   pub fn is_element_node(&self) -> bool {
     self.get_type() == Some(NodeType::ElementNode)
@@ -312,7 +740,7 @@ impl Node {
   }
 
   /// Sets the name of this `Node`
-  pub fn set_name(&mut self, name: &str) -> Result<(), Box<Error>> {
+  pub fn set_name(&mut self, name: &str) -> Result<(), Box<dyn Error>> {
     let c_name = CString::new(name).unwrap();
     unsafe { xmlNodeSetName(self.node_ptr_mut()?, c_name.as_bytes().as_ptr()) }
     Ok(())
@@ -335,9 +763,14 @@ impl Node {
   }
 
   /// Sets the text content of this `Node`
-  pub fn set_content(&mut self, content: &str) -> Result<(), Box<Error>> {
+  pub fn set_content(&mut self, content: &str) -> Result<(), Box<dyn Error>> {
     let c_content = CString::new(content).unwrap();
-    unsafe { xmlNodeSetContent(self.node_ptr_mut()?, c_content.as_bytes().as_ptr()) }
+    let node_ptr = self.node_ptr_mut()?;
+    let node_type = self.get_type();
+    if node_type == Some(NodeType::ElementNode) || node_type == Some(NodeType::AttributeNode) {
+      self.retain_value_nodes(node_ptr as xmlAttrPtr);
+    }
+    unsafe { xmlNodeSetContent(node_ptr, c_content.as_bytes().as_ptr()) }
     Ok(())
   }
 
@@ -389,12 +822,34 @@ impl Node {
   }
 
   /// Sets the value of property `name` to `value`
-  pub fn set_property(&mut self, name: &str, value: &str) -> Result<(), Box<Error>> {
+  pub fn set_property(&mut self, name: &str, value: &str) -> Result<(), Box<dyn Error>> {
     let c_name = CString::new(name).unwrap();
     let c_value = CString::new(value).unwrap();
+    let node_ptr = self.node_ptr_mut()?;
+    // retain the attribute xmlSetProp overwrites: a prefixed name in the namespace its prefix
+    // is bound to, otherwise the name without any namespace
+    let attr_ptr = unsafe {
+      let (c_local_name, ns_href) = match name.split_once(':') {
+        Some((prefix, local_name)) => {
+          let c_prefix = CString::new(prefix).unwrap();
+          let ns_ptr = xmlSearchNs(xmlGetDoc(node_ptr), node_ptr, c_prefix.as_bytes().as_ptr());
+          if ns_ptr.is_null() {
+            (c_name.clone(), ptr::null())
+          } else {
+            (
+              CString::new(local_name).unwrap(),
+              xmlNsHref(ns_ptr) as *const xmlChar,
+            )
+          }
+        }
+        None => (c_name.clone(), ptr::null()),
+      };
+      xmlHasNsProp(node_ptr, c_local_name.as_bytes().as_ptr(), ns_href)
+    };
+    self.retain_value_nodes(attr_ptr);
     unsafe {
       xmlSetProp(
-        self.node_ptr_mut()?,
+        node_ptr,
         c_name.as_bytes().as_ptr(),
         c_value.as_bytes().as_ptr(),
       )
@@ -407,12 +862,20 @@ impl Node {
     name: &str,
     value: &str,
     ns: &Namespace,
-  ) -> Result<(), Box<Error>> {
+  ) -> Result<(), Box<dyn Error>> {
     let c_name = CString::new(name).unwrap();
     let c_value = CString::new(value).unwrap();
+    let node_ptr = self.node_ptr_mut()?;
+    let href_ptr = if ns.ns_ptr().is_null() {
+      ptr::null()
+    } else {
+      xmlNsHref(ns.ns_ptr()) as *const u8
+    };
+    let attr_ptr = unsafe { xmlHasNsProp(node_ptr, c_name.as_bytes().as_ptr(), href_ptr) };
+    self.retain_value_nodes(attr_ptr);
     unsafe {
       xmlSetNsProp(
-        self.node_ptr_mut()?,
+        node_ptr,
         ns.ns_ptr(),
         c_name.as_bytes().as_ptr(),
         c_value.as_bytes().as_ptr(),
@@ -422,25 +885,47 @@ impl Node {
   }
 
   /// Removes the property of given `name`
-  pub fn remove_property(&mut self, name: &str) -> Result<(), Box<Error>> {
+  pub fn remove_property(&mut self, name: &str) -> Result<(), Box<dyn Error>> {
     let c_name = CString::new(name).unwrap();
-    unsafe {
-      let attr_node = xmlHasProp(self.node_ptr_mut()?, c_name.as_bytes().as_ptr());
-      if !attr_node.is_null() {
-        let remove_prop_status = xmlRemoveProp(attr_node);
-        if remove_prop_status == 0 {
-          Ok(())
-        } else {
-          // Propagate libxml2 failure to remove
-          Err(From::from(format!(
-            "libxml2 failed to remove property with status: {:?}",
-            remove_prop_status
-          )))
-        }
-      } else {
-        // silently no-op if asked to remove a property which is not present
-        Ok(())
-      }
+    let attr_node = unsafe { xmlHasProp(self.node_ptr_mut()?, c_name.as_bytes().as_ptr()) };
+    self.remove_property_ptr(attr_node)
+  }
+
+  /// Removes the property of given `name` and namespace `ns`
+  pub fn remove_property_ns(&mut self, name: &str, ns: &str) -> Result<(), Box<dyn Error>> {
+    let c_name = CString::new(name).unwrap();
+    let c_ns = CString::new(ns).unwrap();
+    let attr_node = unsafe {
+      xmlHasNsProp(
+        self.node_ptr_mut()?,
+        c_name.as_bytes().as_ptr(),
+        c_ns.as_bytes().as_ptr(),
+      )
+    };
+    self.remove_property_ptr(attr_node)
+  }
+
+  /// internal helper to remove an attribute of this node, found by `xmlHasProp` or `xmlHasNsProp`
+  fn remove_property_ptr(&self, attr_node: xmlAttrPtr) -> Result<(), Box<dyn Error>> {
+    // silently no-op if asked to remove a property which is not present,
+    // or only defaulted by the DTD
+    if attr_node.is_null()
+      || xmlGetNodeType(attr_node as xmlNodePtr) != xmlElementType_XML_ATTRIBUTE_NODE
+    {
+      return Ok(());
+    }
+    if self.retain_detached(attr_node as xmlNodePtr) {
+      return Ok(());
+    }
+    let remove_prop_status = unsafe { xmlRemoveProp(attr_node) };
+    if remove_prop_status == 0 {
+      Ok(())
+    } else {
+      // Propagate libxml2 failure to remove
+      Err(From::from(format!(
+        "libxml2 failed to remove property with status: {:?}",
+        remove_prop_status
+      )))
     }
   }
 
@@ -459,7 +944,7 @@ impl Node {
   }
 
   /// Alias for set_property
-  pub fn set_attribute(&mut self, name: &str, value: &str) -> Result<(), Box<Error>> {
+  pub fn set_attribute(&mut self, name: &str, value: &str) -> Result<(), Box<dyn Error>> {
     self.set_property(name, value)
   }
   /// Alias for set_property_ns
@@ -468,51 +953,108 @@ impl Node {
     name: &str,
     value: &str,
     ns: &Namespace,
-  ) -> Result<(), Box<Error>> {
+  ) -> Result<(), Box<dyn Error>> {
     self.set_property_ns(name, value, ns)
   }
 
   /// Alias for remove_property
-  pub fn remove_attribute(&mut self, name: &str) -> Result<(), Box<Error>> {
+  pub fn remove_attribute(&mut self, name: &str) -> Result<(), Box<dyn Error>> {
     self.remove_property(name)
   }
 
-  /// Get a copy of the attributes of this node
+  /// Alias for remove_property_ns
+  pub fn remove_attribute_ns(&mut self, name: &str, ns: &str) -> Result<(), Box<dyn Error>> {
+    self.remove_property_ns(name, ns)
+  }
+
+  /// Get a copy of the attributes of this node.
+  /// Attributes sharing a name in different namespaces collide, the first one is kept;
+  /// see `get_properties_ns`.
   pub fn get_properties(&self) -> HashMap<String, String> {
     let mut attributes = HashMap::new();
-    let mut attr_names = Vec::new();
-    unsafe {
-      let mut current_prop = xmlGetFirstProperty(self.node_ptr());
-      while !current_prop.is_null() {
-        let name_ptr = xmlAttrName(current_prop);
-        let c_name_string = CStr::from_ptr(name_ptr);
-        let name = c_name_string.to_string_lossy().into_owned();
-        attr_names.push(name);
-        current_prop = xmlNextPropertySibling(current_prop);
-      }
+    for (_, name, value) in self.property_values() {
+      attributes.entry(name).or_insert(value);
     }
+    attributes
+  }
 
-    for name in attr_names {
-      let value = self.get_property(&name).unwrap_or_default();
-      attributes.insert(name, value);
+  /// Get a copy of the attributes of this node, keyed by namespace URI and local name
+  pub fn get_properties_ns(&self) -> HashMap<(Option<String>, String), String> {
+    let mut attributes = HashMap::new();
+    for (ns_uri, name, value) in self.property_values() {
+      attributes.insert((ns_uri, name), value);
     }
-
     attributes
   }
 
+  /// internal helper listing the namespace URI, local name and value of each attribute
+  fn property_values(&self) -> Vec<(Option<String>, String, String)> {
+    let mut values = Vec::new();
+    if !self.is_element_node() {
+      return values;
+    }
+    let mut current_prop = xmlGetFirstProperty(self.node_ptr());
+    while !current_prop.is_null() {
+      unsafe {
+        let name = CStr::from_ptr(xmlAttrName(current_prop))
+          .to_string_lossy()
+          .into_owned();
+        let ns_ptr = xmlNodeNs(current_prop as xmlNodePtr);
+        let ns_uri = if ns_ptr.is_null() {
+          None
+        } else {
+          Some(
+            CStr::from_ptr(xmlNsHref(ns_ptr))
+              .to_string_lossy()
+              .into_owned(),
+          )
+        };
+        let value_ptr = xmlNodeGetContent(current_prop as xmlNodePtr);
+        let value = if value_ptr.is_null() {
+          String::new()
+        } else {
+          let value = CStr::from_ptr(value_ptr as *const c_char)
+            .to_string_lossy()
+            .into_owned();
+          libc::free(value_ptr as *mut c_void);
+          value
+        };
+        values.push((ns_uri, name, value));
+      }
+      current_prop = xmlNextPropertySibling(current_prop);
+    }
+    values
+  }
+
   /// Alias for `get_properties`
   pub fn get_attributes(&self) -> HashMap<String, String> {
     self.get_properties()
   }
 
+  /// Alias for `get_properties_ns`
+  pub fn get_attributes_ns(&self) -> HashMap<(Option<String>, String), String> {
+    self.get_properties_ns()
+  }
+
+  /// Returns the attributes of this element, in document order
+  pub fn attributes(&self) -> impl Iterator<Item = Attribute> {
+    let mut attributes = Vec::new();
+    if self.is_element_node() {
+      let mut current_prop = xmlGetFirstProperty(self.node_ptr());
+      while !current_prop.is_null() {
+        if let Some(node) = self.ptr_as_option(current_prop as xmlNodePtr) {
+          attributes.push(Attribute::wrap(node));
+        }
+        current_prop = xmlNextPropertySibling(current_prop);
+      }
+    }
+    attributes.into_iter()
+  }
+
   /// Gets the active namespace associated of this node
   pub fn get_namespace(&self) -> Option<Namespace> {
     let ns_ptr = xmlNodeNs(self.node_ptr());
-    if ns_ptr.is_null() {
-      None
-    } else {
-      Some(Namespace { ns_ptr })
-    }
+    self.ns_as_option(ns_ptr)
   }
 
   /// Gets a list of namespaces associated with this node
@@ -524,22 +1066,14 @@ impl Node {
       let mut namespaces = Vec::new();
       let mut ptr_iter = list_ptr_raw as *mut xmlNsPtr;
       unsafe {
-        while !ptr_iter.is_null() && !(*ptr_iter).is_null() {
-          namespaces.push(Namespace { ns_ptr: *ptr_iter });
+        while !(*ptr_iter).is_null() {
+          if let Some(ns) = self.ns_as_option(*ptr_iter) {
+            namespaces.push(ns);
+          }
           ptr_iter = ptr_iter.add(1);
         }
-        /* TODO: valgrind suggests this technique isn't sufficiently fluent:
-          ==114895== Conditional jump or move depends on uninitialised value(s)
-          ==114895==    at 0x4E9962F: xmlFreeNs (in /usr/lib/x86_64-linux-gnu/libxml2.so.2.9.4)
-          ==114895==    by 0x195CE8: libxml::tree::Node::get_namespaces (tree.rs:723)
-          ==114895==    by 0x12E7B6: base_tests::can_work_with_namespaces (base_tests.rs:537)
-
-          DG: I could not improve on this state without creating memory leaks after ~1 hour, so I am
-          marking it as future work.
-        */
-        /* TODO: How do we properly deallocate here? The approach bellow reliably segfaults tree_tests on 1 thread */
-        // println!("\n-- xmlfreens on : {:?}", list_ptr_raw);
-        // xmlFreeNs(list_ptr_raw as xmlNsPtr);
+        // the list is ours, the declarations it points to belong to the tree
+        libc::free(list_ptr_raw as *mut c_void);
       }
       namespaces
     }
@@ -555,7 +1089,7 @@ impl Node {
     let mut ns_ptr = xmlNodeNsDeclarations(self.node_ptr());
     while !ns_ptr.is_null() {
       if !xmlNsPrefix(ns_ptr).is_null() || !xmlNsHref(ns_ptr).is_null() {
-        namespaces.push(Namespace { ns_ptr });
+        namespaces.extend(self.ns_as_option(ns_ptr));
       }
       ns_ptr = xmlNextNsSibling(ns_ptr);
     }
@@ -563,13 +1097,167 @@ impl Node {
   }
 
   /// Sets a `Namespace` for the node
-  pub fn set_namespace(&mut self, namespace: &Namespace) -> Result<(), Box<Error>> {
+  pub fn set_namespace(&mut self, namespace: &Namespace) -> Result<(), Box<dyn Error>> {
     unsafe {
       xmlSetNs(self.node_ptr_mut()?, namespace.ns_ptr());
     }
     Ok(())
   }
 
+  /// Declares `prefix` for `href` on this element, changing the href of an existing declaration
+  /// of `prefix` in place. An empty `prefix` declares the default namespace.
+  /// If `activate` is true, the element is moved into the namespace as well.
+  pub fn set_namespace_declaration(
+    &mut self,
+    prefix: &str,
+    href: &str,
+    activate: bool,
+  ) -> Result<Namespace, Box<dyn Error>> {
+    let node_ptr = self.node_ptr_mut()?;
+    let c_href = CString::new(href).unwrap();
+    let ns = match self.find_namespace_declaration(prefix) {
+      Some(ns) => {
+        unsafe {
+          libc::free((*ns.ns_ptr()).href as *mut c_void);
+          (*ns.ns_ptr()).href = xmlStrdup(c_href.as_bytes().as_ptr());
+        }
+        ns
+      }
+      None => Namespace::new(prefix, href, self)?,
+    };
+    if activate {
+      unsafe { xmlSetNs(node_ptr, ns.ns_ptr()) };
+    }
+    Ok(ns)
+  }
+
+  /// Renames the prefix of the namespace declaration `old_prefix` on this element.
+  /// Elements and attributes using the declaration follow the new prefix.
+  pub fn set_namespace_declaration_prefix(
+    &mut self,
+    old_prefix: &str,
+    new_prefix: &str,
+  ) -> Result<(), Box<dyn Error>> {
+    self.node_ptr_mut()?;
+    let ns = match self.find_namespace_declaration(old_prefix) {
+      Some(ns) => ns,
+      None => return Err(From::from(format!("No declaration of {:?}", old_prefix))),
+    };
+    if old_prefix == new_prefix {
+      return Ok(());
+    }
+    if new_prefix.is_empty() || self.lookup_namespace_uri(new_prefix).is_some() {
+      return Err(From::from(format!(
+        "Prefix {:?} can not be declared here",
+        new_prefix
+      )));
+    }
+    let c_prefix = CString::new(new_prefix).unwrap();
+    unsafe {
+      libc::free((*ns.ns_ptr()).prefix as *mut c_void);
+      (*ns.ns_ptr()).prefix = xmlStrdup(c_prefix.as_bytes().as_ptr());
+    }
+    Ok(())
+  }
+
+  /// Removes the namespace declaration of `prefix` from this element.
+  /// Elements and attributes still using it switch to the same declaration of an ancestor;
+  /// if there is none, the declaration is kept and an error returned.
+  pub fn remove_namespace_declaration(&mut self, prefix: &str) -> Result<(), Box<dyn Error>> {
+    let node_ptr = self.node_ptr_mut()?;
+    let ns_ptr = match self.find_namespace_declaration(prefix) {
+      Some(ns) => ns.ns_ptr(),
+      None => return Ok(()),
+    };
+    let mut subtree = HashSet::new();
+    xmlCollectSubtree(node_ptr, &mut subtree);
+    let users: Vec<xmlNodePtr> = subtree
+      .into_iter()
+      .filter(|node| {
+        let node_type = xmlGetNodeType(*node);
+        (node_type == xmlElementType_XML_ELEMENT_NODE
+          || node_type == xmlElementType_XML_ATTRIBUTE_NODE)
+          && xmlNodeNs(*node) == ns_ptr
+      })
+      .collect();
+    if !users.is_empty() {
+      let parent = xmlGetParent(node_ptr);
+      let c_prefix = CString::new(prefix).unwrap();
+      let replacement =
+        if parent.is_null() || xmlGetNodeType(parent) != xmlElementType_XML_ELEMENT_NODE {
+          ptr::null_mut()
+        } else {
+          let c_prefix_ptr = if prefix.is_empty() {
+            ptr::null()
+          } else {
+            c_prefix.as_bytes().as_ptr()
+          };
+          unsafe { xmlSearchNs(xmlGetDoc(node_ptr), parent, c_prefix_ptr) }
+        };
+      if replacement.is_null() || unsafe { xmlStrEqual((*replacement).href, (*ns_ptr).href) } == 0 {
+        return Err(From::from(format!(
+          "Declaration of {:?} is still in use",
+          prefix
+        )));
+      }
+      for user in users {
+        unsafe { (*user).ns = replacement };
+      }
+    }
+    unsafe {
+      let mut cursor = &mut (*node_ptr).nsDef;
+      while *cursor != ns_ptr {
+        cursor = &mut (**cursor).next;
+      }
+      *cursor = (*ns_ptr).next;
+      (*ns_ptr).next = ptr::null_mut();
+    }
+    // detached nodes and `Namespace`s may still point to the declaration
+    let doc_ref = self.get_docref().upgrade().unwrap();
+    doc_ref.borrow_mut().retire_namespaces(ns_ptr);
+    Ok(())
+  }
+
+  /// Moves this element into the namespace `prefix` is bound to in its scope.
+  /// An empty `prefix` selects the default namespace.
+  pub fn set_namespace_by_prefix(&mut self, prefix: &str) -> Result<(), Box<dyn Error>> {
+    let node_ptr = self.node_ptr_mut()?;
+    let c_prefix = CString::new(prefix).unwrap();
+    let c_prefix_ptr = if prefix.is_empty() {
+      ptr::null()
+    } else {
+      c_prefix.as_bytes().as_ptr()
+    };
+    let ns_ptr = unsafe { xmlSearchNs(xmlGetDoc(node_ptr), node_ptr, c_prefix_ptr) };
+    if ns_ptr.is_null() {
+      Err(From::from(format!("Prefix {:?} is not declared", prefix)))
+    } else {
+      unsafe { xmlSetNs(node_ptr, ns_ptr) };
+      Ok(())
+    }
+  }
+
+  /// internal helper finding the declaration of `prefix` on this element, the default one if empty
+  fn find_namespace_declaration(&self, prefix: &str) -> Option<Namespace> {
+    if !self.is_element_node() {
+      return None;
+    }
+    let mut ns_ptr = xmlNodeNsDeclarations(self.node_ptr());
+    while !ns_ptr.is_null() {
+      let ns_prefix = xmlNsPrefix(ns_ptr);
+      let matches = if ns_prefix.is_null() {
+        prefix.is_empty()
+      } else {
+        unsafe { CStr::from_ptr(ns_prefix) }.to_bytes() == prefix.as_bytes()
+      };
+      if matches {
+        return self.ns_as_option(ns_ptr);
+      }
+      ns_ptr = xmlNextNsSibling(ns_ptr);
+    }
+    None
+  }
+
   /// Looks up the prefix of a namespace from its URI, basedo around a given `Node`
   pub fn lookup_namespace_prefix(&self, href: &str) -> Option<String> {
     if href.is_empty() {
@@ -580,7 +1268,7 @@ impl Node {
       let ptr_mut = self.node_ptr();
       let ns_ptr = xmlSearchNsByHref(xmlGetDoc(ptr_mut), ptr_mut, c_href.as_bytes().as_ptr());
       if !ns_ptr.is_null() {
-        let ns = Namespace { ns_ptr };
+        let ns = Namespace::wrap(ns_ptr, &self.get_docref().upgrade().unwrap());
         let ns_prefix = ns.get_prefix();
         Some(ns_prefix)
       } else {
@@ -602,7 +1290,7 @@ impl Node {
         c_prefix.as_bytes().as_ptr(),
       );
       if !ns_ptr.is_null() {
-        let ns = Namespace { ns_ptr };
+        let ns = Namespace::wrap(ns_ptr, &self.get_docref().upgrade().unwrap());
         let ns_prefix = ns.get_href();
         if !ns_prefix.is_empty() {
           Some(ns_prefix)
@@ -615,13 +1303,41 @@ impl Node {
     }
   }
 
+  /// Declares every namespace used within this subtree but not in scope, e.g. after it was
+  /// moved to another parent or document. Missing declarations are added to this `Node`.
+  pub fn reconcile_namespaces(&mut self) -> Result<(), Box<dyn Error>> {
+    let node_ptr = self.node_ptr_mut()?;
+    let status = unsafe { xmlReconciliateNs(xmlGetDoc(node_ptr), node_ptr) };
+    if status < 0 {
+      Err(From::from("xmlReconciliateNs failed"))
+    } else {
+      Ok(())
+    }
+  }
+
   // TODO: Clear a future Document namespaces vec
   /// Removes the namespaces of this `Node` and it's children!
-  pub fn recursively_remove_namespaces(&mut self) -> Result<(), Box<Error>> {
-    xmlNodeRecursivelyRemoveNs(self.node_ptr_mut()?);
+  pub fn recursively_remove_namespaces(&mut self) -> Result<(), Box<dyn Error>> {
+    let mut removed = Vec::new();
+    xmlNodeRecursivelyRemoveNs(self.node_ptr_mut()?, &mut removed);
+    self.release_namespaces(removed);
     Ok(())
   }
 
+  /// internal helper to free namespace declaration lists removed from the tree,
+  /// unless `Namespace`s or XPath results may still refer to them
+  fn release_namespaces(&self, ns_lists: Vec<xmlNsPtr>) {
+    let doc_ref = self.get_docref().upgrade().unwrap();
+    let mut doc = doc_ref.borrow_mut();
+    for ns_list in ns_lists {
+      if doc.retains_nodes() || doc.has_live_namespace(ns_list) {
+        doc.retire_namespaces(ns_list);
+      } else {
+        unsafe { xmlFreeNsList(ns_list) };
+      }
+    }
+  }
+
   /// Get a set of class names from this node's attributes
   pub fn get_class_names(&self) -> HashSet<String> {
     let mut set = HashSet::new();
@@ -633,21 +1349,28 @@ impl Node {
     set
   }
 
-  /// Creates a new `Node` as child to the self `Node`
+  /// Adds `child` as the last child of the self `Node`.
+  /// Adding a document fragment moves all of its children, in one operation.
   pub fn add_child(&mut self, child: &mut Node) -> Result<(), String> {
-    child.set_linked();
-    unsafe {
-      let new_child_ptr = xmlAddChild(self.node_ptr_mut()?, child.node_ptr_mut()?);
-      if new_child_ptr.is_null() {
-        Err("add_child encountered NULL pointer".to_string())
-      } else {
-        Ok(())
+    let node_ptr = self.node_ptr_mut()?;
+    if child.get_type() == Some(NodeType::DocumentFragNode) {
+      let children = child.take_fragment_children().map_err(|e| e.to_string())?;
+      for child_ptr in children {
+        self
+          .insert_ptr(Insertion::LastChild, node_ptr, child_ptr, "add_child")
+          .map_err(|e| e.to_string())?;
       }
+      return Ok(());
     }
+    let child_ptr = child.node_ptr_mut()?;
+    self
+      .insert_ptr(Insertion::LastChild, node_ptr, child_ptr, "add_child")
+      .map_err(|e| e.to_string())?;
+    Ok(())
   }
 
   /// Creates a new `Node` as child to the self `Node`
-  pub fn new_child(&mut self, ns: Option<Namespace>, name: &str) -> Result<Node, Box<Error>> {
+  pub fn new_child(&mut self, ns: Option<Namespace>, name: &str) -> Result<Node, Box<dyn Error>> {
     let c_name = CString::new(name).unwrap();
     let ns_ptr = match ns {
       None => ptr::null_mut(),
@@ -670,7 +1393,7 @@ impl Node {
     ns: Option<Namespace>,
     name: &str,
     content: &str,
-  ) -> Result<Node, Box<Error>> {
+  ) -> Result<Node, Box<dyn Error>> {
     let c_name = CString::new(name).unwrap();
     let c_content = CString::new(content).unwrap();
     let ns_ptr = match ns {
@@ -689,7 +1412,7 @@ impl Node {
   }
 
   /// Append text to this `Node`
-  pub fn append_text(&mut self, content: &str) -> Result<(), Box<Error>> {
+  pub fn append_text(&mut self, content: &str) -> Result<(), Box<dyn Error>> {
     let c_len = content.len() as i32;
     if c_len > 0 {
       let c_content = CString::new(content).unwrap();
@@ -700,10 +1423,85 @@ impl Node {
     Ok(())
   }
 
+  /// Splits this text or CDATA node at the character `offset`: it keeps the text in front of it,
+  /// while the rest moves to a new node of the same type, inserted behind it and returned.
+  pub fn split_text(&mut self, offset: usize) -> Result<Node, Box<dyn Error>> {
+    let node_ptr = self.node_ptr_mut()?;
+    let node_type = self.get_type();
+    if node_type != Some(NodeType::TextNode) && node_type != Some(NodeType::CDataSectionNode) {
+      return Err(From::from("split_text requires a text or CDATA node"));
+    }
+    let content = self.get_content();
+    let char_count = content.chars().count();
+    if offset > char_count {
+      return Err(From::from(format!(
+        "split_text offset {} is beyond the {} characters of the node",
+        offset, char_count
+      )));
+    }
+    let split_at = content
+      .char_indices()
+      .nth(offset)
+      .map_or(content.len(), |(index, _)| index);
+    let (head, tail) = content.split_at(split_at);
+
+    let doc_ref = self.get_docref().upgrade().unwrap();
+    let doc_ptr = doc_ref.borrow().doc_ptr;
+    let c_head = CString::new(head).unwrap();
+    let c_tail = CString::new(tail).unwrap();
+    let tail_ptr = unsafe {
+      if node_type == Some(NodeType::CDataSectionNode) {
+        xmlNewCDataBlock(doc_ptr, c_tail.as_bytes().as_ptr(), tail.len() as c_int)
+      } else {
+        xmlNewDocText(doc_ptr, c_tail.as_bytes().as_ptr())
+      }
+    };
+    if tail_ptr.is_null() {
+      return Err(From::from("split_text failed to create a node"));
+    }
+    unsafe { xmlNodeSetContent(node_ptr, c_head.as_bytes().as_ptr()) };
+    if xmlGetParent(node_ptr).is_null() {
+      Ok(Node::wrap_new(tail_ptr, &doc_ref))
+    } else {
+      // xmlAddNextSibling would merge the text right back
+      xmlLinkNextSibling(node_ptr, tail_ptr);
+      Ok(Node::wrap(tail_ptr, &doc_ref))
+    }
+  }
+
+  /// Merges adjacent text nodes and removes empty ones, in the subtree of this `Node`,
+  /// after the DOM's `normalize()`
+  pub fn normalize(&mut self) -> Result<(), Box<dyn Error>> {
+    let mut pending = vec![self.node_ptr_mut()?];
+    while let Some(parent_ptr) = pending.pop() {
+      let mut child_ptr = xmlGetFirstChild(parent_ptr);
+      while !child_ptr.is_null() {
+        let next_ptr = xmlNextSibling(child_ptr);
+        let child_type = xmlGetNodeType(child_ptr);
+        if child_type == xmlElementType_XML_ELEMENT_NODE {
+          pending.push(child_ptr);
+        } else if child_type == xmlElementType_XML_TEXT_NODE {
+          let prev_ptr = xmlPrevSibling(child_ptr);
+          let content_ptr = unsafe { xmlNodeGetContent(child_ptr) };
+          if !prev_ptr.is_null() && xmlGetNodeType(prev_ptr) == xmlElementType_XML_TEXT_NODE {
+            unsafe { xmlNodeAddContent(prev_ptr, content_ptr) };
+            self.discard_ptr(child_ptr);
+          } else if content_ptr.is_null() || unsafe { *content_ptr } == 0 {
+            self.discard_ptr(child_ptr);
+          }
+          unsafe { libc::free(content_ptr as *mut c_void) };
+        }
+        child_ptr = next_ptr;
+      }
+    }
+    Ok(())
+  }
+
   /// Unbinds the Node from its siblings and Parent, but not from the Document it belongs to.
-  ///   If the node is not inserted into the DOM afterwards, it will be lost after the program terminates.
-  ///   From a low level view, the unbound node is stripped
-  ///   from the context it is and inserted into a (hidden) document-fragment.
+  ///   If the node is not inserted into the DOM afterwards, it is freed when its last handle drops,
+  ///   unless an XPath result or another handle into its subtree still references it.
+  ///   Unlike in XML::LibXML, the unbound node is not moved into a hidden document-fragment,
+  ///   but stands on its own; to group unbound nodes, add them to a `DocumentFragment`.
   pub fn unlink_node(&mut self) {
     let node_type = self.get_type();
     if node_type != Some(NodeType::DocumentNode)
@@ -730,6 +1528,37 @@ impl Node {
     self.unlink_node()
   }
 
+  /// Unlinks this `Node` and frees it, along with its subtree, right away.
+  /// All handles to nodes of the subtree become null: they report `is_null`, and fail to mutate.
+  /// While XPath results of the document are alive, the memory is only freed once they are gone.
+  pub fn delete(&mut self) -> Result<(), Box<dyn Error>> {
+    let node_ptr = self.node_ptr_mut()?;
+    match self.get_type() {
+      None | Some(NodeType::DocumentNode) | Some(NodeType::HtmlDocumentNode) => {
+        return Err(From::from("Can not delete a document node"))
+      }
+      _ => {}
+    }
+    let doc_ref = self.get_docref().upgrade().unwrap();
+    let mut subtree = HashSet::new();
+    xmlCollectSubtree(node_ptr, &mut subtree);
+    for &subtree_ptr in &subtree {
+      let node = doc_ref.borrow().get_node(subtree_ptr);
+      if let Some(node) = node {
+        node.invalidate();
+      }
+    }
+    let mut document = doc_ref.borrow_mut();
+    document.forget_subtree(node_ptr);
+    unsafe { xmlUnlinkNode(node_ptr) };
+    document.free_deleted_node(node_ptr, subtree);
+    Ok(())
+  }
+  /// Alias for `delete`
+  pub fn remove(&mut self) -> Result<(), Box<dyn Error>> {
+    self.delete()
+  }
+
   /// Checks if node is marked as unlinked
   pub fn is_unlinked(&self) -> bool {
     self.0.borrow().unlinked
@@ -745,8 +1574,17 @@ impl Node {
     }
   }
 
+  fn ns_as_option(&self, ns_ptr: xmlNsPtr) -> Option<Namespace> {
+    if ns_ptr.is_null() {
+      None
+    } else {
+      let doc_ref = self.get_docref().upgrade().unwrap();
+      Some(Namespace::wrap(ns_ptr, &doc_ref))
+    }
+  }
+
   /// internal helper to ensure the node is marked as linked/imported/adopted in the main document tree
-  fn set_linked(&mut self) {
+  pub(crate) fn set_linked(&mut self) {
     self.0.borrow_mut().unlinked = false;
   }
 
@@ -755,15 +1593,131 @@ impl Node {
     self.0.borrow_mut().unlinked = true;
   }
 
+  /// internal helper turning a handle to a node about to be freed into a null one
+  fn invalidate(&self) {
+    let mut node = self.0.borrow_mut();
+    node.node_ptr = ptr::null_mut();
+    node.unlinked = false;
+  }
+
+  /// internal helper to bind the node to the document it was moved to
+  pub(crate) fn rebind(&self, document: &DocumentRef) {
+    self.0.borrow_mut().document = document.clone();
+  }
+
+  /// Unlinks a node about to be freed by libxml2 and keeps it instead, while handles
+  /// or XPath results may still refer into its subtree. Returns whether it was retained;
+  /// otherwise the bookkeeping of the subtree is dropped, for libxml2 to free it.
+  pub(crate) fn retain_detached(&self, node_ptr: xmlNodePtr) -> bool {
+    let doc_ref = self.get_docref().upgrade().unwrap();
+    if !doc_ref.borrow().is_referenced(node_ptr) {
+      doc_ref.borrow_mut().forget_subtree(node_ptr);
+      return false;
+    }
+    unsafe {
+      xmlUnlinkNode(node_ptr);
+    }
+    Node::wrap_new(node_ptr, &doc_ref);
+    true
+  }
+
+  /// internal helper taking `node_ptr` out of the tree, freeing it unless still referred to
+  pub(crate) fn discard_ptr(&self, node_ptr: xmlNodePtr) {
+    if !self.retain_detached(node_ptr) {
+      unsafe {
+        xmlUnlinkNode(node_ptr);
+        xmlFreeNode(node_ptr);
+      }
+    }
+  }
+
+  /// internal helper retaining the children of an element or attribute,
+  /// before libxml2 replaces them with a new value
+  fn retain_value_nodes(&self, attr_ptr: xmlAttrPtr) {
+    if attr_ptr.is_null() {
+      return;
+    }
+    let mut child_ptr = xmlGetFirstChild(attr_ptr as xmlNodePtr);
+    while !child_ptr.is_null() {
+      let next_ptr = xmlNextSibling(child_ptr);
+      self.retain_detached(child_ptr);
+      child_ptr = next_ptr;
+    }
+  }
+
   /// find nodes via xpath, at a specified node or the document root
-  pub fn findnodes(&self, xpath: &str) -> Result<Vec<Node>, ()> {
-    let mut context = Context::from_node(&self)?;
+  pub fn findnodes(&self, xpath: &str) -> Result<Vec<Node>, XPathError> {
+    let mut context = self.xpath_context(Some(xpath), &[])?;
     context.findnodes(xpath, Some(self))
   }
 
+  /// find nodes via a compiled xpath, at this node
+  pub fn findnodes_compiled(&self, expression: &XPathExpression) -> Result<Vec<Node>, XPathError> {
+    let mut context = self.xpath_context(Some(expression.as_str()), &[])?;
+    context.findnodes_compiled(expression, Some(self))
+  }
+
+  /// find a literal value via xpath, at this node.
+  /// `namespaces` lists the prefix-href pairs used in the expression.
+  pub fn findvalue(&self, xpath: &str, namespaces: &[(&str, &str)]) -> Result<String, XPathError> {
+    let mut context = self.xpath_context(Some(xpath), namespaces)?;
+    context.findvalue(xpath, Some(self))
+  }
+
+  /// find the first node via xpath, at this node
+  pub fn find_first(
+    &self,
+    xpath: &str,
+    namespaces: &[(&str, &str)],
+  ) -> Result<Option<Node>, XPathError> {
+    let mut context = self.xpath_context(Some(xpath), namespaces)?;
+    context.find_first(xpath, Some(self))
+  }
+
+  /// find a number via xpath, at this node
+  pub fn find_number(&self, xpath: &str, namespaces: &[(&str, &str)]) -> Result<f64, XPathError> {
+    let mut context = self.xpath_context(Some(xpath), namespaces)?;
+    context.find_number(xpath, Some(self))
+  }
+
+  /// find a boolean via xpath, at this node
+  pub fn find_bool(&self, xpath: &str, namespaces: &[(&str, &str)]) -> Result<bool, XPathError> {
+    let mut context = self.xpath_context(Some(xpath), namespaces)?;
+    context.find_bool(xpath, Some(self))
+  }
+
+  /// check if an xpath matches anything, at this node
+  pub fn exists(&self, xpath: &str, namespaces: &[(&str, &str)]) -> Result<bool, XPathError> {
+    let mut context = self.xpath_context(Some(xpath), namespaces)?;
+    context.exists(xpath, Some(self))
+  }
+
+  /// find the descendants of this node matching a CSS selector.
+  /// Type and attribute selectors match case-insensitively in HTML documents.
+  pub fn select(&self, selector: &str) -> Result<Vec<Node>, SelectorError> {
+    let html = self
+      .get_docref()
+      .upgrade()
+      .is_some_and(|document| document.borrow().is_html());
+    let xpath = selector_to_xpath(selector, html)?;
+    Ok(self.findnodes(&xpath)?)
+  }
+
+  /// internal helper to set up an xpath context for this node's document
+  fn xpath_context(
+    &self,
+    xpath: Option<&str>,
+    namespaces: &[(&str, &str)],
+  ) -> Result<Context, XPathError> {
+    let context =
+      Context::from_node(self).map_err(|_| XPathError::new(XPathErrorCode::InvalidCtxt, xpath))?;
+    context.register_namespaces(namespaces)?;
+    Ok(context)
+  }
+
   /// replace a `self`'s `old` child node with a `new` node in the same position
   /// borrowed from Perl's XML::LibXML
-  pub fn replace_child_node(&mut self, mut new: Node, mut old: Node) -> Result<Node, Box<Error>> {
+  pub fn replace_child_node(&mut self, mut new: Node, mut old: Node) -> Result<Node, Box<dyn Error>> {
     // if newNode == oldNode or self == newNode then do nothing, just return nNode.
     if new == old || self == &new {
       // nothing to do here, already in place
@@ -2,29 +2,435 @@
 
 use crate::bindings::*;
 use crate::c_helpers::*;
-use crate::tree::{Document, DocumentRef, DocumentWeak, Node};
+use crate::tree::{Attribute, Document, DocumentRef, DocumentWeak, Namespace, Node, NodeType};
 use libc;
-use libc::{c_char, c_void, size_t};
+use libc::{c_char, c_int, c_void, size_t};
 use std::cell::RefCell;
+use std::collections::HashMap;
+use std::error::Error;
 use std::ffi::{CStr, CString};
+use std::fmt;
+use std::mem;
+use std::ptr;
 use std::rc::Rc;
 use std::str;
 
 ///Thinly wrapped libxml2 xpath context
 pub(crate) type ContextRef = Rc<RefCell<_Context>>;
 
-#[derive(Debug)]
-pub(crate) struct _Context(pub(crate) xmlXPathContextPtr);
+/// Signature of a user-supplied variable lookup callback,
+/// receiving the variable name and its optional namespace URI
+type VariableLookupFn = dyn Fn(&str, Option<&str>) -> Option<Value>;
+
+/// Callback data handed to libxml2 for variable lookups
+struct VariableLookup {
+  /// the context the callback is registered on, used to fall back to registered variables
+  context: xmlXPathContextPtr,
+  /// the user-supplied lookup
+  lookup: Box<VariableLookupFn>,
+  /// keeps the nodes of looked up values alive until the evaluation result takes them over
+  returned_nodes: ReturnedNodes,
+}
+
+/// Signature of a user-supplied XPath extension function,
+/// receiving the evaluated arguments in call order
+type XPathFunction = dyn Fn(Vec<Value>) -> Result<Value, ()>;
+
+/// Callback data handed to libxml2 for extension function calls
+struct FunctionTable {
+  /// owner document of the context, needed to wrap node-set arguments
+  document: DocumentWeak,
+  /// registered functions, keyed by name and optional namespace URI
+  functions: HashMap<(String, Option<String>), Rc<XPathFunction>>,
+  /// keeps the nodes of returned values alive until the evaluation result takes them over
+  returned_nodes: ReturnedNodes,
+}
+
+/// Nodes handed to libxml2 by callbacks during an evaluation, which may be their only handles
+type ReturnedNodes = Rc<RefCell<Vec<Node>>>;
+
+/// Keeps the nodes of a value returned by a callback alive, as its node-set only holds pointers
+fn retain_returned_nodes(returned_nodes: &ReturnedNodes, value: Value) {
+  if let Value::Nodes(nodes) = value {
+    returned_nodes.borrow_mut().extend(nodes);
+  }
+}
+
+/// Nodes bound to a variable, which count as references into their documents,
+/// so that they are neither freed nor deleted while the binding refers to them
+struct BoundNodes(Vec<(Node, DocumentRef)>);
+
+impl BoundNodes {
+  fn new(nodes: Vec<Node>) -> Self {
+    let mut bound = Vec::with_capacity(nodes.len());
+    for node in nodes {
+      if let Some(document) = node.get_docref().upgrade() {
+        document.borrow_mut().hold_reference();
+        bound.push((node, document));
+      }
+    }
+    BoundNodes(bound)
+  }
+}
+
+impl Drop for BoundNodes {
+  fn drop(&mut self) {
+    for (_, document) in &self.0 {
+      document.borrow_mut().release_reference();
+    }
+  }
+}
+
+pub(crate) struct _Context {
+  /// libxml's `xmlXPathContextPtr`
+  pub(crate) ptr: xmlXPathContextPtr,
+  /// Owns the variable lookup callback data, for as long as libxml2 may call it
+  variable_lookup: Option<Box<VariableLookup>>,
+  /// Owns the extension functions, for as long as libxml2 may call them
+  functions: Option<Box<FunctionTable>>,
+  /// Nodes bound to variables, keyed by name and optional namespace URI
+  bound_nodes: HashMap<(String, Option<String>), BoundNodes>,
+  /// Nodes returned by callbacks during the current evaluation
+  returned_nodes: ReturnedNodes,
+}
+
+impl _Context {
+  fn new(ptr: xmlXPathContextPtr) -> Self {
+    _Context {
+      ptr,
+      variable_lookup: None,
+      functions: None,
+      bound_nodes: HashMap::new(),
+      returned_nodes: Rc::new(RefCell::new(Vec::new())),
+    }
+  }
+}
 
 impl Drop for _Context {
   ///free xpath context when it goes out of scope
   fn drop(&mut self) {
     unsafe {
-      xmlXPathFreeContext(self.0);
+      xmlXPathFreeContext(self.ptr);
     }
   }
 }
 
+/// A typed XPath value, as bound to variables and passed to and from extension functions
+#[derive(Clone, Debug, PartialEq)]
+pub enum Value {
+  /// An XPath string
+  String(String),
+  /// An XPath number
+  Number(f64),
+  /// An XPath boolean
+  Boolean(bool),
+  /// An XPath node-set
+  Nodes(Vec<Node>),
+}
+
+impl Value {
+  /// Builds a freshly allocated libxml2 XPath object holding this value.
+  /// Ownership of the object is passed on to the caller.
+  /// Fails for strings containing a NUL character, which libxml2 can not represent.
+  pub(crate) fn to_object_ptr(&self) -> Result<xmlXPathObjectPtr, ()> {
+    unsafe {
+      Ok(match *self {
+        Value::String(ref s) => {
+          let c_string = CString::new(s.as_str()).map_err(|_| ())?;
+          xmlXPathNewString(c_string.as_bytes().as_ptr())
+        }
+        Value::Number(n) => xmlXPathNewFloat(n),
+        Value::Boolean(b) => xmlXPathNewBoolean(b as i32),
+        Value::Nodes(ref nodes) => {
+          let ptr = xmlXPathNewNodeSet(ptr::null_mut());
+          if !ptr.is_null() {
+            for node in nodes {
+              xmlXPathNodeSetAdd((*ptr).nodesetval, node.node_ptr());
+            }
+          }
+          ptr
+        }
+      })
+    }
+  }
+}
+
+impl<'a> From<&'a str> for Value {
+  fn from(s: &'a str) -> Self {
+    Value::String(s.to_owned())
+  }
+}
+impl From<String> for Value {
+  fn from(s: String) -> Self {
+    Value::String(s)
+  }
+}
+impl From<f64> for Value {
+  fn from(n: f64) -> Self {
+    Value::Number(n)
+  }
+}
+impl From<bool> for Value {
+  fn from(b: bool) -> Self {
+    Value::Boolean(b)
+  }
+}
+impl From<Node> for Value {
+  fn from(node: Node) -> Self {
+    Value::Nodes(vec![node])
+  }
+}
+impl From<Vec<Node>> for Value {
+  fn from(nodes: Vec<Node>) -> Self {
+    Value::Nodes(nodes)
+  }
+}
+
+/// Kinds of XPath failures, following libxml2's `xmlXPathError`
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[allow(missing_docs)]
+pub enum XPathErrorCode {
+  NumberError,
+  UnfinishedLiteralError,
+  StartLiteralError,
+  VariableRefError,
+  UndefVariableError,
+  InvalidPredicateError,
+  ExprError,
+  UnclosedError,
+  UnknownFuncError,
+  InvalidOperand,
+  InvalidType,
+  InvalidArity,
+  InvalidCtxtSize,
+  InvalidCtxtPosition,
+  MemoryError,
+  XPtrSyntaxError,
+  XPtrResourceError,
+  XPtrSubResourceError,
+  UndefPrefixError,
+  EncodingError,
+  InvalidCharError,
+  InvalidCtxt,
+  StackError,
+  ForbidVariableError,
+  /// The evaluation failed without libxml2 recording a reason
+  Unknown,
+}
+
+impl XPathErrorCode {
+  /// converts an integer from libxml's `enum xmlXPathError`
+  /// to an instance of our `XPathErrorCode`
+  pub fn from_int(i: u32) -> XPathErrorCode {
+    match i {
+      1 => XPathErrorCode::NumberError,
+      2 => XPathErrorCode::UnfinishedLiteralError,
+      3 => XPathErrorCode::StartLiteralError,
+      4 => XPathErrorCode::VariableRefError,
+      5 => XPathErrorCode::UndefVariableError,
+      6 => XPathErrorCode::InvalidPredicateError,
+      7 => XPathErrorCode::ExprError,
+      8 => XPathErrorCode::UnclosedError,
+      9 => XPathErrorCode::UnknownFuncError,
+      10 => XPathErrorCode::InvalidOperand,
+      11 => XPathErrorCode::InvalidType,
+      12 => XPathErrorCode::InvalidArity,
+      13 => XPathErrorCode::InvalidCtxtSize,
+      14 => XPathErrorCode::InvalidCtxtPosition,
+      15 => XPathErrorCode::MemoryError,
+      16 => XPathErrorCode::XPtrSyntaxError,
+      17 => XPathErrorCode::XPtrResourceError,
+      18 => XPathErrorCode::XPtrSubResourceError,
+      19 => XPathErrorCode::UndefPrefixError,
+      20 => XPathErrorCode::EncodingError,
+      21 => XPathErrorCode::InvalidCharError,
+      22 => XPathErrorCode::InvalidCtxt,
+      23 => XPathErrorCode::StackError,
+      24 => XPathErrorCode::ForbidVariableError,
+      _ => XPathErrorCode::Unknown,
+    }
+  }
+
+  /// The description libxml2 uses for this kind of failure
+  pub fn message(self) -> &'static str {
+    match self {
+      XPathErrorCode::NumberError => "Number encoding",
+      XPathErrorCode::UnfinishedLiteralError => "Unfinished literal",
+      XPathErrorCode::StartLiteralError => "Start of literal",
+      XPathErrorCode::VariableRefError => "Expected $ for variable reference",
+      XPathErrorCode::UndefVariableError => "Undefined variable",
+      XPathErrorCode::InvalidPredicateError => "Invalid predicate",
+      XPathErrorCode::ExprError => "Invalid expression",
+      XPathErrorCode::UnclosedError => "Missing closing curly brace",
+      XPathErrorCode::UnknownFuncError => "Unregistered function",
+      XPathErrorCode::InvalidOperand => "Invalid operand",
+      XPathErrorCode::InvalidType => "Invalid type",
+      XPathErrorCode::InvalidArity => "Invalid number of arguments",
+      XPathErrorCode::InvalidCtxtSize => "Invalid context size",
+      XPathErrorCode::InvalidCtxtPosition => "Invalid context position",
+      XPathErrorCode::MemoryError => "Memory allocation error",
+      XPathErrorCode::XPtrSyntaxError => "Syntax error",
+      XPathErrorCode::XPtrResourceError => "Resource error",
+      XPathErrorCode::XPtrSubResourceError => "Sub resource error",
+      XPathErrorCode::UndefPrefixError => "Undefined namespace prefix",
+      XPathErrorCode::EncodingError => "Encoding error",
+      XPathErrorCode::InvalidCharError => "Char out of XML range",
+      XPathErrorCode::InvalidCtxt => "Invalid or incomplete context",
+      XPathErrorCode::StackError => "Stack usage error",
+      XPathErrorCode::ForbidVariableError => "Forbidden variable",
+      XPathErrorCode::Unknown => "Unknown error",
+    }
+  }
+}
+
+/// An XPath compilation or evaluation failure
+#[derive(Clone, Debug, PartialEq)]
+pub struct XPathError {
+  /// The kind of failure
+  pub code: XPathErrorCode,
+  /// A human-readable description of the failure
+  pub message: String,
+  /// The expression that failed, when known
+  pub expression: Option<String>,
+  /// The character offset into `expression` at which the failure was detected, when known
+  pub offset: Option<usize>,
+}
+
+impl XPathError {
+  /// Creates an error not recorded by libxml2, for an optional expression
+  pub(crate) fn new(code: XPathErrorCode, expression: Option<&str>) -> Self {
+    XPathError {
+      code,
+      message: code.message().to_owned(),
+      expression: expression.map(str::to_owned),
+      offset: None,
+    }
+  }
+
+  /// Reads the last error recorded on a libxml2 xpath context,
+  /// falling back to `expression` when libxml2 did not record one
+  fn from_context(ctxt: xmlXPathContextPtr, expression: Option<&str>) -> Self {
+    let error = unsafe { &(*ctxt).lastError };
+    let xpath_offset = xmlParserErrors_XML_XPATH_EXPRESSION_OK as c_int;
+    if error.code <= xpath_offset {
+      return XPathError::new(XPathErrorCode::Unknown, expression);
+    }
+    let code = XPathErrorCode::from_int((error.code - xpath_offset) as u32);
+    if error.str1.is_null() {
+      // e.g. compiled expressions are evaluated without their source string
+      return XPathError::new(code, expression);
+    }
+    let recorded = unsafe { CStr::from_ptr(error.str1) }.to_bytes();
+    // libxml2 records a byte offset, report it in characters
+    let byte_offset = (error.int1.max(0) as usize).min(recorded.len());
+    let offset = String::from_utf8_lossy(&recorded[..byte_offset])
+      .chars()
+      .count();
+    XPathError {
+      code,
+      message: code.message().to_owned(),
+      expression: Some(String::from_utf8_lossy(recorded).into_owned()),
+      offset: Some(offset),
+    }
+  }
+}
+
+impl fmt::Display for XPathError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "XPath error: {}", self.message)?;
+    if let Some(ref expression) = self.expression {
+      write!(f, " in {:?}", expression)?;
+    }
+    if let Some(offset) = self.offset {
+      write!(f, " at offset {}", offset)?;
+    }
+    Ok(())
+  }
+}
+
+impl Error for XPathError {}
+
+/// Silences libxml2's default error printing, errors are read back from the context instead
+unsafe extern "C" fn ignore_structured_error(_user_data: *mut c_void, _error: xmlErrorPtr) {}
+
+/// Makes libxml2 record the errors on a context, rather than printing them
+fn record_context_errors(ctxt: xmlXPathContextPtr) {
+  unsafe {
+    // the bindings declare the callback with the Rust ABI, while libxml2 calls it with the C ABI
+    (*ctxt).error = Some(mem::transmute::<
+      unsafe extern "C" fn(*mut c_void, xmlErrorPtr),
+      unsafe fn(*mut c_void, xmlErrorPtr),
+    >(ignore_structured_error));
+  }
+}
+
+unsafe extern "C" fn ignore_generic_error(_ctx: *mut c_void, _msg: *const c_char) {}
+
+/// Silences libxml2's generic error messages until dropped.
+/// Some evaluation errors, such as unknown functions, are printed through the generic handler
+/// in addition to being recorded on the context.
+struct SilencedGenericErrors {
+  handler: xmlGenericErrorFunc,
+  context: *mut c_void,
+}
+
+impl SilencedGenericErrors {
+  fn new() -> Self {
+    unsafe {
+      // the handlers are per thread, so restoring them on drop does not race other threads
+      let silenced = SilencedGenericErrors {
+        handler: *__xmlGenericError(),
+        context: *__xmlGenericErrorContext(),
+      };
+      // the extra variadic arguments are ignored by the C calling convention
+      xmlSetGenericErrorFunc(
+        ptr::null_mut(),
+        Some(mem::transmute::<
+          unsafe extern "C" fn(*mut c_void, *const c_char),
+          unsafe extern "C" fn(*mut c_void, *const c_char, ...),
+        >(ignore_generic_error)),
+      );
+      silenced
+    }
+  }
+}
+
+impl Drop for SilencedGenericErrors {
+  fn drop(&mut self) {
+    unsafe {
+      *__xmlGenericError() = self.handler;
+      *__xmlGenericErrorContext() = self.context;
+    }
+  }
+}
+
+/// Dispatches libxml2 variable lookups to the registered Rust callback,
+/// falling back to the variables registered on the context when it declines
+unsafe extern "C" fn variable_lookup_callback(
+  data: *mut c_void,
+  name: *const xmlChar,
+  ns_uri: *const xmlChar,
+) -> xmlXPathObjectPtr {
+  let lookup = &*(data as *const VariableLookup);
+  let rust_name = CStr::from_ptr(name as *const c_char).to_string_lossy();
+  let rust_ns_uri = if ns_uri.is_null() {
+    None
+  } else {
+    Some(CStr::from_ptr(ns_uri as *const c_char).to_string_lossy())
+  };
+  match (lookup.lookup)(&rust_name, rust_ns_uri.as_ref().map(|ns| ns.as_ref())) {
+    // libxml2 reports an undefined variable for values it can not represent
+    Some(value) => match value.to_object_ptr() {
+      Ok(value_ptr) => {
+        retain_returned_nodes(&lookup.returned_nodes, value);
+        value_ptr
+      }
+      Err(()) => ptr::null_mut(),
+    },
+    None => xmlXPathRegisteredVariableLookup(lookup.context, name, ns_uri),
+  }
+}
+
 /// An XPath context
 #[derive(Clone)]
 pub struct Context {
@@ -34,11 +440,163 @@ pub struct Context {
   pub(crate) document: DocumentWeak,
 }
 
+/// A compiled XPath expression, which can be evaluated repeatedly without being re-parsed
+pub struct XPathExpression {
+  /// libxml's `xmlXPathCompExprPtr`
+  ptr: xmlXPathCompExprPtr,
+  /// The source of the expression, for error reporting
+  expression: String,
+}
+
+impl XPathExpression {
+  /// Compiles an xpath expression, failing with the syntax error if it is invalid
+  pub fn compile(xpath: &str) -> Result<Self, XPathError> {
+    let c_xpath = CString::new(xpath).unwrap();
+    unsafe {
+      // compile on a scratch context, where libxml2 records syntax errors
+      let ctxt = xmlXPathNewContext(ptr::null_mut());
+      if ctxt.is_null() {
+        return Err(XPathError::new(XPathErrorCode::MemoryError, Some(xpath)));
+      }
+      record_context_errors(ctxt);
+      let silenced = SilencedGenericErrors::new();
+      let ptr = xmlXPathCtxtCompile(ctxt, c_xpath.as_bytes().as_ptr());
+      drop(silenced);
+      let result = if ptr.is_null() {
+        Err(XPathError::from_context(ctxt, Some(xpath)))
+      } else {
+        Ok(XPathExpression {
+          ptr,
+          expression: xpath.to_owned(),
+        })
+      };
+      xmlXPathFreeContext(ctxt);
+      result
+    }
+  }
+
+  /// Returns the raw libxml2 compiled expression pointer behind the struct
+  pub fn as_ptr(&self) -> xmlXPathCompExprPtr {
+    self.ptr
+  }
+
+  /// Returns the source of the compiled expression
+  pub fn as_str(&self) -> &str {
+    &self.expression
+  }
+}
+
+impl Drop for XPathExpression {
+  /// free the compiled expression
+  fn drop(&mut self) {
+    unsafe {
+      xmlXPathFreeCompExpr(self.ptr);
+    }
+  }
+}
+
+/// An entry of an XPath node-set, typed by the kind of node it is
+#[derive(Clone, Debug)]
+pub enum NodeSetItem {
+  /// An element node
+  Element(Node),
+  /// An attribute of an element
+  Attribute(Attribute),
+  /// A text or CDATA node
+  Text(Node),
+  /// A comment node
+  Comment(Node),
+  /// A processing instruction node
+  ProcessingInstruction(Node),
+  /// A namespace in scope of an element, as declared in the document
+  Namespace(Namespace),
+  /// The document node
+  Document(Node),
+  /// Any other kind of node
+  Other(Node),
+}
+
+impl NodeSetItem {
+  /// The item as a generic `Node`, unless it is a namespace
+  pub fn as_node(&self) -> Option<&Node> {
+    match self {
+      NodeSetItem::Element(node)
+      | NodeSetItem::Text(node)
+      | NodeSetItem::Comment(node)
+      | NodeSetItem::ProcessingInstruction(node)
+      | NodeSetItem::Document(node)
+      | NodeSetItem::Other(node) => Some(node),
+      NodeSetItem::Attribute(attribute) => Some(attribute.as_node()),
+      NodeSetItem::Namespace(_) => None,
+    }
+  }
+}
+
 ///Essentially, the result of the evaluation of some xpath expression
 pub struct Object {
   ///libxml's `ObjectPtr`
   pub ptr: xmlXPathObjectPtr,
-  document: DocumentWeak,
+  /// Keeps the evaluated document, and with it the result nodes, alive
+  document: DocumentRef,
+  /// Keeps the nodes returned by callbacks during the evaluation alive
+  returned_nodes: Vec<Node>,
+}
+
+/// Dispatches libxml2 extension function calls to the registered Rust closure
+unsafe extern "C" fn function_callback(ctxt: xmlXPathParserContextPtr, nargs: c_int) {
+  let context = (*ctxt).context;
+  let table = &*((*context).funcLookupData as *const FunctionTable);
+  let name = CStr::from_ptr((*context).function as *const c_char)
+    .to_string_lossy()
+    .into_owned();
+  let ns_uri = if (*context).functionURI.is_null() {
+    None
+  } else {
+    Some(
+      CStr::from_ptr((*context).functionURI as *const c_char)
+        .to_string_lossy()
+        .into_owned(),
+    )
+  };
+  let function = match table.functions.get(&(name, ns_uri)) {
+    Some(function) => function.clone(),
+    None => {
+      xmlXPathErr(ctxt, xmlXPathError_XPATH_UNKNOWN_FUNC_ERROR as c_int);
+      return;
+    }
+  };
+  let document = match table.document.upgrade() {
+    Some(document) => document,
+    None => {
+      xmlXPathErr(ctxt, xmlXPathError_XPATH_INVALID_CTXT as c_int);
+      return;
+    }
+  };
+
+  // arguments are on the value stack, last one on top
+  let mut args = Vec::with_capacity(nargs as usize);
+  for _ in 0..nargs {
+    let ptr = valuePop(ctxt);
+    if ptr.is_null() {
+      xmlXPathErr(ctxt, xmlXPathError_XPATH_STACK_ERROR as c_int);
+      return;
+    }
+    let arg = Object::new(ptr, document.clone());
+    args.push(arg.get_value());
+  }
+  args.reverse();
+
+  let value = match function(args) {
+    Ok(value) => value,
+    Err(()) => return xmlXPathErr(ctxt, xmlXPathError_XPATH_INVALID_OPERAND as c_int),
+  };
+  match value.to_object_ptr() {
+    Ok(value_ptr) => {
+      retain_returned_nodes(&table.returned_nodes, value);
+      valuePush(ctxt, value_ptr);
+    }
+    Err(()) => xmlXPathErr(ctxt, xmlXPathError_XPATH_INVALID_OPERAND as c_int),
+  }
 }
 
 impl Context {
@@ -48,8 +606,9 @@ impl Context {
     if ctxtptr.is_null() {
       Err(())
     } else {
+      record_context_errors(ctxtptr);
       Ok(Context {
-        context_ptr: Rc::new(RefCell::new(_Context(ctxtptr))),
+        context_ptr: Rc::new(RefCell::new(_Context::new(ctxtptr))),
         document: Rc::downgrade(&doc.0),
       })
     }
@@ -59,16 +618,17 @@ impl Context {
     if ctxtptr.is_null() {
       Err(())
     } else {
+      record_context_errors(ctxtptr);
       Ok(Context {
-        context_ptr: Rc::new(RefCell::new(_Context(ctxtptr))),
-        document: Rc::downgrade(&docref),
+        context_ptr: Rc::new(RefCell::new(_Context::new(ctxtptr))),
+        document: Rc::downgrade(docref),
       })
     }
   }
 
   /// Returns the raw libxml2 context pointer behind the struct
   pub fn as_ptr(&self) -> xmlXPathContextPtr {
-    self.context_ptr.borrow().0
+    self.context_ptr.borrow().ptr
   }
 
   /// Instantiate a new Context for the Document of a given Node.
@@ -96,32 +656,233 @@ impl Context {
     }
   }
 
-  ///evaluate an xpath
-  pub fn evaluate(&self, xpath: &str) -> Result<Object, ()> {
-    let c_xpath = CString::new(xpath).unwrap();
-    let ptr = unsafe { xmlXPathEvalExpression(c_xpath.as_bytes().as_ptr(), self.as_ptr()) };
-    if ptr.is_null() {
+  /// Bind the variable `$name` to `value` on the xpath context.
+  /// Bound values are substituted safely, without any re-parsing of the expression.
+  /// Bound nodes are kept alive, even if deleted, for as long as the binding.
+  /// Fails for names or strings containing a NUL character.
+  pub fn register_variable<V: Into<Value>>(&self, name: &str, value: V) -> Result<(), ()> {
+    self.register_variable_with_uri(name, None, value.into())
+  }
+
+  /// Bind the variable `$prefix:name` to `value`, where `prefix` is registered for `ns_uri`
+  pub fn register_variable_ns<V: Into<Value>>(
+    &self,
+    name: &str,
+    ns_uri: &str,
+    value: V,
+  ) -> Result<(), ()> {
+    self.register_variable_with_uri(name, Some(ns_uri), value.into())
+  }
+
+  fn register_variable_with_uri(
+    &self,
+    name: &str,
+    ns_uri: Option<&str>,
+    value: Value,
+  ) -> Result<(), ()> {
+    let c_name = CString::new(name).map_err(|_| ())?;
+    let c_ns_uri = match ns_uri {
+      Some(uri) => Some(CString::new(uri).map_err(|_| ())?),
+      None => None,
+    };
+    let value_ptr = value.to_object_ptr()?;
+    let mut context = self.context_ptr.borrow_mut();
+    let result = unsafe {
+      xmlXPathRegisterVariableNS(
+        context.ptr,
+        c_name.as_bytes().as_ptr(),
+        c_ns_uri
+          .as_ref()
+          .map_or(ptr::null(), |uri| uri.as_bytes().as_ptr()),
+        value_ptr,
+      )
+    };
+    if result != 0 {
+      unsafe { xmlXPathFreeObject(value_ptr) };
+      return Err(());
+    }
+    // libxml2 has released the former value, along with the nodes it was bound to
+    let key = (name.to_owned(), ns_uri.map(str::to_owned));
+    match value {
+      Value::Nodes(nodes) => {
+        context.bound_nodes.insert(key, BoundNodes::new(nodes));
+      }
+      _ => {
+        context.bound_nodes.remove(&key);
+      }
+    }
+    Ok(())
+  }
+
+  /// Register a callback resolving variables on demand, from their name and optional namespace URI.
+  /// Returning `None` falls back to the variables bound via `register_variable`.
+  /// Replaces any previously registered lookup callback.
+  pub fn register_variable_lookup<F>(&self, lookup: F)
+  where
+    F: Fn(&str, Option<&str>) -> Option<Value> + 'static,
+  {
+    let mut context = self.context_ptr.borrow_mut();
+    let mut data = Box::new(VariableLookup {
+      context: context.ptr,
+      lookup: Box::new(lookup),
+      returned_nodes: context.returned_nodes.clone(),
+    });
+    unsafe {
+      xmlXPathRegisterVariableLookup(
+        context.ptr,
+        Some(variable_lookup_callback),
+        &mut *data as *mut VariableLookup as *mut c_void,
+      );
+    }
+    // the boxed data has a stable address, keep it alive alongside the context
+    context.variable_lookup = Some(data);
+  }
+
+  /// Register a Rust closure as the XPath function `name`.
+  /// The closure receives the evaluated arguments; returning `Err` aborts the evaluation.
+  pub fn register_function<F>(&self, name: &str, function: F) -> Result<(), ()>
+  where
+    F: Fn(Vec<Value>) -> Result<Value, ()> + 'static,
+  {
+    self.register_function_with_uri(name, None, Rc::new(function))
+  }
+
+  /// Register a Rust closure as the XPath function `prefix:name`, where `prefix` is registered for `ns_uri`
+  pub fn register_function_ns<F>(&self, name: &str, ns_uri: &str, function: F) -> Result<(), ()>
+  where
+    F: Fn(Vec<Value>) -> Result<Value, ()> + 'static,
+  {
+    self.register_function_with_uri(name, Some(ns_uri), Rc::new(function))
+  }
+
+  fn register_function_with_uri(
+    &self,
+    name: &str,
+    ns_uri: Option<&str>,
+    function: Rc<XPathFunction>,
+  ) -> Result<(), ()> {
+    let c_name = CString::new(name).map_err(|_| ())?;
+    let c_ns_uri = match ns_uri {
+      Some(uri) => Some(CString::new(uri).map_err(|_| ())?),
+      None => None,
+    };
+    let c_ns_uri_ptr = match c_ns_uri {
+      Some(ref uri) => uri.as_bytes().as_ptr(),
+      None => ptr::null(),
+    };
+    let mut context = self.context_ptr.borrow_mut();
+    let context_ptr = context.ptr;
+    let document = self.document.clone();
+    let returned_nodes = context.returned_nodes.clone();
+    let table = context.functions.get_or_insert_with(|| {
+      let mut table = Box::new(FunctionTable {
+        document,
+        functions: HashMap::new(),
+        returned_nodes,
+      });
+      // libxml2 functions carry no user data, so the table is reachable via the
+      // context's function lookup data instead. No lookup function is needed,
+      // as the functions themselves are registered in the context's hash.
+      unsafe {
+        xmlXPathRegisterFuncLookup(
+          context_ptr,
+          None,
+          &mut *table as *mut FunctionTable as *mut c_void,
+        );
+      }
+      table
+    });
+    table
+      .functions
+      .insert((name.to_owned(), ns_uri.map(str::to_owned)), function);
+
+    let result = unsafe {
+      xmlXPathRegisterFuncNS(
+        context_ptr,
+        c_name.as_bytes().as_ptr(),
+        c_ns_uri_ptr,
+        Some(function_callback),
+      )
+    };
+    if result != 0 {
       Err(())
     } else {
-      Ok(Object {
-        ptr,
-        document: self.document.clone(),
-      })
+      Ok(())
     }
   }
 
+  ///evaluate an xpath
+  pub fn evaluate(&self, xpath: &str) -> Result<Object, XPathError> {
+    let c_xpath = CString::new(xpath).unwrap();
+    self.evaluate_with(xpath, |ctxt| unsafe {
+      xmlXPathEvalExpression(c_xpath.as_bytes().as_ptr(), ctxt)
+    })
+  }
+
   ///evaluate an xpath on a context Node
-  pub fn node_evaluate(&self, xpath: &str, node: &Node) -> Result<Object, ()> {
+  pub fn node_evaluate(&self, xpath: &str, node: &Node) -> Result<Object, XPathError> {
     let c_xpath = CString::new(xpath).unwrap();
-    let ptr =
-      unsafe { xmlXPathNodeEval(node.node_ptr(), c_xpath.as_bytes().as_ptr(), self.as_ptr()) };
+    self.evaluate_with(xpath, |ctxt| unsafe {
+      xmlXPathNodeEval(node.node_ptr(), c_xpath.as_bytes().as_ptr(), ctxt)
+    })
+  }
+
+  ///evaluate a compiled xpath
+  pub fn evaluate_compiled(&self, expression: &XPathExpression) -> Result<Object, XPathError> {
+    self.evaluate_with(expression.as_str(), |ctxt| unsafe {
+      xmlXPathCompiledEval(expression.as_ptr(), ctxt)
+    })
+  }
+
+  ///evaluate a compiled xpath on a context Node
+  pub fn node_evaluate_compiled(
+    &self,
+    expression: &XPathExpression,
+    node: &Node,
+  ) -> Result<Object, XPathError> {
+    unsafe {
+      if xmlXPathSetContextNode(node.node_ptr(), self.as_ptr()) != 0 {
+        return Err(XPathError::new(
+          XPathErrorCode::InvalidCtxt,
+          Some(expression.as_str()),
+        ));
+      }
+    }
+    self.evaluate_compiled(expression)
+  }
+
+  /// clear the error recorded by a previous evaluation
+  fn reset_error(&self) {
+    unsafe { xmlResetError(&mut (*self.as_ptr()).lastError) }
+  }
+
+  /// run an evaluation on the context, then wrap its result or report the error
+  /// libxml2 recorded for it
+  fn evaluate_with<F>(&self, xpath: &str, evaluation: F) -> Result<Object, XPathError>
+  where
+    F: FnOnce(xmlXPathContextPtr) -> xmlXPathObjectPtr,
+  {
+    self.reset_error();
+    let returned_nodes = self.context_ptr.borrow().returned_nodes.clone();
+    // evaluations nested in callbacks only take over the nodes returned to them
+    let returned_start = returned_nodes.borrow().len();
+    let silenced = SilencedGenericErrors::new();
+    let ptr = evaluation(self.as_ptr());
+    drop(silenced);
+    let returned = returned_nodes.borrow_mut().split_off(returned_start);
     if ptr.is_null() {
-      Err(())
-    } else {
-      Ok(Object {
-        ptr,
-        document: self.document.clone(),
-      })
+      return Err(XPathError::from_context(self.as_ptr(), Some(xpath)));
+    }
+    match self.document.upgrade() {
+      Some(document) => {
+        let mut object = Object::new(ptr, document);
+        object.returned_nodes = returned;
+        Ok(object)
+      }
+      None => {
+        unsafe { xmlXPathFreeObject(ptr) };
+        Err(XPathError::new(XPathErrorCode::InvalidCtxt, Some(xpath)))
+      }
     }
   }
 
@@ -137,7 +898,11 @@ impl Context {
   }
 
   /// find nodes via xpath, at a specified node or the document root
-  pub fn findnodes(&mut self, xpath: &str, node_opt: Option<&Node>) -> Result<Vec<Node>, ()> {
+  pub fn findnodes(
+    &mut self,
+    xpath: &str,
+    node_opt: Option<&Node>,
+  ) -> Result<Vec<Node>, XPathError> {
     let evaluated;
     if let Some(node) = node_opt {
       evaluated = self.node_evaluate(xpath, node)?;
@@ -147,8 +912,22 @@ impl Context {
     Ok(evaluated.get_nodes_as_vec())
   }
 
+  /// find nodes via a compiled xpath, at a specified node or the document root
+  pub fn findnodes_compiled(
+    &mut self,
+    expression: &XPathExpression,
+    node_opt: Option<&Node>,
+  ) -> Result<Vec<Node>, XPathError> {
+    let evaluated = if let Some(node) = node_opt {
+      self.node_evaluate_compiled(expression, node)?
+    } else {
+      self.evaluate_compiled(expression)?
+    };
+    Ok(evaluated.get_nodes_as_vec())
+  }
+
   /// find a literal value via xpath, at a specified node or the document root
-  pub fn findvalue(&mut self, xpath: &str, node_opt: Option<&Node>) -> Result<String, ()> {
+  pub fn findvalue(&mut self, xpath: &str, node_opt: Option<&Node>) -> Result<String, XPathError> {
     let evaluated;
     if let Some(node) = node_opt {
       evaluated = self.node_evaluate(xpath, node)?;
@@ -157,6 +936,52 @@ impl Context {
     }
     Ok(evaluated.to_string())
   }
+
+  /// find the first node via xpath, at a specified node or the document root
+  pub fn find_first(
+    &mut self,
+    xpath: &str,
+    node_opt: Option<&Node>,
+  ) -> Result<Option<Node>, XPathError> {
+    let evaluated = self.evaluate_at(xpath, node_opt)?;
+    Ok(evaluated.get_nodes_as_vec().into_iter().next())
+  }
+
+  /// find a number via xpath, at a specified node or the document root
+  pub fn find_number(&mut self, xpath: &str, node_opt: Option<&Node>) -> Result<f64, XPathError> {
+    let evaluated = self.evaluate_at(xpath, node_opt)?;
+    Ok(evaluated.to_number())
+  }
+
+  /// find a boolean via xpath, at a specified node or the document root
+  pub fn find_bool(&mut self, xpath: &str, node_opt: Option<&Node>) -> Result<bool, XPathError> {
+    let evaluated = self.evaluate_at(xpath, node_opt)?;
+    Ok(evaluated.to_bool())
+  }
+
+  /// check if an xpath matches anything, at a specified node or the document root.
+  /// Results which are not node-sets are checked for their boolean value.
+  pub fn exists(&mut self, xpath: &str, node_opt: Option<&Node>) -> Result<bool, XPathError> {
+    self.find_bool(xpath, node_opt)
+  }
+
+  fn evaluate_at(&self, xpath: &str, node_opt: Option<&Node>) -> Result<Object, XPathError> {
+    if let Some(node) = node_opt {
+      self.node_evaluate(xpath, node)
+    } else {
+      self.evaluate(xpath)
+    }
+  }
+
+  /// register a list of prefix-href namespace pairs, as accepted by the convenience finders
+  pub(crate) fn register_namespaces(&self, namespaces: &[(&str, &str)]) -> Result<(), XPathError> {
+    for (prefix, href) in namespaces {
+      if self.register_namespace(prefix, href).is_err() {
+        return Err(XPathError::new(XPathErrorCode::InvalidCtxt, None));
+      }
+    }
+    Ok(())
+  }
 }
 
 impl Drop for Object {
@@ -165,10 +990,20 @@ impl Drop for Object {
     unsafe {
       xmlXPathFreeObject(self.ptr);
     }
+    self.document.borrow_mut().release_reference();
   }
 }
 
 impl Object {
+  fn new(ptr: xmlXPathObjectPtr, document: DocumentRef) -> Self {
+    document.borrow_mut().hold_reference();
+    Object {
+      ptr,
+      document,
+      returned_nodes: Vec::new(),
+    }
+  }
+
   ///get the number of nodes in the result set
   pub fn get_number_of_nodes(&self) -> usize {
     let v = xmlXPathObjectNumberOfNodes(self.ptr);
@@ -185,26 +1020,109 @@ impl Object {
     v as usize
   }
 
-  /// returns the result set as a vector of node references
+  /// returns the result set as a vector of node references.
+  /// Nodes deleted since the evaluation are skipped, as are namespace nodes, see `get_items`.
+  /// Nodes removed in other ways stay valid, since they are retained while this object lives.
   pub fn get_nodes_as_vec(&self) -> Vec<Node> {
+    self
+      .node_set()
+      .into_iter()
+      .filter(|&ptr| !self.is_deleted(ptr))
+      .filter(|&ptr| xmlGetNodeType(ptr) != xmlElementType_XML_NAMESPACE_DECL)
+      .map(|ptr| Node::wrap(ptr, &self.document))
+      .collect()
+  }
+
+  /// returns the result set as typed items, including attributes and namespaces.
+  /// Nodes deleted since the evaluation are skipped.
+  pub fn get_items(&self) -> Vec<NodeSetItem> {
+    let mut items = Vec::new();
+    for ptr in self.node_set() {
+      if self.is_deleted(ptr) {
+        continue;
+      }
+      let node_type = NodeType::from_int(xmlGetNodeType(ptr));
+      if node_type == Some(NodeType::NamespaceDecl) {
+        // libxml2 copies namespace nodes into the set, pointing `next` at their element.
+        // The copies die with this object, so resolve the declaration they stem from.
+        let ns_copy = ptr as xmlNsPtr;
+        let element = xmlNextNsSibling(ns_copy) as xmlNodePtr;
+        if self.is_deleted(element) {
+          continue;
+        }
+        let ns_ptr =
+          unsafe { xmlSearchNs(self.document.borrow().doc_ptr, element, (*ns_copy).prefix) };
+        if !ns_ptr.is_null() {
+          items.push(NodeSetItem::Namespace(Namespace::wrap(
+            ns_ptr,
+            &self.document,
+          )));
+        }
+        continue;
+      }
+      let node = Node::wrap(ptr, &self.document);
+      items.push(match node_type {
+        Some(NodeType::ElementNode) => NodeSetItem::Element(node),
+        Some(NodeType::AttributeNode) => NodeSetItem::Attribute(Attribute::wrap(node)),
+        Some(NodeType::TextNode) | Some(NodeType::CDataSectionNode) => NodeSetItem::Text(node),
+        Some(NodeType::CommentNode) => NodeSetItem::Comment(node),
+        Some(NodeType::PiNode) => NodeSetItem::ProcessingInstruction(node),
+        Some(NodeType::DocumentNode) | Some(NodeType::HtmlDocumentNode) => {
+          NodeSetItem::Document(node)
+        }
+        _ => NodeSetItem::Other(node),
+      });
+    }
+    items
+  }
+
+  /// the raw node pointers of the result set
+  fn node_set(&self) -> Vec<xmlNodePtr> {
     let n = self.get_number_of_nodes();
-    let mut vec: Vec<Node> = Vec::with_capacity(n);
-    let slice = if n > 0 {
-      xmlXPathObjectGetNodes(self.ptr, n as size_t)
+    if n == 0 {
+      return Vec::new();
+    }
+    let slice = xmlXPathObjectGetNodes(self.ptr, n as size_t);
+    if slice.iter().any(|ptr| ptr.is_null()) {
+      panic!("rust-libxml: xpath: found null pointer result set");
+    }
+    slice
+  }
+
+  /// whether the node was deleted since the evaluation
+  fn is_deleted(&self, node_ptr: xmlNodePtr) -> bool {
+    self.document.borrow().is_deleted(node_ptr)
+  }
+
+  /// returns the result as a typed `Value`, following the XPath type of the result
+  pub fn get_value(&self) -> Value {
+    let object_type = unsafe { (*self.ptr).type_ };
+    if object_type == xmlXPathObjectType_XPATH_NODESET
+      || object_type == xmlXPathObjectType_XPATH_XSLT_TREE
+    {
+      Value::Nodes(self.get_nodes_as_vec())
+    } else if object_type == xmlXPathObjectType_XPATH_BOOLEAN {
+      Value::Boolean(unsafe { (*self.ptr).boolval } != 0)
+    } else if object_type == xmlXPathObjectType_XPATH_NUMBER {
+      Value::Number(unsafe { (*self.ptr).floatval })
     } else {
-      Vec::new()
-    };
-    for ptr in slice {
-      if ptr.is_null() {
-        panic!("rust-libxml: xpath: found null pointer result set");
-      }
-      let node = Node::wrap(ptr, &self.document.upgrade().unwrap());
-      vec.push(node);
+      Value::String(self.to_string())
     }
-    vec
+  }
+
+  /// use if the XPath used was meant to return a number, such as count(//foo)
+  pub fn to_number(&self) -> f64 {
+    unsafe { xmlXPathCastToNumber(self.ptr) }
+  }
+
+  /// use if the XPath used was meant to return a boolean, such as boolean(//foo).
+  /// Node-sets are true when non-empty.
+  pub fn to_bool(&self) -> bool {
+    unsafe { xmlXPathCastToBoolean(self.ptr) != 0 }
   }
 
   /// use if the XPath used was meant to return a string, such as string(//foo/@attr)
+  #[allow(clippy::inherent_to_string)]
   pub fn to_string(&self) -> String {
     unsafe {
       let receiver = xmlXPathCastToString(self.ptr);
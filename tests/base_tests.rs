@@ -108,7 +108,7 @@ fn create_test_document(file: Option<&str>) -> Document {
 #[test]
 fn document_can_import_node() {
   let doc1 = create_test_document(None);
-  let mut doc2 = create_test_document(None);
+  let doc2 = create_test_document(None);
 
   assert_eq!(
     doc2.get_root_element().unwrap().get_child_elements().len(),
@@ -116,9 +116,8 @@ fn document_can_import_node() {
   );
 
   let mut elements = doc1.get_root_element().unwrap().get_child_elements();
-  let mut node = elements.pop().unwrap();
-  node.unlink();
-  let mut imported = doc2.import_node(&mut node).unwrap();
+  let node = elements.pop().unwrap();
+  let mut imported = doc2.import_node(&node, true).unwrap();
   assert!(doc2
     .get_root_element()
     .unwrap()
@@ -129,6 +128,44 @@ fn document_can_import_node() {
     doc2.get_root_element().unwrap().get_child_elements().len(),
     3
   );
+  // the source document is left untouched
+  assert_eq!(
+    doc1.get_root_element().unwrap().get_child_elements().len(),
+    2
+  );
+  assert_eq!(node.get_parent(), doc1.get_root_element());
+  assert_eq!(imported.get_content(), node.get_content());
+}
+
+#[test]
+fn document_can_adopt_node() {
+  let parser = Parser::default();
+  let doc1 = parser
+    .parse_string(r#"<root xmlns:x="urn:x"><x:child><x:leaf/></x:child></root>"#)
+    .unwrap();
+  let mut doc2 = create_test_document(None);
+
+  let node = doc1.get_root_element().unwrap().get_first_child().unwrap();
+  let node_ptr = node.node_ptr();
+  let leaf = node.get_first_child().unwrap();
+  // namespaces declared outside the moved subtree do not prevent the move
+  let root_ns = leaf.get_namespace().unwrap();
+  let mut adopted = doc2.adopt_node(node).unwrap();
+  // the handles are moved along with the nodes
+  assert_eq!(adopted.node_ptr(), node_ptr);
+  assert!(adopted.is_unlinked());
+  assert!(doc1.get_root_element().unwrap().get_first_child().is_none());
+  drop(doc1);
+
+  let mut root = doc2.get_root_element().unwrap();
+  root.add_child(&mut adopted).unwrap();
+  assert_eq!(leaf.get_parent(), Some(adopted.clone()));
+  assert_eq!(
+    doc2.node_to_string(&adopted),
+    r#"<x:child xmlns:x="urn:x"><x:leaf/></x:child>"#
+  );
+  assert_eq!(leaf.get_namespace().unwrap().get_href(), "urn:x");
+  assert_eq!(root_ns.get_href(), "urn:x");
 }
 
 #[test]
@@ -143,6 +180,36 @@ fn document_formatted_serialization() {
   assert!(doc_str_formatted.len() > doc_str.len());
 }
 
+#[test]
+fn document_metadata() {
+  let mut doc = create_test_document(None);
+  assert_eq!(doc.version(), Some(String::from("1.0")));
+  assert_eq!(doc.encoding(), Some(String::from("UTF-8")));
+  assert_eq!(doc.standalone(), None);
+  assert_eq!(doc.url(), Some(String::from("tests/resources/file01.xml")));
+
+  doc.set_encoding("ISO-8859-1");
+  doc.set_standalone(Some(true));
+  doc.set_url("http://example.com/feeds/file01.xml");
+  assert_eq!(doc.encoding(), Some(String::from("ISO-8859-1")));
+  assert_eq!(doc.standalone(), Some(true));
+  assert_eq!(
+    doc.url(),
+    Some(String::from("http://example.com/feeds/file01.xml"))
+  );
+  assert!(doc
+    .to_string(false)
+    .starts_with("<?xml version=\"1.0\" encoding=\"UTF-8\" standalone=\"yes\"?>"));
+  doc.set_standalone(None);
+  assert_eq!(doc.standalone(), None);
+
+  let parsed = Parser::default().parse_string("<root/>").unwrap();
+  assert_eq!(parsed.url(), None);
+  assert_eq!(parsed.encoding(), Some(String::from("utf-8")));
+  let html = Document::new_html().unwrap();
+  assert_eq!(html.version(), None);
+}
+
 #[test]
 /// Test well-formedness of a Rust string
 /// IMPORTANT: Currenlty NOT THREAD-SAFE, use in single-threaded apps only!
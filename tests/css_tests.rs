@@ -0,0 +1,176 @@
+//! CSS selector tests
+//!
+
+use libxml::css::{css_to_xpath, SelectorError};
+use libxml::parser::Parser;
+use libxml::tree::{Document, Node};
+
+fn selector_document() -> Document {
+  let parser = Parser::default_html();
+  let doc_result = parser.parse_file("tests/resources/css_selectors.html");
+  assert!(doc_result.is_ok());
+  doc_result.unwrap()
+}
+
+fn contents(nodes: &[Node]) -> Vec<String> {
+  nodes.iter().map(|node| node.get_content()).collect()
+}
+
+#[test]
+/// Type, class, id and attribute selectors
+fn simple_selectors() {
+  let doc = selector_document();
+  assert_eq!(doc.select("li").unwrap().len(), 5);
+  assert_eq!(doc.select("*").unwrap().len(), 19);
+  assert_eq!(contents(&doc.select(".special").unwrap()), vec!["two"]);
+  assert_eq!(doc.select("li.item").unwrap().len(), 5);
+  assert_eq!(doc.select(".item.last").unwrap().len(), 1);
+  // class matching is by whole class name
+  assert!(doc.select(".ite").unwrap().is_empty());
+  assert_eq!(doc.select("#main").unwrap().len(), 1);
+  assert_eq!(doc.select("div#main.wide").unwrap().len(), 1);
+
+  assert_eq!(doc.select("[data-kind]").unwrap().len(), 1);
+  assert_eq!(
+    contents(&doc.select("[data-kind=note]").unwrap()),
+    vec!["Note"]
+  );
+  assert_eq!(doc.select("[class~=first]").unwrap().len(), 1);
+  assert_eq!(doc.select("[lang|=en]").unwrap().len(), 1);
+  assert_eq!(doc.select("a[href^='https:']").unwrap().len(), 1);
+  assert_eq!(
+    contents(&doc.select("a[href$=\".pdf\"]").unwrap()),
+    vec!["Local"]
+  );
+  assert_eq!(doc.select("a[href*=page]").unwrap().len(), 2);
+  assert!(doc.select("a[href^='']").unwrap().is_empty());
+  assert_eq!(doc.select("[title='it\\'s \"quoted\"']").unwrap().len(), 1);
+}
+
+#[test]
+/// Descendant, child and sibling combinators, and selector groups
+fn combinators() {
+  let doc = selector_document();
+  assert_eq!(doc.select("div p").unwrap().len(), 3);
+  assert_eq!(doc.select("body > p").unwrap().len(), 0);
+  assert_eq!(doc.select("#main > p").unwrap().len(), 2);
+  assert_eq!(contents(&doc.select("h1 + p").unwrap()), vec!["Intro"]);
+  assert_eq!(doc.select("h1 ~ p").unwrap().len(), 2);
+  assert_eq!(
+    contents(&doc.select("li.special+li").unwrap()),
+    vec!["three"]
+  );
+  assert_eq!(doc.select("h1, .sidebar a").unwrap().len(), 3);
+
+  // node selections are scoped to descendants
+  let sidebar = doc.select(".sidebar").unwrap().pop().unwrap();
+  assert_eq!(contents(&sidebar.select("p").unwrap()), vec!["Aside"]);
+  assert!(sidebar.select("li").unwrap().is_empty());
+}
+
+#[test]
+/// Structural pseudo-classes and negation
+fn pseudo_classes() {
+  let doc = selector_document();
+  assert_eq!(
+    contents(&doc.select("li:first-child").unwrap()),
+    vec!["one"]
+  );
+  assert_eq!(
+    contents(&doc.select("li:last-child").unwrap()),
+    vec!["five"]
+  );
+  assert_eq!(
+    contents(&doc.select("li:nth-child(2n+1)").unwrap()),
+    vec!["one", "three", "five"]
+  );
+  assert_eq!(
+    contents(&doc.select("li:nth-child(even)").unwrap()),
+    vec!["two", "four"]
+  );
+  assert_eq!(
+    contents(&doc.select("li:nth-child(3)").unwrap()),
+    vec!["three"]
+  );
+  assert_eq!(
+    contents(&doc.select("li:nth-child(-n + 2)").unwrap()),
+    vec!["one", "two"]
+  );
+  assert_eq!(
+    contents(&doc.select("li:nth-last-child(2)").unwrap()),
+    vec!["four"]
+  );
+  assert_eq!(
+    contents(&doc.select("#main p:first-of-type").unwrap()),
+    vec!["Intro"]
+  );
+  assert_eq!(
+    contents(&doc.select("#main p:last-of-type").unwrap()),
+    vec!["Note"]
+  );
+  assert_eq!(doc.select("p:only-of-type").unwrap().len(), 1);
+  assert_eq!(
+    contents(&doc.select("p:nth-of-type(2)").unwrap()),
+    vec!["Note"]
+  );
+  assert_eq!(doc.select("span:empty").unwrap().len(), 1);
+  assert_eq!(doc.select(":root").unwrap().len(), 1);
+  assert_eq!(doc.select("li:not(.item)").unwrap().len(), 0);
+  assert_eq!(doc.select("li:not(:first-child)").unwrap().len(), 4);
+  assert_eq!(doc.select("#main > :not(p)").unwrap().len(), 3);
+}
+
+#[test]
+/// Invalid and unsupported selectors are reported
+fn selector_errors() {
+  assert_eq!(
+    css_to_xpath("div >"),
+    Err(SelectorError::Syntax {
+      message: "expected a selector".to_owned(),
+      offset: 5
+    })
+  );
+  assert!(css_to_xpath("").is_err());
+  assert!(css_to_xpath("p::before").is_err());
+  assert!(css_to_xpath("a:hover").is_err());
+  assert!(css_to_xpath("*:first-of-type").is_err());
+  assert!(css_to_xpath("li:nth-child(foo)").is_err());
+  assert!(css_to_xpath("[href").is_err());
+  assert!(css_to_xpath("a[href='x]").is_err());
+
+  let doc = selector_document();
+  assert!(doc.select("li:not(:not(.item))").is_err());
+  assert_eq!(
+    css_to_xpath("ul > li.item").unwrap(),
+    "descendant::ul/child::li[contains(concat(' ', @class, ' '), ' item ')]"
+  );
+}
+
+#[test]
+/// Type and attribute names match case-insensitively in HTML documents only
+fn html_names_ignore_case() {
+  let doc = selector_document();
+  assert_eq!(doc.select("LI").unwrap().len(), 5);
+  assert_eq!(doc.select("UL > Li.item").unwrap().len(), 5);
+  assert_eq!(doc.select("[DATA-KIND]").unwrap().len(), 1);
+  assert_eq!(doc.select("li:not(LI)").unwrap().len(), 0);
+  let list = doc.select("ul").unwrap().pop().unwrap();
+  assert_eq!(list.select("LI:first-of-type").unwrap().len(), 1);
+
+  let parser = Parser::default();
+  let xml = parser.parse_string("<root><Item/><item/></root>").unwrap();
+  assert_eq!(xml.select("item").unwrap().len(), 1);
+  assert_eq!(xml.select("ITEM").unwrap().len(), 0);
+
+  // the *-of-type pseudo-classes need an element type to compare against
+  match doc.select(".special:first-of-type") {
+    Err(SelectorError::Syntax { message, .. }) => assert_eq!(
+      message,
+      ":first-of-type requires a type selector in its compound, e.g. li:first-of-type"
+    ),
+    other => panic!(
+      "expected a syntax error, got {:?}",
+      other.map(|nodes| nodes.len())
+    ),
+  }
+}
@@ -1,15 +1,19 @@
 //! Enforce Rust ownership pragmatics for the underlying libxml2 objects
 
 use libxml::parser::Parser;
-use libxml::tree::set_node_rc_guard;
+use libxml::tree::{Document, Node};
 
-#[test]
-fn ownership_guards() {
-  // Setup
+fn create_test_document() -> Document {
   let parser = Parser::default();
   let doc_result = parser.parse_file("tests/resources/file01.xml");
   assert!(doc_result.is_ok());
-  let doc = doc_result.unwrap();
+  doc_result.unwrap()
+}
+
+#[test]
+fn ownership_guards() {
+  // Setup
+  let doc = create_test_document();
   let root = doc.get_root_element().unwrap();
 
   let mut first_a = root.get_first_element_child().unwrap();
@@ -24,34 +28,117 @@ fn ownership_guards() {
     Some(String::from("value"))
   );
 
-  // Setting an attribute will fail and return an error, as there are too many Rc references
-  // to the same node (Rc strong count of 3)
-  // see `Node::node_ptr_mut` for details
-  assert!(first_a.set_attribute("attribute", "newa").is_err());
+  // Both handles refer to the same node, so either may mutate it
+  assert!(first_a.set_attribute("attribute", "newa").is_ok());
 
   assert_eq!(
     first_a.get_attribute("attribute"),
-    Some(String::from("value"))
+    Some(String::from("newa"))
   );
   assert_eq!(
     first_b.get_attribute("attribute"),
-    Some(String::from("value"))
+    Some(String::from("newa"))
   );
+}
 
-  // Try again with guard boosted, which allows the change
-  set_node_rc_guard(3);
+#[test]
+fn replaced_content_stays_valid() {
+  let doc = create_test_document();
+  let mut child = doc
+    .get_root_element()
+    .unwrap()
+    .get_first_element_child()
+    .unwrap();
+  let text = child.get_first_child().unwrap();
+  let attribute_text = child
+    .get_property_node("attribute")
+    .unwrap()
+    .get_first_child()
+    .unwrap();
 
-  // Setting an attribute will fail and return an error, as there are too many Rc references
-  // to the same node (Rc strong count of 3)
-  // see `Node::node_ptr_mut` for details
-  assert!(first_a.set_attribute("attribute", "newa").is_ok());
+  // the text nodes are taken out of the tree, instead of freed
+  child.set_content("new text").unwrap();
+  child.set_attribute("attribute", "new value").unwrap();
+  assert!(text.is_unlinked());
+  assert!(text.get_parent().is_none());
+  assert_eq!(text.get_content(), "some text");
+  assert_eq!(attribute_text.get_content(), "value");
+  assert_eq!(child.get_content(), "new text");
+  assert_eq!(
+    child.get_attribute("attribute"),
+    Some(String::from("new value"))
+  );
 
+  let mut root = doc.get_root_element().unwrap();
+  let second = root.get_child_elements().pop().unwrap();
+  root.remove_all_children().unwrap();
+  assert!(child.is_unlinked());
+  assert_eq!(second.get_content(), "more text");
+}
+
+#[test]
+fn merged_nodes_stay_valid() {
+  let doc = create_test_document();
+  let mut child = doc
+    .get_root_element()
+    .unwrap()
+    .get_first_element_child()
+    .unwrap();
+
+  // libxml2 merges adjacent text, the inserted handle keeps a detached node
+  let mut text = Node::new_text(" and more", &doc).unwrap();
+  child.add_child(&mut text).unwrap();
+  assert_eq!(child.get_content(), "some text and more");
+  assert_eq!(child.get_child_nodes().len(), 1);
+  assert!(text.is_unlinked());
+  assert_eq!(text.get_content(), " and more");
+
+  // an attribute replaced by another of the same name is kept as well
+  let old_attribute = child.get_property_node("attribute").unwrap();
+  let mut source = Node::new("source", None, &doc).unwrap();
+  source.set_attribute("attribute", "moved").unwrap();
+  let mut new_attribute = source.get_property_node("attribute").unwrap();
+  child.add_child(&mut new_attribute).unwrap();
   assert_eq!(
-    first_a.get_attribute("attribute"),
-    Some(String::from("newa"))
+    child.get_attribute("attribute"),
+    Some(String::from("moved"))
+  );
+  assert!(old_attribute.get_parent().is_none());
+  assert_eq!(old_attribute.get_content(), "value");
+}
+
+#[test]
+fn namespaced_attribute_is_not_overwritten() {
+  let parser = Parser::default();
+  let doc = parser
+    .parse_string(r#"<a xmlns:x="urn:x" x:attr="ns"/>"#)
+    .unwrap();
+  let mut root = doc.get_root_element().unwrap();
+  let ns_text = root
+    .get_property_node("attr")
+    .unwrap()
+    .get_first_child()
+    .unwrap();
+
+  // a plain attribute of the same name is added next to the namespaced one
+  root.set_attribute("attr", "plain").unwrap();
+  assert!(!ns_text.is_unlinked());
+  assert!(ns_text.get_parent().is_some());
+  assert_eq!(
+    root.get_attribute_ns("attr", "urn:x"),
+    Some(String::from("ns"))
   );
   assert_eq!(
-    first_b.get_attribute("attribute"),
-    Some(String::from("newa"))
+    doc.node_to_string(&root),
+    r#"<a xmlns:x="urn:x" x:attr="ns" attr="plain"/>"#
+  );
+
+  // a prefixed name overwrites the attribute in the bound namespace
+  root.set_attribute("x:attr", "new").unwrap();
+  assert!(ns_text.is_unlinked());
+  assert_eq!(ns_text.get_content(), "ns");
+  assert_eq!(
+    doc.node_to_string(&root),
+    r#"<a xmlns:x="urn:x" x:attr="new" attr="plain"/>"#
   );
 }
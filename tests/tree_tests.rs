@@ -2,7 +2,8 @@
 //!
 
 use libxml::parser::Parser;
-use libxml::tree::{Document, Namespace, Node, NodeType};
+use libxml::tree::{Doctype, Document, DocumentFragment, Namespace, Node, NodeType};
+use libxml::xpath::Context;
 
 #[test]
 /// Root node and first child of root node are different
@@ -18,13 +19,13 @@ fn child_of_root_has_different_hash() {
     if let Some(child) = root.get_first_child() {
       assert!(root != child);
     } else {
-      assert!(false); //test failed - child doesn't exist
+      panic!("test failed - child doesn't exist");
     }
     // same check with last child
     if let Some(child) = root.get_last_child() {
       assert!(root != child);
     } else {
-      assert!(false); //test failed - child doesn't exist
+      panic!("test failed - child doesn't exist");
     }
   }
 }
@@ -153,6 +154,88 @@ fn attribute_namespace_accessors() {
   assert_eq!(fb_uri, Some("http://www.foobar.org".to_string())); // system ns has the global prefix when doing global lookup
 }
 
+#[test]
+/// Attributes keep their order, names and namespaces
+fn attribute_handles() {
+  let parser = Parser::default();
+  let doc = parser
+    .parse_string(
+      r#"<root xmlns:xlink="http://www.w3.org/1999/xlink"><a z="1" xlink:href="x" href="y" b="2"/></root>"#,
+    )
+    .unwrap();
+  let mut element = doc.get_root_element().unwrap().get_first_child().unwrap();
+
+  let names: Vec<String> = element.attributes().map(|attr| attr.name()).collect();
+  assert_eq!(names, vec!["z", "xlink:href", "href", "b"]);
+  let values: Vec<String> = element.attributes().map(|attr| attr.value()).collect();
+  assert_eq!(values, vec!["1", "x", "y", "2"]);
+
+  let xlink_href = element.attributes().nth(1).unwrap();
+  assert_eq!(xlink_href.local_name(), "href");
+  assert_eq!(
+    xlink_href.namespace().unwrap().get_href(),
+    "http://www.w3.org/1999/xlink"
+  );
+  assert!(element.attributes().nth(2).unwrap().namespace().is_none());
+  assert_eq!(xlink_href.owner_element(), Some(element.clone()));
+  drop(xlink_href);
+
+  let mut href = element.attributes().nth(2).unwrap();
+  href.set_value("z").unwrap();
+  assert_eq!(href.value(), "z");
+  assert_eq!(
+    element.get_attribute_ns("href", "http://www.w3.org/1999/xlink"),
+    Some("x".to_owned())
+  );
+  drop(href);
+
+  let removed = element.attributes().next().unwrap();
+  element.attributes().next().unwrap().remove().unwrap();
+  assert_eq!(removed.owner_element(), None);
+  assert_eq!(removed.value(), "1");
+  drop(removed);
+  let names: Vec<String> = element.attributes().map(|attr| attr.name()).collect();
+  assert_eq!(names, vec!["xlink:href", "href", "b"]);
+  assert_eq!(
+    doc.node_to_string(&element),
+    r#"<a xlink:href="x" href="z" b="2"/>"#
+  );
+  assert!(element.set_attribute("z", "3").is_ok());
+  assert_eq!(element.attributes().last().unwrap().name(), "z");
+  assert_eq!(doc.get_root_element().unwrap().attributes().count(), 0);
+}
+
+#[test]
+/// Attributes sharing a local name are told apart by their namespace
+fn namespaced_attribute_map() {
+  let parser = Parser::default();
+  let doc = parser
+    .parse_string(
+      r#"<a xmlns:xlink="http://www.w3.org/1999/xlink" href="local" xlink:href="linked" id="1"/>"#,
+    )
+    .unwrap();
+  let mut element = doc.get_root_element().unwrap();
+  let xlink = Some("http://www.w3.org/1999/xlink".to_owned());
+
+  let attributes = element.get_properties_ns();
+  assert_eq!(attributes.len(), 3);
+  assert_eq!(attributes[&(None, "href".to_owned())], "local");
+  assert_eq!(attributes[&(xlink.clone(), "href".to_owned())], "linked");
+  assert_eq!(attributes[&(None, "id".to_owned())], "1");
+  assert_eq!(element.get_properties()["href"], "local");
+
+  assert!(element
+    .remove_property_ns("href", "http://www.w3.org/1999/xlink")
+    .is_ok());
+  assert!(element
+    .remove_attribute_ns("id", "http://www.w3.org/1999/xlink")
+    .is_ok());
+  let attributes = element.get_attributes_ns();
+  assert_eq!(attributes.len(), 2);
+  assert!(!attributes.contains_key(&(xlink, "href".to_owned())));
+  assert_eq!(element.get_attribute("href"), Some("local".to_owned()));
+}
+
 #[test]
 fn node_can_unbind() {
   let mut doc = Document::new().unwrap();
@@ -297,6 +380,177 @@ fn can_work_with_namespaces() {
   assert_eq!(first_ns.get_href(), "http://example.com/ns/mock");
 }
 
+#[test]
+/// Namespaces are freed along with their document, exactly once
+fn namespace_memory_management() {
+  let mut doc = Document::new().unwrap();
+  let mut root_node = Node::new("root", None, &doc).unwrap();
+  doc.set_root_element(&root_node);
+  let ns = Namespace::new("mock", "http://example.com/ns/mock", &mut root_node).unwrap();
+  let ns_copy = ns.clone();
+  assert_eq!(ns, ns_copy);
+  drop(ns);
+
+  // repeated listings hand out handles to the same declarations
+  for _ in 0..3 {
+    let namespace_list = root_node.get_namespaces(&doc);
+    assert_eq!(namespace_list, vec![ns_copy.clone()]);
+  }
+
+  // declarations removed from the tree stay valid while referenced
+  let declaration = root_node.get_namespace_declarations().pop().unwrap();
+  assert!(root_node.recursively_remove_namespaces().is_ok());
+  assert!(root_node.get_namespace_declarations().is_empty());
+  assert_eq!(declaration.get_prefix(), "mock");
+
+  // and outlive the document handle itself
+  drop(root_node);
+  drop(doc);
+  assert_eq!(ns_copy.get_href(), "http://example.com/ns/mock");
+  assert_eq!(declaration.get_href(), "http://example.com/ns/mock");
+
+  // live namespaces only keep their own declarations from being freed
+  let parser = Parser::default();
+  let doc = parser
+    .parse_string(r#"<root><a xmlns:x="urn:x"><x:b/></a><c/></root>"#)
+    .unwrap();
+  let root = doc.get_root_element().unwrap();
+  let mut a = root.get_first_child().unwrap();
+  let ns = a.get_first_child().unwrap().get_namespace().unwrap();
+  let mut other = Document::new().unwrap();
+  assert!(other.adopt_node(a.clone()).is_err());
+  a.delete().unwrap();
+  assert_eq!(ns.get_href(), "urn:x");
+  let c = root.get_first_child().unwrap();
+  assert!(other.adopt_node(c).is_ok());
+  assert!(root.get_first_child().is_none());
+}
+
+#[test]
+/// Moved nodes get the declarations they lost
+fn can_reconcile_namespaces() {
+  let parser = Parser::default();
+  let doc = parser
+    .parse_string(r#"<root><a xmlns:p="urn:p"><p:x p:id="1"/></a><b/></root>"#)
+    .unwrap();
+  let root = doc.get_root_element().unwrap();
+  let mut a = root.get_first_child().unwrap();
+  let mut b = a.get_next_sibling().unwrap();
+  let mut x = a.get_first_child().unwrap();
+  x.unlink();
+  a.unlink();
+  b.add_child(&mut x).unwrap();
+  assert_eq!(
+    doc.node_to_string(&root),
+    r#"<root><b><p:x p:id="1"/></b></root>"#
+  );
+
+  assert!(x.reconcile_namespaces().is_ok());
+  assert_eq!(
+    doc.node_to_string(&root),
+    r#"<root><b><p:x xmlns:p="urn:p" p:id="1"/></b></root>"#
+  );
+}
+
+#[test]
+/// Repeated declarations are hoisted to the root, unused ones removed
+fn can_normalize_namespaces() {
+  let parser = Parser::default();
+  let mut doc = parser
+    .parse_string(concat!(
+      r#"<root xmlns:unused="urn:unused">"#,
+      r#"<a xmlns:p="urn:p" xmlns:q="urn:q"><p:x/></a>"#,
+      r#"<b xmlns:p="urn:p" xmlns="urn:default"><p:y p:id="1"/><z/></b>"#,
+      r#"<c xmlns:r="urn:r1"><r:z/></c><d xmlns:r="urn:r2"><r:z/></d>"#,
+      r#"</root>"#
+    ))
+    .unwrap();
+  let root = doc.get_root_element().unwrap();
+  let declaration = root.get_namespace_declarations().pop().unwrap();
+
+  assert!(doc.normalize_namespaces().is_ok());
+  assert_eq!(
+    doc.node_to_string(&root),
+    concat!(
+      r#"<root xmlns:p="urn:p">"#,
+      r#"<a><p:x/></a>"#,
+      r#"<b xmlns="urn:default"><p:y p:id="1"/><z/></b>"#,
+      r#"<c xmlns:r="urn:r1"><r:z/></c><d xmlns:r="urn:r2"><r:z/></d>"#,
+      r#"</root>"#
+    )
+  );
+  // removed declarations stay valid while referenced
+  assert_eq!(declaration.get_prefix(), "unused");
+  assert!(doc.normalize_namespaces().is_ok());
+  assert_eq!(root.get_namespace_declarations().len(), 1);
+
+  // prefixes used in QName content keep their declarations
+  let mut doc = parser
+    .parse_string(concat!(
+      r#"<r xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance">"#,
+      r#"<a xmlns:t="urn:t" xmlns:u="urn:u" xsi:type="t:Foo"/>"#,
+      r#"<b xmlns:v="urn:v">v:Bar</b><c xmlns:w="urn:w">nov:w</c>"#,
+      r#"</r>"#
+    ))
+    .unwrap();
+  assert!(doc.normalize_namespaces().is_ok());
+  assert_eq!(
+    doc.node_to_string(&doc.get_root_element().unwrap()),
+    concat!(
+      r#"<r xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance">"#,
+      r#"<a xmlns:t="urn:t" xsi:type="t:Foo"/>"#,
+      r#"<b xmlns:v="urn:v">v:Bar</b><c>nov:w</c>"#,
+      r#"</r>"#
+    )
+  );
+}
+
+#[test]
+/// Namespace declarations can be changed, renamed and removed
+fn can_edit_ns_declarations() {
+  let parser = Parser::default();
+  let doc = parser
+    .parse_string(
+      r#"<root xmlns:p="urn:p"><a xmlns:p="urn:p" xmlns:q="urn:q"><p:x q:id="1"/></a></root>"#,
+    )
+    .unwrap();
+  let root = doc.get_root_element().unwrap();
+  let mut a = root.get_first_child().unwrap();
+
+  // redeclared declarations fall back to the ancestor's, used ones stay
+  assert!(a.remove_namespace_declaration("p").is_ok());
+  assert!(a.remove_namespace_declaration("q").is_err());
+  assert!(a.remove_namespace_declaration("missing").is_ok());
+  assert_eq!(
+    doc.node_to_string(&a),
+    r#"<a xmlns:q="urn:q"><p:x q:id="1"/></a>"#
+  );
+
+  assert!(a.set_namespace_declaration_prefix("q", "p").is_err());
+  assert!(a.set_namespace_declaration_prefix("q", "r").is_ok());
+  assert_eq!(
+    doc.node_to_string(&a),
+    r#"<a xmlns:r="urn:q"><p:x r:id="1"/></a>"#
+  );
+
+  let ns = a.set_namespace_declaration("r", "urn:r", true).unwrap();
+  assert_eq!(ns.get_href(), "urn:r");
+  let ns = a
+    .set_namespace_declaration("", "urn:default", false)
+    .unwrap();
+  assert_eq!(ns.get_prefix(), "");
+  assert_eq!(
+    doc.node_to_string(&a),
+    r#"<r:a xmlns:r="urn:r" xmlns="urn:default"><p:x r:id="1"/></r:a>"#
+  );
+
+  assert!(a.set_namespace_by_prefix("").is_ok());
+  assert_eq!(a.get_namespace().unwrap().get_href(), "urn:default");
+  assert!(a.set_namespace_by_prefix("p").is_ok());
+  assert_eq!(a.get_namespace().unwrap().get_href(), "urn:p");
+  assert!(a.set_namespace_by_prefix("missing").is_err());
+}
+
 #[test]
 fn can_work_with_ns_declarations() {
   let mut doc = Document::new().unwrap();
@@ -312,6 +566,440 @@ fn can_work_with_ns_declarations() {
   assert_eq!(declarations.len(), 2);
 }
 
+#[test]
+/// Nodes of every kind can be created
+fn can_create_node_types() {
+  let mut doc = Document::new().unwrap();
+  let mut root = Node::new("book", None, &doc).unwrap();
+  doc.set_root_element(&root);
+  let dtd = doc
+    .create_internal_subset(
+      "book",
+      Some("-//OASIS//DTD DocBook XML V4.5//EN"),
+      Some("http://www.oasis-open.org/docbook/xml/4.5/docbookx.dtd"),
+    )
+    .unwrap();
+  assert_eq!(dtd.get_type(), Some(NodeType::DTDNode));
+  assert!(doc.create_internal_subset("book", None, None).is_err());
+
+  let mut comment = Node::new_comment(" generated ", &doc).unwrap();
+  assert_eq!(comment.get_type(), Some(NodeType::CommentNode));
+  let mut cdata = Node::new_cdata("a < b && c", &doc).unwrap();
+  assert_eq!(cdata.get_type(), Some(NodeType::CDataSectionNode));
+  let mut reference = Node::new_entity_reference("&amp;", &doc).unwrap();
+  assert_eq!(reference.get_type(), Some(NodeType::EntityRefNode));
+  assert_eq!(reference.get_name(), "amp");
+  let fragment = Node::new_document_fragment(&doc).unwrap();
+  assert_eq!(fragment.get_type(), Some(NodeType::DocumentFragNode));
+
+  root.add_child(&mut comment).unwrap();
+  root.add_child(&mut cdata).unwrap();
+  root.add_child(&mut reference).unwrap();
+  assert_eq!(
+    doc.to_string(false),
+    concat!(
+      "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n",
+      "<!DOCTYPE book PUBLIC \"-//OASIS//DTD DocBook XML V4.5//EN\" ",
+      "\"http://www.oasis-open.org/docbook/xml/4.5/docbookx.dtd\">\n",
+      "<book><!-- generated --><![CDATA[a < b && c]]>&amp;</book>\n"
+    )
+  );
+}
+
+#[test]
+/// Nodes built up in a fragment are inserted as a group
+fn can_insert_document_fragments() {
+  let parser = Parser::default();
+  let doc = parser.parse_string("<ul><li>middle</li></ul>").unwrap();
+  let mut list = doc.get_root_element().unwrap();
+  let mut middle = list.get_first_child().unwrap();
+
+  let mut fragment = DocumentFragment::new(&doc).unwrap();
+  assert!(fragment.is_empty());
+  fragment
+    .new_child(None, "li")
+    .unwrap()
+    .append_text("last")
+    .unwrap();
+  let mut comment = Node::new_comment(" end ", &doc).unwrap();
+  fragment.add_child(&mut comment).unwrap();
+  assert_eq!(fragment.get_child_nodes().len(), 2);
+
+  list.add_child(&mut fragment).unwrap();
+  assert!(fragment.is_empty());
+  assert_eq!(
+    doc.node_to_string(&list),
+    "<ul><li>middle</li><li>last</li><!-- end --></ul>"
+  );
+
+  // fragments are reusable, for siblings as well
+  for name in &["first", "second"] {
+    let mut item = Node::new("li", None, &doc).unwrap();
+    item.append_text(name).unwrap();
+    fragment.add_child(&mut item).unwrap();
+  }
+  middle.add_prev_sibling(&mut fragment).unwrap();
+  for name in &["third", "fourth"] {
+    let mut item = Node::new("li", None, &doc).unwrap();
+    item.append_text(name).unwrap();
+    fragment.add_child(&mut item).unwrap();
+  }
+  middle.add_next_sibling(&mut fragment).unwrap();
+  assert_eq!(
+    doc.node_to_string(&list),
+    concat!(
+      "<ul><li>first</li><li>second</li><li>middle</li><li>third</li><li>fourth</li>",
+      "<li>last</li><!-- end --></ul>"
+    )
+  );
+  assert_eq!(
+    fragment.as_node().get_type(),
+    Some(NodeType::DocumentFragNode)
+  );
+}
+
+#[test]
+/// Can copy nodes, within and across documents
+fn can_clone_nodes() {
+  let parser = Parser::default();
+  let doc = parser
+    .parse_string(r#"<r xmlns:x="urn:x"><x:a id="1"><b>text</b></x:a></r>"#)
+    .unwrap();
+  let root = doc.get_root_element().unwrap();
+  let a = root.get_first_child().unwrap();
+
+  let mut deep = a.clone_node(true).unwrap();
+  assert_ne!(deep, a);
+  assert!(deep.get_parent().is_none());
+  // namespaces in scope of the original are declared on the copy
+  assert_eq!(
+    doc.node_to_string(&deep),
+    r#"<x:a xmlns:x="urn:x" id="1"><b>text</b></x:a>"#
+  );
+  let shallow = a.clone_node(false).unwrap();
+  assert_eq!(shallow.get_attribute("id"), Some("1".to_owned()));
+  assert!(shallow.get_first_child().is_none());
+
+  // the copy is independent of the original
+  deep.set_attribute("id", "2").unwrap();
+  assert_eq!(a.get_attribute("id"), Some("1".to_owned()));
+
+  let other = Document::new().unwrap();
+  let mut copy = a.copy_to(&other, true).unwrap();
+  assert_eq!(
+    other.node_to_string(&copy),
+    r#"<x:a xmlns:x="urn:x" id="1"><b>text</b></x:a>"#
+  );
+  let mut other_root = Node::new("root", None, &other).unwrap();
+  other_root.add_child(&mut copy).unwrap();
+  assert_eq!(copy.get_parent(), Some(other_root));
+
+  let doc_node = doc.as_node();
+  assert!(doc_node.clone_node(true).is_err());
+}
+
+#[test]
+/// Can insert, replace, remove, wrap and unwrap nodes
+fn can_rewrite_tree() {
+  let parser = Parser::default();
+  let doc = parser.parse_string("<r><b/><d/></r>").unwrap();
+  let mut root = doc.get_root_element().unwrap();
+  let b = root.get_first_child().unwrap();
+  let d = root.get_last_child().unwrap();
+
+  let mut a = Node::new("a", None, &doc).unwrap();
+  root.prepend_child(&mut a).unwrap();
+  let mut c = Node::new("c", None, &doc).unwrap();
+  root.insert_before(&mut c, Some(&d)).unwrap();
+  let mut e = Node::new("e", None, &doc).unwrap();
+  root.insert_after(&mut e, Some(&d)).unwrap();
+  let mut f = Node::new("f", None, &doc).unwrap();
+  root.insert_before(&mut f, None).unwrap();
+  assert_eq!(doc.node_to_string(&root), "<r><a/><b/><c/><d/><e/><f/></r>");
+  // the reference has to be a child
+  let mut g = Node::new("g", None, &doc).unwrap();
+  assert!(c.clone().insert_before(&mut g, Some(&b)).is_err());
+
+  let f = root.remove_child(f).unwrap();
+  assert!(f.is_unlinked());
+  let mut d = d;
+  d.replace_with(&mut g).unwrap();
+  assert!(d.is_unlinked());
+  assert_eq!(doc.node_to_string(&root), "<r><a/><b/><c/><g/><e/></r>");
+
+  let mut wrapper = Node::new("w", None, &doc).unwrap();
+  g.wrap_in(&mut wrapper).unwrap();
+  assert_eq!(
+    doc.node_to_string(&root),
+    "<r><a/><b/><c/><w><g/></w><e/></r>"
+  );
+  assert!(wrapper.clone().wrap_in(&mut root).is_err());
+  wrapper.unwrap().unwrap();
+  assert!(wrapper.is_unlinked());
+  assert_eq!(doc.node_to_string(&root), "<r><a/><b/><c/><g/><e/></r>");
+
+  // a fragment takes the place of the replaced node
+  let mut fragment = DocumentFragment::new(&doc).unwrap();
+  fragment.append_text("text").unwrap();
+  let mut x = Node::new("x", None, &doc).unwrap();
+  fragment.add_child(&mut x).unwrap();
+  g.replace_with(&mut fragment).unwrap();
+  assert_eq!(doc.node_to_string(&root), "<r><a/><b/><c/>text<x/><e/></r>");
+
+  // nodes can not be moved into their own subtree
+  let mut y = Node::new("y", None, &doc).unwrap();
+  x.add_child(&mut y).unwrap();
+  assert!(y.clone().replace_with(&mut root).is_err());
+  assert!(y.clone().replace_with(&mut x).is_err());
+  assert!(y.clone().insert_before(&mut x, None).is_err());
+  assert!(y.clone().insert_after(&mut root, None).is_err());
+  assert!(x.clone().insert_before(&mut x, None).is_err());
+  assert!(y.clone().prepend_child(&mut x).is_err());
+  assert_eq!(
+    doc.node_to_string(&root),
+    "<r><a/><b/><c/>text<x><y/></x><e/></r>"
+  );
+
+  // attributes only replace attributes
+  x.set_attribute("id", "1").unwrap();
+  let mut attribute = x.get_property_node("id").unwrap();
+  assert!(y.clone().replace_with(&mut attribute).is_err());
+  assert!(attribute.clone().replace_with(&mut y.clone()).is_err());
+  assert!(attribute
+    .wrap_in(&mut Node::new("w", None, &doc).unwrap())
+    .is_err());
+  assert!(!y.is_unlinked());
+  assert_eq!(
+    doc.node_to_string(&root),
+    "<r><a/><b/><c/>text<x id=\"1\"><y/></x><e/></r>"
+  );
+
+  root.remove_all_children().unwrap();
+  assert!(root.get_first_child().is_none());
+  assert!(b.is_unlinked());
+}
+
+#[test]
+/// Node handles are released independently, and keep their document alive
+fn node_handle_lifetimes() {
+  let parser = Parser::default();
+  let doc = parser.parse_string("<r><a><b>text</b></a></r>").unwrap();
+  let root = doc.get_root_element().unwrap();
+
+  // identity holds across handles wrapped again after being dropped
+  let a_ptr = root.get_first_child().unwrap().node_ptr();
+  for child in root.get_child_nodes() {
+    assert_eq!(child.node_ptr(), a_ptr);
+  }
+  assert_eq!(root.get_first_child().unwrap().node_ptr(), a_ptr);
+
+  // an unlinked subtree is kept while a handle refers into it
+  let mut a = root.get_first_child().unwrap();
+  let b = a.get_first_child().unwrap();
+  a.unlink();
+  drop(a);
+  let mut a = b.get_parent().unwrap();
+  assert!(a.is_unlinked());
+  assert_eq!(a.get_name(), "a");
+  let mut root = root;
+  root.add_child(&mut a).unwrap();
+  assert!(!a.is_unlinked());
+
+  // and freed along with the last handle into it, whichever order they are dropped in
+  for _ in 0..3 {
+    let mut unlinked = root.clone().new_child(None, "unlinked").unwrap();
+    unlinked.append_text("text").unwrap();
+    let text = unlinked.get_first_child().unwrap();
+    unlinked.unlink();
+    drop(unlinked);
+    assert_eq!(text.get_parent().unwrap().get_name(), "unlinked");
+    drop(text);
+  }
+  assert_eq!(root.get_child_nodes().len(), 1);
+
+  // a node created and dropped without being inserted is freed right away
+  for _ in 0..3 {
+    let mut orphan = Node::new("orphan", None, &doc).unwrap();
+    orphan.append_text("text").unwrap();
+  }
+
+  drop(doc);
+  drop(root);
+  assert_eq!(b.get_content(), "text");
+  assert_eq!(a.get_name(), "a");
+}
+
+#[test]
+/// Deleting a node frees its subtree and nulls the handles into it
+fn can_delete_nodes() {
+  let parser = Parser::default();
+  let doc = parser
+    .parse_string(r#"<r><a id="1"><b>text</b></a><c/></r>"#)
+    .unwrap();
+  let root = doc.get_root_element().unwrap();
+  let mut a = root.get_first_child().unwrap();
+  let mut b = a.get_first_child().unwrap();
+  let text = b.get_first_child().unwrap();
+  let id = a.get_property_node("id").unwrap();
+  let c = a.clone();
+
+  a.delete().unwrap();
+  assert_eq!(doc.node_to_string(&root), "<r><c/></r>");
+  for handle in &[&a, &b, &text, &id, &c] {
+    assert!(handle.is_null());
+  }
+  assert_eq!(b.get_name(), "");
+  assert!(b.get_parent().is_none());
+  assert!(b.set_content("again").is_err());
+  assert!(a.remove().is_err());
+
+  // XPath results skip deleted nodes, which are freed once the results are gone
+  let mut c = root.get_first_child().unwrap();
+  let context = Context::new(&doc).unwrap();
+  let result = context.evaluate("//c").unwrap();
+  c.remove().unwrap();
+  assert!(c.is_null());
+  assert!(result.get_nodes_as_vec().is_empty());
+  assert_eq!(doc.node_to_string(&root), "<r/>");
+}
+
+#[test]
+/// Can split, merge and strip text nodes
+fn can_manipulate_text_nodes() {
+  let parser = Parser::default();
+  let mut doc = parser
+    .parse_string("<r>\n  <p>Grüße, world</p>\n  <pre xml:space=\"preserve\"> <b/> </pre>\n</r>")
+    .unwrap();
+  let root = doc.get_root_element().unwrap();
+  let mut p = root.get_first_element_child().unwrap();
+  let mut text = p.get_first_child().unwrap();
+  assert!(!text.is_blank());
+  assert!(root.get_first_child().unwrap().is_blank());
+
+  // offsets count characters
+  let world = text.split_text(6).unwrap();
+  assert_eq!(text.get_content(), "Grüße,");
+  assert_eq!(world.get_content(), " world");
+  assert_eq!(p.get_child_nodes().len(), 2);
+  assert_eq!(text.get_next_sibling(), Some(world.clone()));
+  assert!(text.split_text(7).is_err());
+  let mut empty = Node::new_text("", &doc).unwrap();
+  p.add_child(&mut empty).unwrap();
+
+  p.normalize().unwrap();
+  assert_eq!(p.get_child_nodes().len(), 1);
+  assert_eq!(text.get_content(), "Grüße, world");
+  assert!(world.get_parent().is_none());
+
+  doc.strip_blank_nodes();
+  assert_eq!(
+    doc.node_to_string(&root),
+    "<r><p>Grüße, world</p><pre xml:space=\"preserve\"> <b/> </pre></r>"
+  );
+}
+
+#[test]
+/// Can declare, look up and substitute entities
+fn can_handle_entities() {
+  let input = "<!DOCTYPE r [<!ENTITY co \"ACME &amp; Co\">]><r>&co;</r>";
+  let parser = Parser::default();
+  let mut doc = parser.parse_string(input).unwrap();
+  let mut root = doc.get_root_element().unwrap();
+  let reference = root.get_first_child().unwrap();
+  assert_eq!(reference.get_type(), Some(NodeType::EntityRefNode));
+  assert_eq!(reference.get_name(), "co");
+
+  let entity = doc.get_entity("co").unwrap();
+  assert_eq!(entity.name(), "co");
+  assert_eq!(entity.content(), Some(String::from("ACME &amp; Co")));
+  assert!(!entity.is_external());
+  assert!(doc.get_entity("lt").is_none());
+  assert!(doc.get_entity("missing").is_none());
+
+  let year = doc.add_entity("year", "2026").unwrap();
+  let chapter = doc
+    .add_external_entity("chapter", None, "chapter.xml")
+    .unwrap();
+  assert!(chapter.is_external());
+  assert_eq!(chapter.system_id(), Some(String::from("chapter.xml")));
+  assert_eq!(chapter.external_id(), None);
+  assert!(doc.add_entity("year", "2027").is_err());
+  assert_eq!(doc.get_entities(), vec![entity, year, chapter]);
+
+  let mut year_reference = Node::new_entity_reference("year", &doc).unwrap();
+  root.add_child(&mut year_reference).unwrap();
+  assert_eq!(doc.node_to_string(&root), "<r>&co;&year;</r>");
+
+  // entities can only be declared within a DTD
+  let mut plain = parser.parse_string("<r/>").unwrap();
+  assert!(plain.add_entity("year", "2026").is_err());
+
+  let parser = parser.with_entity_substitution(true);
+  let substituted = parser.parse_string(input).unwrap();
+  let root = substituted.get_root_element().unwrap();
+  assert_eq!(root.get_child_nodes().len(), 1);
+  assert_eq!(
+    root.get_first_child().unwrap().get_type(),
+    Some(NodeType::TextNode)
+  );
+  assert_eq!(root.get_content(), "ACME & Co");
+}
+
+#[test]
+/// Can inspect, replace and remove the DOCTYPE of a document
+fn can_handle_doctype() {
+  let parser = Parser::default();
+  let mut doc = parser
+    .parse_string("<!DOCTYPE r SYSTEM \"r.dtd\" [<!ENTITY e \"entity\">]><r>&e;</r>")
+    .unwrap();
+  assert_eq!(
+    doc.doctype(),
+    Some(Doctype {
+      name: String::from("r"),
+      public_id: None,
+      system_id: Some(String::from("r.dtd")),
+    })
+  );
+
+  let dtd = doc
+    .set_doctype(
+      "r",
+      Some("-//Partner//DTD Feed 2.0//EN"),
+      Some("http://example.com/feed.dtd"),
+    )
+    .unwrap();
+  assert_eq!(dtd.get_type(), Some(NodeType::DTDNode));
+  assert_eq!(
+    doc.doctype().unwrap().public_id,
+    Some(String::from("-//Partner//DTD Feed 2.0//EN"))
+  );
+  assert!(doc.get_entity("e").is_none());
+  assert_eq!(
+    doc.to_string(false),
+    "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<!DOCTYPE r PUBLIC \"-//Partner//DTD Feed 2.0//EN\" \"http://example.com/feed.dtd\">\n<r>&e;</r>\n"
+  );
+
+  let removed = doc.remove_doctype().unwrap();
+  assert_eq!(removed, dtd);
+  assert!(removed.is_unlinked());
+  assert!(doc.doctype().is_none());
+  assert!(doc.remove_doctype().is_none());
+  assert_eq!(
+    doc.to_string(false),
+    "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<r>&e;</r>\n"
+  );
+
+  let mut html = Document::new_html().unwrap();
+  assert_eq!(html.doctype().unwrap().name, "html");
+  let root = Node::new("html", None, &html).unwrap();
+  html.set_root_element(&root);
+  assert_eq!(
+    html.get_root_element().unwrap().get_type(),
+    Some(NodeType::ElementNode)
+  );
+  assert!(html.to_string(false).contains("<!DOCTYPE html>\n<html/>"));
+}
+
 #[test]
 /// Can view documents as nodes
 fn can_cast_doc_to_node() {
@@ -2,7 +2,8 @@
 //!
 
 use libxml::parser::Parser;
-use libxml::xpath::Context;
+use libxml::tree::{Document, Node};
+use libxml::xpath::{Context, NodeSetItem, Value, XPathErrorCode, XPathExpression};
 
 #[test]
 /// Test the evaluation of an xpath expression yields the correct number of nodes
@@ -159,3 +160,505 @@ fn safe_context_clone() {
   let body2 = context2.evaluate("/html/body").unwrap().get_nodes_as_vec();
   assert_eq!(body2.len(), 1);
 }
+
+#[test]
+/// Variables can be bound on a context, rather than interpolated into the expression
+fn xpath_variable_binding() {
+  let parser = Parser::default();
+  let doc_result = parser.parse_file("tests/resources/file01.xml");
+  assert!(doc_result.is_ok());
+  let doc = doc_result.unwrap();
+  let context = Context::new(&doc).unwrap();
+
+  assert!(context.register_variable("attr", "empty").is_ok());
+  let result = context.evaluate("//child[@attribute=$attr]").unwrap();
+  let nodes = result.get_nodes_as_vec();
+  assert_eq!(nodes.len(), 1);
+  assert_eq!(nodes[0].get_content(), "more text");
+
+  // hostile input stays a plain string value
+  assert!(context
+    .register_variable("attr", "value' or '1'='1")
+    .is_ok());
+  let result = context.evaluate("//child[@attribute=$attr]").unwrap();
+  assert_eq!(result.get_number_of_nodes(), 0);
+
+  assert!(context.register_variable("index", 2.0).is_ok());
+  let result = context.evaluate("string(//child[$index])").unwrap();
+  assert_eq!(result.to_string(), "more text");
+
+  assert!(context.register_variable("flag", true).is_ok());
+  let result = context.evaluate("string($flag)").unwrap();
+  assert_eq!(result.to_string(), "true");
+
+  let children = context.evaluate("//child").unwrap().get_nodes_as_vec();
+  assert!(context.register_variable("children", children).is_ok());
+  let result = context.evaluate("$children[@attribute='value']").unwrap();
+  assert_eq!(result.get_number_of_nodes(), 1);
+
+  // unbound variables fail to evaluate
+  assert!(context.evaluate("$missing").is_err());
+}
+
+#[test]
+/// Values libxml2 can not represent are rejected rather than panicking
+fn xpath_variable_binding_rejects_nul() {
+  let parser = Parser::default();
+  let doc = parser.parse_string("<root><a>x</a></root>").unwrap();
+  let context = Context::new(&doc).unwrap();
+
+  assert!(context.register_variable("x", "a\0b").is_err());
+  assert!(context.register_variable("x\0y", "ab").is_err());
+  assert!(context.register_variable_ns("x", "urn:\0", "ab").is_err());
+  assert!(context.evaluate("$x").is_err());
+
+  context.register_variable_lookup(|name, _| match name {
+    "looked_up" => Some(Value::from("a\0b")),
+    _ => None,
+  });
+  assert!(context.evaluate("string($looked_up)").is_err());
+}
+
+#[test]
+/// Nodes bound to a variable stay alive while the binding refers to them
+fn xpath_variable_binding_keeps_nodes_alive() {
+  let parser = Parser::default();
+  let doc = parser.parse_string("<root><a>x</a></root>").unwrap();
+  let context = Context::new(&doc).unwrap();
+  let mut a = doc.get_root_element().unwrap().get_first_child().unwrap();
+
+  assert!(context.register_variable("n", a.clone()).is_ok());
+  a.delete().unwrap();
+  let result = context.evaluate("string($n)").unwrap();
+  assert_eq!(result.to_string(), "x");
+  assert_eq!(context.evaluate("count(/root/a)").unwrap().to_string(), "0");
+
+  // rebinding the variable releases the deleted node
+  assert!(context.register_variable("n", 1.0).is_ok());
+  assert_eq!(context.evaluate("$n").unwrap().to_string(), "1");
+}
+
+#[test]
+/// Variables can be resolved on demand by a lookup callback
+fn xpath_variable_lookup() {
+  let parser = Parser::default();
+  let doc_result = parser.parse_file("tests/resources/file01.xml");
+  assert!(doc_result.is_ok());
+  let doc = doc_result.unwrap();
+  let context = Context::new(&doc).unwrap();
+
+  assert!(context.register_variable("registered", "value").is_ok());
+  context.register_variable_lookup(|name, ns_uri| match (name, ns_uri) {
+    ("attr", None) => Some(Value::from("empty")),
+    _ => None,
+  });
+
+  let result = context.evaluate("//child[@attribute=$attr]").unwrap();
+  assert_eq!(result.get_number_of_nodes(), 1);
+  // declined lookups fall back to the registered variables
+  let result = context.evaluate("//child[@attribute=$registered]").unwrap();
+  assert_eq!(result.get_number_of_nodes(), 1);
+  assert!(context.evaluate("$missing").is_err());
+}
+
+#[test]
+/// Rust closures can be registered as XPath extension functions
+fn xpath_extension_functions() {
+  let parser = Parser::default();
+  let doc_result = parser.parse_file("tests/resources/file01.xml");
+  assert!(doc_result.is_ok());
+  let doc = doc_result.unwrap();
+  let context = Context::new(&doc).unwrap();
+
+  assert!(context
+    .register_function("lower-case", |args| match args.as_slice() {
+      [Value::String(s)] => Ok(Value::String(s.to_lowercase())),
+      [Value::Nodes(nodes)] => Ok(Value::String(
+        nodes
+          .first()
+          .map(|node| node.get_content().to_lowercase())
+          .unwrap_or_default(),
+      )),
+      _ => Err(()),
+    })
+    .is_ok());
+  let result = context
+    .evaluate("//child[lower-case(@attribute)='value']")
+    .unwrap();
+  assert_eq!(result.get_number_of_nodes(), 1);
+  let result = context.evaluate("lower-case('MORE Text')").unwrap();
+  assert_eq!(result.get_value(), Value::String("more text".to_owned()));
+  // errors returned by the closure fail the evaluation
+  assert!(context.evaluate("lower-case(1, 2)").is_err());
+
+  assert!(context.register_namespace("ext", "urn:ext").is_ok());
+  assert!(context
+    .register_function_ns("count-text", "urn:ext", |args| {
+      let count = args
+        .iter()
+        .map(|arg| match arg {
+          Value::Nodes(nodes) => nodes.iter().filter(|n| n.is_text_node()).count(),
+          _ => 0,
+        })
+        .sum::<usize>();
+      Ok(Value::Number(count as f64))
+    })
+    .is_ok());
+  let result = context.evaluate("ext:count-text(//child/text())").unwrap();
+  assert_eq!(result.get_value(), Value::Number(2.0));
+  // namespaced functions are not visible without their namespace
+  assert!(context.evaluate("count-text(//child/text())").is_err());
+}
+
+#[test]
+/// Nodes created by extension functions stay alive as long as the evaluation result
+fn xpath_functions_return_new_nodes() {
+  let parser = Parser::default();
+  let doc = parser.parse_string("<root><a>x</a></root>").unwrap();
+  let context = Context::new(&doc).unwrap();
+
+  let function_doc = doc.clone();
+  assert!(context
+    .register_function("greeting", move |_| {
+      let text = Node::new_text("hello", &function_doc).map_err(|_| ())?;
+      Ok(Value::Nodes(vec![text]))
+    })
+    .is_ok());
+
+  let result = context.evaluate("greeting()").unwrap();
+  assert_eq!(result.to_string(), "hello");
+  let nodes = result.get_nodes_as_vec();
+  assert_eq!(nodes.len(), 1);
+  assert!(nodes[0].is_unlinked());
+  drop(result);
+  assert_eq!(nodes[0].get_content(), "hello");
+}
+
+#[test]
+/// Nodes created by a variable lookup stay alive as long as the evaluation result
+fn xpath_variable_lookup_returns_new_nodes() {
+  let parser = Parser::default();
+  let doc = parser.parse_string("<root><a>x</a></root>").unwrap();
+  let context = Context::new(&doc).unwrap();
+
+  let lookup_doc = doc.clone();
+  context.register_variable_lookup(move |name, _| match name {
+    "created" => {
+      let mut element = Node::new("created", None, &lookup_doc).ok()?;
+      element.append_text("text").ok()?;
+      Some(Value::Nodes(vec![element]))
+    }
+    _ => None,
+  });
+
+  let result = context.evaluate("$created").unwrap();
+  assert_eq!(result.to_string(), "text");
+  assert_eq!(result.get_nodes_as_vec()[0].get_name(), "created");
+  assert_eq!(
+    context
+      .evaluate("concat(name($created), $created)")
+      .unwrap()
+      .to_string(),
+    "createdtext"
+  );
+}
+
+#[test]
+/// Compiled expressions can be evaluated repeatedly, on contexts and nodes
+fn xpath_compiled_expressions() {
+  assert!(XPathExpression::compile("//child[").is_err());
+  assert!(XPathExpression::compile("").is_err());
+
+  let children = XPathExpression::compile("//child").unwrap();
+  let text = XPathExpression::compile("text()").unwrap();
+
+  let parser = Parser::default();
+  for _ in 0..2 {
+    let doc = parser.parse_file("tests/resources/file01.xml").unwrap();
+    let mut context = Context::new(&doc).unwrap();
+    let result = context.evaluate_compiled(&children).unwrap();
+    assert_eq!(result.get_number_of_nodes(), 2);
+
+    let nodes = context.findnodes_compiled(&children, None).unwrap();
+    assert_eq!(nodes.len(), 2);
+    for node in &nodes {
+      let texts = node.findnodes_compiled(&text).unwrap();
+      assert_eq!(texts.len(), 1);
+      assert!(texts[0].is_text_node());
+    }
+    let result = context.node_evaluate_compiled(&text, &nodes[1]).unwrap();
+    assert_eq!(result.to_string(), "more text");
+  }
+}
+
+#[test]
+/// Failed compilations and evaluations report the libxml2 error
+fn xpath_error_reporting() {
+  let parser = Parser::default();
+  let doc = parser.parse_file("tests/resources/file01.xml").unwrap();
+  let context = Context::new(&doc).unwrap();
+
+  let error = context.evaluate("//child[@attribute=$id]").err().unwrap();
+  assert_eq!(error.code, XPathErrorCode::UndefVariableError);
+  assert_eq!(error.message, "Undefined variable");
+  assert_eq!(error.expression, Some("//child[@attribute=$id]".to_owned()));
+  assert_eq!(error.offset, Some(23));
+
+  let error = context.evaluate("//child[").err().unwrap();
+  assert_eq!(error.code, XPathErrorCode::ExprError);
+  assert_eq!(error.offset, Some(8));
+  assert!(error.to_string().contains("Invalid expression"));
+
+  let root = doc.get_root_element().unwrap();
+  let error = root.findnodes("unknown:child").err().unwrap();
+  assert_eq!(error.code, XPathErrorCode::UndefPrefixError);
+
+  let error = XPathExpression::compile("count(//child, 'é')]")
+    .err()
+    .unwrap();
+  assert_eq!(error.code, XPathErrorCode::ExprError);
+  assert_eq!(error.offset, Some(19));
+
+  // compiled expressions report their source
+  let expression = XPathExpression::compile("missing-function(//child)").unwrap();
+  let error = context.evaluate_compiled(&expression).err().unwrap();
+  assert_eq!(error.code, XPathErrorCode::UnknownFuncError);
+  assert_eq!(
+    error.expression,
+    Some("missing-function(//child)".to_owned())
+  );
+
+  // a successful evaluation is not affected by earlier errors
+  assert!(context.evaluate("//child").is_ok());
+}
+
+#[test]
+/// Convenience finders on nodes and documents
+fn xpath_convenience_finders() {
+  let parser = Parser::default();
+  let doc = parser.parse_file("tests/resources/file01.xml").unwrap();
+
+  assert_eq!(doc.findnodes("//child").unwrap().len(), 2);
+  assert_eq!(
+    doc.findvalue("//child[2]/@attribute", &[]).unwrap(),
+    "empty"
+  );
+  let first = doc.find_first("//child", &[]).unwrap().unwrap();
+  assert_eq!(first.get_content(), "some text");
+  assert!(doc.find_first("//nonexistent", &[]).unwrap().is_none());
+  assert_eq!(doc.find_number("count(//child)", &[]).unwrap(), 2.0);
+  assert!(doc.find_number("number('NaN')", &[]).unwrap().is_nan());
+  assert!(doc.find_bool("count(//child) = 2", &[]).unwrap());
+  assert!(doc.exists("//child[@attribute='value']", &[]).unwrap());
+  assert!(!doc.exists("//child[@attribute='other']", &[]).unwrap());
+
+  let root = doc.get_root_element().unwrap();
+  assert_eq!(root.findvalue("child[1]", &[]).unwrap(), "some text");
+  let second = root.find_first("child[2]", &[]).unwrap().unwrap();
+  assert_eq!(second.get_attribute("attribute"), Some("empty".to_owned()));
+  assert_eq!(second.find_number("string-length(.)", &[]).unwrap(), 9.0);
+  assert!(second.find_bool("@attribute = 'empty'", &[]).unwrap());
+  assert!(!second.exists("child", &[]).unwrap());
+  assert!(root.exists("child", &[]).unwrap());
+  assert!(root.findvalue("child[", &[]).is_err());
+}
+
+#[test]
+/// Convenience finders accept namespace mappings
+fn xpath_convenience_finders_with_namespaces() {
+  let parser = Parser::default();
+  let doc = parser
+    .parse_file("tests/resources/simple_namespaces.xml")
+    .unwrap();
+  let namespaces = [
+    ("h", "http://example.com/ns/hello"),
+    ("f", "http://example.com/ns/farewell"),
+  ];
+
+  assert_eq!(doc.find_number("count(//h:td)", &namespaces).unwrap(), 3.0);
+  assert!(doc.exists("//f:footer//h:td", &namespaces).unwrap());
+  assert!(doc.exists("//h:td", &[]).is_err());
+
+  let footer = doc.find_first("//f:footer", &namespaces).unwrap().unwrap();
+  assert_eq!(
+    footer.findvalue("h:table//h:td", &namespaces).unwrap(),
+    "col 3"
+  );
+  assert_eq!(
+    footer
+      .find_first("descendant::f:footer", &namespaces)
+      .unwrap()
+      .unwrap()
+      .get_content(),
+    " nested f"
+  );
+}
+
+#[test]
+/// Evaluation results keep their document alive
+fn xpath_result_outlives_document() {
+  let parser = Parser::default();
+  let doc = parser.parse_file("tests/resources/file01.xml").unwrap();
+  let result = {
+    let context = Context::new(&doc).unwrap();
+    context.evaluate("//child").unwrap()
+  };
+  drop(doc);
+
+  let nodes = result.get_nodes_as_vec();
+  assert_eq!(nodes.len(), 2);
+  assert_eq!(nodes[0].get_content(), "some text");
+  assert_eq!(
+    nodes[1].get_attribute("attribute"),
+    Some("empty".to_owned())
+  );
+}
+
+#[test]
+/// Nodes unlinked or replaced after the evaluation are safe to iterate
+fn xpath_result_survives_mutation() {
+  let parser = Parser::default();
+  let doc = parser.parse_file("tests/resources/file01.xml").unwrap();
+  let context = Context::new(&doc).unwrap();
+
+  let children = context.evaluate("//child").unwrap();
+  for mut child in doc.findnodes("//child").unwrap() {
+    child.unlink();
+  }
+  let nodes = children.get_nodes_as_vec();
+  assert_eq!(nodes.len(), 2);
+  assert!(nodes.iter().all(|node| node.is_unlinked()));
+  assert_eq!(nodes[1].get_content(), "more text");
+  drop(nodes);
+  assert_eq!(
+    context.evaluate("//child").unwrap().get_number_of_nodes(),
+    0
+  );
+
+  let doc = parser.parse_file("tests/resources/file01.xml").unwrap();
+  let context = Context::new(&doc).unwrap();
+  let texts = context.evaluate("//child/text()").unwrap();
+  let attributes = context.evaluate("//child/@attribute").unwrap();
+  let mut first = doc.find_first("//child", &[]).unwrap().unwrap();
+  first.set_content("replaced").unwrap();
+  first.remove_property("attribute").unwrap();
+  assert_eq!(first.get_content(), "replaced");
+  assert_eq!(first.get_attribute("attribute"), None);
+
+  let text_values: Vec<String> = texts
+    .get_nodes_as_vec()
+    .iter()
+    .map(|node| node.get_content())
+    .collect();
+  assert_eq!(text_values, vec!["some text", "more text"]);
+  let attribute_values: Vec<String> = attributes
+    .get_nodes_as_vec()
+    .iter()
+    .map(|node| node.get_content())
+    .collect();
+  assert_eq!(attribute_values, vec!["value", "empty"]);
+
+  // deleted nodes are skipped, and freed once the results are gone
+  let children = context.evaluate("//child").unwrap();
+  let attributes = context.evaluate("//child/@attribute").unwrap();
+  doc
+    .find_first("//child", &[])
+    .unwrap()
+    .unwrap()
+    .delete()
+    .unwrap();
+  assert_eq!(children.get_number_of_nodes(), 2);
+  let nodes = children.get_nodes_as_vec();
+  assert_eq!(nodes.len(), 1);
+  assert_eq!(nodes[0].get_content(), "more text");
+  drop(nodes);
+  assert_eq!(children.get_items().len(), 1);
+  assert_eq!(attributes.get_nodes_as_vec().len(), 1);
+  drop(children);
+  drop(attributes);
+  assert_eq!(doc.findnodes("//child").unwrap().len(), 1);
+}
+
+#[test]
+/// Results evaluated within a detached subtree
+fn xpath_result_in_detached_subtree() {
+  let doc = Document::new().unwrap();
+  let mut parent = Node::new("parent", None, &doc).unwrap();
+  parent.new_child(None, "child").unwrap();
+  parent.new_child(None, "child").unwrap();
+
+  let context = Context::new(&doc).unwrap();
+  let result = context.node_evaluate("child", &parent).unwrap();
+  let nodes = result.get_nodes_as_vec();
+  assert_eq!(nodes.len(), 2);
+  assert!(nodes.iter().all(|node| node.get_name() == "child"));
+}
+
+#[test]
+/// Node-set entries are typed, including attribute and namespace nodes
+fn xpath_typed_node_set_items() {
+  let parser = Parser::default();
+  let doc = parser
+    .parse_file("tests/resources/simple_namespaces.xml")
+    .unwrap();
+  let context = Context::new(&doc).unwrap();
+  context
+    .register_namespace("r", "http://example.com/ns/root")
+    .unwrap();
+
+  let namespaces = context.evaluate("/r:root/namespace::*").unwrap();
+  let mut declared: Vec<(String, String)> = namespaces
+    .get_items()
+    .iter()
+    .map(|item| match item {
+      NodeSetItem::Namespace(ns) => (ns.get_prefix(), ns.get_href()),
+      other => panic!("expected a namespace, got {:?}", other),
+    })
+    .collect();
+  drop(namespaces);
+  declared.sort();
+  assert_eq!(
+    declared,
+    vec![
+      ("f".to_owned(), "http://example.com/ns/farewell".to_owned()),
+      ("h".to_owned(), "http://example.com/ns/hello".to_owned()),
+      ("r".to_owned(), "http://example.com/ns/root".to_owned()),
+      (
+        "xml".to_owned(),
+        "http://www.w3.org/XML/1998/namespace".to_owned()
+      ),
+    ]
+  );
+  assert!(context
+    .evaluate("/r:root/namespace::*")
+    .unwrap()
+    .get_nodes_as_vec()
+    .is_empty());
+
+  let doc = parser.parse_file("tests/resources/file01.xml").unwrap();
+  let context = Context::new(&doc).unwrap();
+  let attributes = context.evaluate("//@attribute").unwrap().get_items();
+  assert_eq!(attributes.len(), 2);
+  match &attributes[1] {
+    NodeSetItem::Attribute(attribute) => {
+      assert_eq!(attribute.local_name(), "attribute");
+      assert_eq!(attribute.value(), "empty");
+    }
+    other => panic!("expected an attribute, got {:?}", other),
+  }
+
+  let items = context
+    .evaluate("/ | /root | //child[1]/text() | //child/@attribute")
+    .unwrap()
+    .get_items();
+  assert_eq!(items.len(), 5);
+  assert!(matches!(items[0], NodeSetItem::Document(_)));
+  assert!(matches!(items[1], NodeSetItem::Element(_)));
+  assert!(matches!(items[2], NodeSetItem::Attribute(_)));
+  assert!(matches!(items[4], NodeSetItem::Attribute(_)));
+  match &items[3] {
+    NodeSetItem::Text(text) => assert_eq!(text.get_content(), "some text"),
+    other => panic!("expected a text node, got {:?}", other),
+  }
+  assert_eq!(items[1].as_node().unwrap().get_name(), "root");
+}